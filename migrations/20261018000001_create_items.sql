@@ -0,0 +1,8 @@
+-- 📦 ตาราง items เดิมของระบบ (IF NOT EXISTS เพื่อให้ใช้กับฐานข้อมูลที่มีตารางอยู่แล้วได้)
+CREATE TABLE IF NOT EXISTS items (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    category TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
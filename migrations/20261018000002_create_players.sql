@@ -0,0 +1,15 @@
+-- 🎮 ผู้เล่น และเจ้าของ item
+CREATE TABLE players (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+-- 👤 item ที่ไม่มีเจ้าของ (owner_id IS NULL) คือ item กลางของระบบแบบเดิม
+ALTER TABLE items ADD COLUMN owner_id INT REFERENCES players (id);
+
+CREATE INDEX items_owner_id_idx ON items (owner_id);
+
+-- 🔑 ชื่อ item ไม่ซ้ำภายในเจ้าของเดียวกันและประเภทเดียวกัน (item กลางนับเป็นเจ้าของเดียวกัน)
+CREATE UNIQUE INDEX items_owner_category_name_key ON items (COALESCE(owner_id, 0), category, name);
@@ -0,0 +1,5 @@
+-- ⚖️ น้ำหนักของ item (ใช้คำนวณน้ำหนักรวมในกระเป๋าผู้เล่น)
+ALTER TABLE items ADD COLUMN weight INT NOT NULL DEFAULT 0 CHECK (weight >= 0);
+
+-- 🏅 ระดับของผู้เล่น ใช้เลือก limit ของกระเป๋าจาก [inventory.tiers] ใน Setting.toml
+ALTER TABLE players ADD COLUMN tier TEXT NOT NULL DEFAULT 'basic';
@@ -0,0 +1,10 @@
+-- 💎 ความหายาก, เลเวลขั้นต่ำ และค่าสถานะตามประเภทของ item
+-- 📊 stats เป็น JSONB เพราะแต่ละ category มี field ไม่เหมือนกัน (ตรวจใน use case)
+ALTER TABLE items
+    ADD COLUMN rarity TEXT NOT NULL DEFAULT 'Common'
+        CHECK (rarity IN ('Common', 'Uncommon', 'Rare', 'Epic', 'Legendary')),
+    ADD COLUMN required_level INT NOT NULL DEFAULT 1 CHECK (required_level >= 1),
+    ADD COLUMN stats JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+-- 🔎 ใช้กรองรายการ item ด้วย rarity / required_level
+CREATE INDEX items_rarity_required_level_idx ON items (rarity, required_level);
@@ -0,0 +1,18 @@
+-- 📚 Catalog ของประเภท item - เพิ่มประเภทใหม่ได้โดยไม่ต้องแก้โค้ด
+CREATE TABLE categories (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- 🪄⚔️ ประเภทที่มีอยู่ในโค้ด (models::item::Category) ต้องอยู่ใน catalog เสมอ
+INSERT INTO categories (name) VALUES ('Staff'), ('Sword');
+
+-- 🏷️ ประเภทที่ item ใช้อยู่แต่ยังไม่มีใน catalog (ข้อมูลเก่า)
+INSERT INTO categories (name) SELECT DISTINCT category FROM items ON CONFLICT (name) DO NOTHING;
+
+-- 🔗 item ต้องใช้ประเภทที่อยู่ใน catalog / เปลี่ยนชื่อประเภทแล้ว item ตามไปด้วย
+-- 🚫 ลบประเภทที่ยังมี item ใช้อยู่ไม่ได้ (23503)
+ALTER TABLE items
+    ADD CONSTRAINT items_category_fkey FOREIGN KEY (category) REFERENCES categories (name) ON UPDATE CASCADE;
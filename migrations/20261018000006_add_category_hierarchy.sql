@@ -0,0 +1,24 @@
+-- 🌳 ลำดับชั้นของประเภท (Weapon > Magic > Staff) และกติกาที่สืบทอดลงไปตาม tree
+ALTER TABLE categories
+    ADD COLUMN parent_id INT REFERENCES categories (id),
+    ADD COLUMN rules JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+CREATE INDEX categories_parent_id_idx ON categories (parent_id);
+
+-- 🗡️ กลุ่มแม่ของประเภทที่มีอยู่
+INSERT INTO categories (name) VALUES ('Weapon') ON CONFLICT (name) DO NOTHING;
+INSERT INTO categories (name, parent_id)
+    SELECT 'Magic', id FROM categories WHERE name = 'Weapon'
+    ON CONFLICT (name) DO NOTHING;
+
+-- 🪄 Staff อยู่ใต้ Magic และต้องมี magic_power เท่านั้น
+UPDATE categories
+    SET parent_id = (SELECT id FROM categories WHERE name = 'Magic'),
+        rules = '{"required_stats": ["magic_power"], "allowed_stats": ["magic_power"]}'
+    WHERE name = 'Staff';
+
+-- ⚔️ Sword อยู่ใต้ Weapon และต้องมี attack + durability เท่านั้น
+UPDATE categories
+    SET parent_id = (SELECT id FROM categories WHERE name = 'Weapon'),
+        rules = '{"required_stats": ["attack", "durability"], "allowed_stats": ["attack", "durability"]}'
+    WHERE name = 'Sword';
@@ -0,0 +1,14 @@
+-- 🧾 JSON Schema ของ attributes ต่อประเภท - แก้ schema = เพิ่มเวอร์ชันใหม่ ของเดิมไม่ถูกแก้
+CREATE TABLE category_schemas (
+    category_id INT NOT NULL REFERENCES categories (id) ON DELETE CASCADE,
+    version INT NOT NULL CHECK (version >= 1),
+    schema JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (category_id, version)
+);
+
+-- 🧩 attributes อิสระของ item และเวอร์ชัน schema ที่ใช้ตรวจ (NULL = ประเภทไม่มี schema)
+-- 📌 item เดิมยังอ้างถึงเวอร์ชันที่ผ่านการตรวจ แม้ประเภทจะมี schema เวอร์ชันใหม่แล้ว
+ALTER TABLE items
+    ADD COLUMN attributes JSONB NOT NULL DEFAULT '{}',
+    ADD COLUMN attributes_schema_version INT;
@@ -0,0 +1,39 @@
+-- 📜 แบบของ item (definition) ที่ designer ดูแล - items กลายเป็นชิ้นจริง (instance) ที่อ้างถึงแบบ
+CREATE TABLE item_definitions (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    category TEXT NOT NULL REFERENCES categories (name) ON UPDATE CASCADE,
+    weight INT NOT NULL DEFAULT 0 CHECK (weight >= 0),
+    rarity TEXT NOT NULL DEFAULT 'Common'
+        CHECK (rarity IN ('Common', 'Uncommon', 'Rare', 'Epic', 'Legendary')),
+    required_level INT NOT NULL DEFAULT 1 CHECK (required_level >= 1),
+    base_stats JSONB NOT NULL DEFAULT '{}',
+    stat_variance INT NOT NULL DEFAULT 0 CHECK (stat_variance BETWEEN 0 AND 100),
+    max_durability INT CHECK (max_durability >= 1),
+    icon TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (category, name)
+);
+
+-- 🔁 item เดิม: หนึ่งแบบต่อ (ประเภท, ชื่อ) ใช้ค่าของแถวแรกเป็นค่าตั้งต้นของแบบ
+INSERT INTO item_definitions (name, category, weight, rarity, required_level, base_stats, created_at, updated_at)
+SELECT DISTINCT ON (category, name) name, category, weight, rarity, required_level, stats, created_at, updated_at
+FROM items
+ORDER BY category, name, id;
+
+-- 🎲 instance อ้างถึงแบบ มีค่าสถานะที่สุ่มได้และความทนทานของตัวเอง (definition_id NULL = item แบบเดิมที่ไม่มีแบบ)
+ALTER TABLE items
+    ADD COLUMN definition_id INT REFERENCES item_definitions (id),
+    ADD COLUMN durability INT CHECK (durability >= 0);
+
+UPDATE items SET definition_id = item_definitions.id
+FROM item_definitions
+WHERE item_definitions.category = items.category AND item_definitions.name = items.name;
+
+CREATE INDEX items_definition_id_idx ON items (definition_id);
+
+-- 🔑 instance ของแบบเดียวกันมีได้หลายชิ้นในกระเป๋าเดียว - ชื่อห้ามซ้ำเฉพาะ item ที่ไม่มีแบบ
+DROP INDEX items_owner_category_name_key;
+CREATE UNIQUE INDEX items_owner_category_name_key ON items (COALESCE(owner_id, 0), category, name)
+    WHERE definition_id IS NULL;
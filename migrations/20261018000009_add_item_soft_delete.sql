@@ -0,0 +1,10 @@
+-- 🗑️ ลบแบบ soft delete: ตั้ง deleted_at แทนการลบแถว (GM กู้คืนได้จนกว่า purge job จะลบจริง)
+ALTER TABLE items ADD COLUMN deleted_at TIMESTAMP;
+
+-- 🧹 purge job หา item ที่ลบเกินระยะเก็บรักษา
+CREATE INDEX items_deleted_at_idx ON items (deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- 🔑 ชื่อห้ามซ้ำเฉพาะ item ที่ยังไม่ถูกลบ - ลบแล้วสร้างชื่อเดิมใหม่ได้
+DROP INDEX items_owner_category_name_key;
+CREATE UNIQUE INDEX items_owner_category_name_key ON items (COALESCE(owner_id, 0), category, name)
+    WHERE definition_id IS NULL AND deleted_at IS NULL;
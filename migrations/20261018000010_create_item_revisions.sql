@@ -0,0 +1,15 @@
+-- 📜 ประวัติการแก้ไข item: ใครทำอะไร เมื่อไร ค่าก่อน/หลัง และ request id สำหรับตามรอยใน log
+-- 🧹 ไม่มี foreign key ไปที่ items เพื่อให้ประวัติยังอยู่หลังจาก purge job ลบ item จริง
+CREATE TABLE item_revisions (
+    item_id INT NOT NULL,
+    revision INT NOT NULL CHECK (revision >= 1),
+    action TEXT NOT NULL
+        CHECK (action IN ('create', 'update', 'transfer', 'delete', 'restore', 'revert')),
+    actor TEXT NOT NULL,
+    request_id TEXT,
+    before JSONB,
+    after JSONB,
+    diff JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (item_id, revision)
+);
@@ -0,0 +1,2 @@
+-- 🔢 optimistic concurrency: version เพิ่มขึ้นทุกครั้งที่แก้ไข - การแก้ไขที่อ้าง version เก่าจะถูกปฏิเสธ
+ALTER TABLE items ADD COLUMN version INT NOT NULL DEFAULT 1 CHECK (version >= 1);
@@ -0,0 +1,28 @@
+-- 🔎 ค้นหาชื่อ item แบบบางส่วน / สะกดผิด / ภาษาไทย (GET /items/search)
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- 🔤 ชื่อในรูปที่ใช้เทียบ: ตัวเล็ก / ไม่มี zero-width space (ใช้แบ่งคำภาษาไทย) / นิคหิต + สระอา (พิมพ์แยกสองตัว) เป็นสระอำ
+CREATE FUNCTION item_search_text(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE AS $$
+    SELECT regexp_replace(replace(lower(value), '​', ''), 'ํ(่|้|๊|๋)?า', '\1ำ', 'g')
+$$;
+
+-- 🧩 ภาษาไทยไม่เว้นวรรคระหว่างคำ และ pg_trgm นับเฉพาะตัวอักษรตาม locale ของฐานข้อมูล (C locale = ASCII เท่านั้น)
+--    จึงแปลงอักษรไทยแต่ละตัว (U+0E01-U+0E5B) เป็นตัวเลข + ตัวอักษร ASCII สองตัว เช่น "ดาบ" -> "0u1y1a"
+--    trigram ของรูปนี้เทียบอักษรไทยทีละตัวได้ ทั้ง substring และการสะกดผิด
+--    (ฐานข้อมูล SQL_ASCII เก็บอักษรไทยเป็น byte - ไม่ถูกแปลง ค้นภาษาไทยได้แบบ substring อย่างเดียว)
+CREATE FUNCTION item_search_key(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE AS $$
+    SELECT COALESCE(string_agg(
+        CASE WHEN ascii(ch) BETWEEN 3585 AND 3675
+             THEN chr(48 + (ascii(ch) - 3584) / 26) || chr(97 + (ascii(ch) - 3584) % 26)
+             ELSE ch
+        END, '' ORDER BY position), '')
+    FROM regexp_split_to_table(item_search_text(value), '') WITH ORDINALITY AS t(ch, position)
+$$;
+
+-- 🔍 trigram: substring (LIKE) และชื่อที่สะกดใกล้เคียง (<%)
+CREATE INDEX items_name_trgm_idx ON items USING GIN (item_search_key(name) gin_trgm_ops) WHERE deleted_at IS NULL;
+
+-- 📝 full-text: คำภาษาอังกฤษครบคำ ไม่สนลำดับ ("staff fire" เจอ "Fire Staff")
+CREATE INDEX items_name_fts_idx ON items USING GIN (to_tsvector('simple', name)) WHERE deleted_at IS NULL;
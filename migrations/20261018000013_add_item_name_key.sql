@@ -0,0 +1,30 @@
+-- 🔤 ชื่อ item ไม่สนตัวพิมพ์: "Wooden Staff" กับ "wooden staff" คือชื่อเดียวกัน
+--    แอปเก็บชื่อที่ normalize แล้ว (ตัดช่องว่างหัวท้าย / NFC / ช่องว่างหลายตัวเหลือตัวเดียว - models::item::name_normalizing)
+--    name_key = ชื่อตัวเล็กตาม locale ของฐานข้อมูล ใช้ตรวจชื่อซ้ำแทน name
+
+-- 🔑 เอา index เดิมออกก่อน - ชื่อเดิมที่ normalize แล้วอาจซ้ำกัน
+DROP INDEX items_owner_category_name_key;
+
+-- 🧹 normalize ชื่อเดิมแบบเดียวกับแอป (normalize() ใช้ได้เฉพาะฐานข้อมูล UTF8)
+UPDATE items SET name = btrim(regexp_replace(name, '\s+', ' ', 'g'))
+    WHERE name <> btrim(regexp_replace(name, '\s+', ' ', 'g'));
+DO $$
+BEGIN
+    IF current_setting('server_encoding') = 'UTF8' THEN
+        EXECUTE 'UPDATE items SET name = normalize(name, NFC) WHERE name IS NOT NFC NORMALIZED';
+    END IF;
+END
+$$;
+
+ALTER TABLE items ADD COLUMN name_key TEXT GENERATED ALWAYS AS (lower(name)) STORED;
+
+-- 🔁 ชื่อที่ซ้ำกันหลัง normalize: ชิ้นแรก (id น้อยสุด) ใช้ชื่อเดิม ชิ้นอื่นต่อท้ายด้วย " #id"
+UPDATE items SET name = items.name || ' #' || items.id, version = items.version + 1
+    FROM (
+        SELECT id, row_number() OVER (PARTITION BY COALESCE(owner_id, 0), category, name_key ORDER BY id) AS duplicate
+        FROM items WHERE definition_id IS NULL AND deleted_at IS NULL
+    ) ranked
+    WHERE items.id = ranked.id AND ranked.duplicate > 1;
+
+CREATE UNIQUE INDEX items_owner_category_name_key ON items (COALESCE(owner_id, 0), category, name_key)
+    WHERE definition_id IS NULL AND deleted_at IS NULL;
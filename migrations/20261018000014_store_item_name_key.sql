@@ -0,0 +1,6 @@
+-- 🔤 name_key เก็บค่าที่แอปคำนวณ (models::item::name_key) แทน lower(name) ของฐานข้อมูล
+--    lower() ขึ้นกับ locale - locale C แปลงได้แค่ ASCII ("ÉPÉE" กับ "épée" จึงไม่ซ้ำกัน)
+--    ค่าเดิม (= lower(name)) ยังอยู่ และ unique index เดิมยังใช้ได้
+--    ค่าของชื่อที่ไม่ใช่ ASCII คำนวณใหม่ด้วยคำสั่ง admin: cargo run --bin name_keys_rebuilding
+ALTER TABLE items ALTER COLUMN name_key DROP EXPRESSION;
+ALTER TABLE items ALTER COLUMN name_key SET NOT NULL;
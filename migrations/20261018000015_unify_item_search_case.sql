@@ -0,0 +1,13 @@
+-- 🔤 ตัวพิมพ์ของการค้นหาใช้กติกาเดียวกับแอป (models::item::lowercasing)
+--    ชื่อเทียบผ่านคอลัมน์ name_key ที่แอปคำนวณ และคำค้นถูกแปลงเป็นตัวเล็กในแอปก่อนส่งมา
+--    lower() ของฐานข้อมูลขึ้นกับ locale (locale C แปลงได้แค่ ASCII: "ÉPÉE" ค้นด้วย "épée" ไม่เจอ แต่ไฮไลต์เจอ) จึงไม่ใช้แล้ว
+DROP INDEX items_name_trgm_idx;
+DROP INDEX items_name_fts_idx;
+
+CREATE OR REPLACE FUNCTION item_search_text(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE AS $$
+    SELECT regexp_replace(replace(value, '​', ''), 'ํ(่|้|๊|๋)?า', '\1ำ', 'g')
+$$;
+
+CREATE INDEX items_name_trgm_idx ON items USING GIN (item_search_key(name_key) gin_trgm_ops) WHERE deleted_at IS NULL;
+CREATE INDEX items_name_fts_idx ON items USING GIN (to_tsvector('simple', name_key)) WHERE deleted_at IS NULL;
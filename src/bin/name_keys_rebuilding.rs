@@ -0,0 +1,32 @@
+// === 🔤 คำสั่ง admin: คำนวณ name_key ของ item เดิมใหม่ ===
+// 📖 รันครั้งเดียวหลังอัปเกรดผ่าน migration store_item_name_key: cargo run --bin name_keys_rebuilding
+//    ชื่อที่ normalize แล้วซ้ำกันได้ต่อท้าย " #id" และบันทึก revision ผู้แก้ไข "migration"
+// ⚠️ ล็อกตาราง items จนเสร็จ - รันช่วงปิดปรับปรุง และรันจากเครื่องเดียว
+
+use rust_clean_architecture_v1::{
+    database,                                          // 🗄️ จัดการการเชื่อมต่อฐานข้อมูล
+    entities::item_revisions::Auditing,                // 📜 ผู้แก้ไขของการเปลี่ยนชื่อ
+    models::audit::{Actor, ItemAction},                // 📜 ชนิดการแก้ไข / ผู้แก้ไข
+    repositories::items_postgres::name_keys_rebuilding, // 💾 คำนวณ name_key ใหม่ทั้งตาราง
+    setting::Setting,                                  // ⚙️ การตั้งค่าระบบ
+    time_helper::TimerHelper,                          // ⏰ เวลาของ revision
+};
+use std::sync::Arc;
+use tracing::info;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let setting = Setting::new().unwrap();
+    let db_pool = database::conn_getting(Arc::clone(&setting)).await.unwrap();
+    database::migrations_running(&db_pool).await.unwrap(); // 🔤 name_key ต้องเป็นคอลัมน์ธรรมดาแล้ว
+    info!("database migrations have been applied.");
+
+    let migration = Actor { name: "migration".to_string(), request_id: None };
+    let auditing = Auditing::new(ItemAction::Update, &migration, TimerHelper::Directly.creation());
+    let renamed = name_keys_rebuilding(&db_pool, auditing).await.unwrap();
+    info!("item name keys have been rebuilt ({} renamed).", renamed);
+}
@@ -1,8 +1,8 @@
 use crate::setting::Setting;
-use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
+use sqlx::{migrate::Migrator, postgres::PgPoolOptions, Pool, Postgres};
 // นำเข้า Arc (Atomic Reference Counted) จาก standard library
 // Arc เป็นตัว smart pointer ที่ใช้แชร์ข้อมูลระหว่าง threads ได้อย่างปลอดภัย
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 pub async fn conn_getting(setting: Arc<Setting>) -> Result<Pool<Postgres>,sqlx::Error>{
     let pool = PgPoolOptions::new()
@@ -10,4 +10,10 @@ pub async fn conn_getting(setting: Arc<Setting>) -> Result<Pool<Postgres>,sqlx::
         .connect(setting.database.url_getting().as_str())
         .await?;
     Ok(pool)
-}
\ No newline at end of file
+}
+
+// 🧱 รัน migration ในโฟลเดอร์ migrations/ (อ่านจาก working directory เหมือน Setting.toml)
+// migration ที่รันไปแล้วจะถูกข้าม จึงเรียกทุกครั้งที่เริ่มระบบได้
+pub async fn migrations_running(pool: &Pool<Postgres>) -> Result<(), sqlx::migrate::MigrateError>{
+    Migrator::new(Path::new("migrations")).await?.run(pool).await
+}
@@ -0,0 +1,62 @@
+// === 🏛️ Domain Layer: Categories Entity ===
+// 📖 ประเภทของ item ใน catalog (ตาราง categories) - items.category อ้างถึง name
+//    ประเภทเป็น tree ผ่าน parent_id และกติกา (rules) สืบทอดจากแม่ลงไปหาลูก
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Categories entity เก็บเฉพาะข้อมูลของประเภทใน catalog
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
+
+use chrono::NaiveDateTime;
+use sqlx::types::Json;
+
+use crate::{
+    models::{
+        category::{CategoryEntry, CategoryRules},
+        error::{APIError, IntoErrorResponse},
+    },
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📦 Categories struct: ประเภทหนึ่งรายการใน catalog
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+pub struct Categories {
+    pub id: Option<i32>,           // 🆔 Primary key (None สำหรับข้อมูลใหม่)
+    pub name: String,              // 🏷️ ชื่อประเภท (ไม่ซ้ำ)
+    pub parent_id: Option<i32>,    // 🌳 ประเภทแม่ (None = ราก)
+    pub rules: Json<CategoryRules>, // 📏 กติกาของประเภทนี้เอง (JSONB)
+    pub created_at: NaiveDateTime, // 📅 วันที่สร้าง
+    pub updated_at: NaiveDateTime, // 🔄 วันที่อัปเดตล่าสุด
+}
+
+impl Categories {
+    // 🏗️ Constructor: สร้าง Categories ใหม่
+    pub fn new(name: String, t: IntoTimerHelperShared) -> Self {
+        Self {
+            id: None,
+            name,
+            parent_id: None,
+            rules: Json(CategoryRules::default()),
+            created_at: t.now(),
+            updated_at: t.now(),
+        }
+    }
+
+    // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
+    pub fn to_model(&self) -> Result<CategoryEntry, Box<dyn IntoErrorResponse>> {
+        let id = match self.id {
+            Some(id) => id,
+            None => return Err(Box::new(APIError::MissingEntityId(self.name.clone()))),
+        };
+
+        Ok(CategoryEntry {
+            id,
+            name: self.name.to_string(),
+            parent_id: self.parent_id,
+            rules: self.rules.0.clone(),
+        })
+    }
+}
@@ -0,0 +1,58 @@
+// === 🏛️ Domain Layer: Category Schemas Entity ===
+// 📖 JSON Schema ของ attributes ของประเภทหนึ่งเวอร์ชัน (ตาราง category_schemas)
+//    แก้ schema = เพิ่มเวอร์ชันใหม่ item เดิมยังอ้างถึงเวอร์ชันที่ใช้ตรวจตอนบันทึก
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    CategorySchemas entity เก็บเฉพาะ schema หนึ่งเวอร์ชัน
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
+
+use chrono::NaiveDateTime;
+use serde_json::Value;
+use sqlx::types::Json;
+
+use crate::{
+    models::{
+        category::CategorySchemaEntry,
+        error::{APIError, IntoErrorResponse},
+    },
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📦 CategorySchemas struct: schema หนึ่งเวอร์ชันของประเภท
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+pub struct CategorySchemas {
+    pub category_id: i32,          // 📚 ประเภทเจ้าของ schema
+    pub version: Option<i32>,      // 🔢 เวอร์ชัน (None สำหรับข้อมูลใหม่ - ฐานข้อมูลกำหนดให้)
+    pub schema: Json<Value>,       // 🧾 JSON Schema (JSONB)
+    pub created_at: NaiveDateTime, // 📅 วันที่สร้าง
+}
+
+impl CategorySchemas {
+    // 🏗️ Constructor: สร้าง schema เวอร์ชันใหม่
+    pub fn new(category_id: i32, schema: Value, t: IntoTimerHelperShared) -> Self {
+        Self {
+            category_id,
+            version: None,
+            schema: Json(schema),
+            created_at: t.now(),
+        }
+    }
+
+    // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
+    pub fn to_model(&self) -> Result<CategorySchemaEntry, Box<dyn IntoErrorResponse>> {
+        let version = match self.version {
+            Some(version) => version,
+            None => return Err(Box::new(APIError::MissingEntityId(format!("schema of category {}", self.category_id)))),
+        };
+
+        Ok(CategorySchemaEntry {
+            category_id: self.category_id,
+            version,
+            schema: self.schema.0.clone(),
+        })
+    }
+}
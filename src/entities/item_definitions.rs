@@ -0,0 +1,102 @@
+// === 🏛️ Domain Layer: Item Definitions Entity ===
+// 📖 แบบของ item (ตาราง item_definitions) ที่ designer ดูแล - instance ใน items อ้างถึงผ่าน definition_id
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ItemDefinitions entity เก็บเฉพาะแบบของ item ไม่รู้เรื่องเจ้าของหรือค่าที่สุ่มได้
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
+
+use chrono::NaiveDateTime;
+use sqlx::types::Json;
+
+use crate::{
+    models::{
+        error::{APIError, IntoErrorResponse},
+        item::{Category, ItemStats, Rarity},
+        item_definition::ItemDefinition as ItemDefinitionModel,
+    },
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📦 ItemDefinitions struct: แบบของ item หนึ่งรายการ
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+pub struct ItemDefinitions {
+    pub id: Option<i32>,             // 🆔 Primary key (None สำหรับข้อมูลใหม่)
+    pub name: String,                // 📝 ชื่อแบบ (ไม่ซ้ำภายในประเภท)
+    pub category: String,            // 🏷️ ประเภท (อ้างถึง categories.name)
+    pub weight: i32,                 // ⚖️ น้ำหนักของทุก instance
+    pub rarity: String,              // 💎 ความหายาก
+    pub required_level: i32,         // 🎚️ เลเวลขั้นต่ำ
+    pub base_stats: Json<ItemStats>, // 📊 ค่าสถานะตั้งต้นก่อนสุ่ม (JSONB)
+    pub stat_variance: i32,          // 🎲 ช่วงสุ่มค่าสถานะ ± เปอร์เซ็นต์ (0 = ไม่สุ่ม)
+    pub max_durability: Option<i32>, // 🔧 ความทนทานของ instance ใหม่ (None = ไม่สึกหรอ)
+    pub icon: Option<String>,        // 🖼️ ไอคอน (URL หรือ asset key)
+    pub created_at: NaiveDateTime,   // 📅 วันที่สร้าง
+    pub updated_at: NaiveDateTime,   // 🔄 วันที่อัปเดตล่าสุด
+}
+
+impl ItemDefinitions {
+    // 🏗️ Constructor: สร้างแบบใหม่ด้วยค่าเริ่มต้น
+    pub fn new(name: String, category: String, t: IntoTimerHelperShared) -> Self {
+        Self {
+            id: None,
+            name,
+            category,
+            weight: 0,
+            rarity: Rarity::Common.to_string(),
+            required_level: 1,
+            base_stats: Json(ItemStats::default()),
+            stat_variance: 0,
+            max_durability: None,
+            icon: None,
+            created_at: t.now(),
+            updated_at: t.now(),
+        }
+    }
+
+    // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
+    pub fn to_model(&self) -> Result<ItemDefinitionModel, Box<dyn IntoErrorResponse>> {
+        let rarity = match self.get_rarity() {
+            Some(rarity) => rarity,
+            None => return Err(Box::new(APIError::InvalidRarity(self.rarity.clone()))),
+        };
+
+        let id = match self.id {
+            Some(id) => id,
+            None => return Err(Box::new(APIError::MissingEntityId(self.name.clone()))),
+        };
+
+        Ok(ItemDefinitionModel {
+            id,
+            name: self.name.to_string(),
+            category: self.get_category(),
+            weight: self.weight,
+            rarity,
+            required_level: self.required_level,
+            base_stats: self.base_stats.0.clone(),
+            stat_variance: self.stat_variance,
+            max_durability: self.max_durability,
+            icon: self.icon.clone(),
+        })
+    }
+
+    // 🏷️ แปลง String category เป็น Category enum
+    pub fn get_category(&self) -> Category {
+        Category::from(self.category.clone())
+    }
+
+    // 💎 แปลง String rarity เป็น Rarity enum (ใช้กติกาเดียวกับ Items)
+    pub fn get_rarity(&self) -> Option<Rarity> {
+        match self.rarity.as_str() {
+            "Common" => Some(Rarity::Common),
+            "Uncommon" => Some(Rarity::Uncommon),
+            "Rare" => Some(Rarity::Rare),
+            "Epic" => Some(Rarity::Epic),
+            "Legendary" => Some(Rarity::Legendary),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// === 🏛️ Domain Layer: Item Revisions Entity ===
+// 📖 ประวัติการแก้ไข item หนึ่งครั้ง (ตาราง item_revisions) - บันทึกใน transaction เดียวกับการแก้ไข
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ItemRevisions เก็บภาพก่อน/หลังและ diff ส่วน Auditing รู้ว่าใครแก้ เมื่อไร
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
+
+use chrono::NaiveDateTime;
+use serde_json::{Map, Value, json};
+use sqlx::types::Json;
+
+use crate::{
+    entities::items::Items,
+    models::{
+        audit::{Actor, ItemAction, ItemRevision},
+        error::{APIError, IntoErrorResponse},
+    },
+    time_helper::IntoTimerHelperShared,
+};
+
+// 🕵️ field ที่เปลี่ยนทุกครั้งจึงไม่นับใน diff
+const UNTRACKED_FIELDS: [&str; 2] = ["updated_at", "version"];
+
+// 📦 ItemRevisions struct: การแก้ไข item หนึ่งครั้ง
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+pub struct ItemRevisions {
+    pub item_id: i32,                // 🆔 item ที่ถูกแก้ไข
+    pub revision: Option<i32>,       // 🔢 ลำดับ (None สำหรับข้อมูลใหม่ - ฐานข้อมูลกำหนดให้)
+    pub action: String,              // 🏷️ ชนิดของการแก้ไข (เก็บเป็น String)
+    pub actor: String,               // 👤 ผู้แก้ไข
+    pub request_id: Option<String>,  // 🆔 request id
+    pub before: Option<Json<Value>>, // 📸 ค่าก่อนแก้ไข (JSONB)
+    pub after: Option<Json<Value>>,  // 📸 ค่าหลังแก้ไข (JSONB)
+    pub diff: Json<Value>,           // 🔍 field ที่เปลี่ยน (JSONB)
+    pub created_at: NaiveDateTime,   // 📅 เวลาที่แก้ไข
+}
+
+impl ItemRevisions {
+    // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
+    pub fn to_model(&self) -> Result<ItemRevision, Box<dyn IntoErrorResponse>> {
+        let revision = match self.revision {
+            Some(revision) => revision,
+            None => return Err(Box::new(APIError::MissingEntityId(format!("revision of item {}", self.item_id)))),
+        };
+        let action = match serde_json::from_value(Value::String(self.action.clone())) {
+            Ok(action) => action,
+            Err(_) => return Err(Box::new(APIError::InternalServerError)),
+        };
+
+        Ok(ItemRevision {
+            item_id: self.item_id,
+            revision,
+            action,
+            actor: self.actor.clone(),
+            request_id: self.request_id.clone(),
+            before: self.before.as_ref().map(|before| before.0.clone()),
+            after: self.after.as_ref().map(|after| after.0.clone()),
+            diff: self.diff.0.clone(),
+            created_at: self.created_at,
+        })
+    }
+}
+
+// 🕵️ Auditing: ใครแก้ไขอะไร เมื่อไร - use case สร้างส่งให้ repository บันทึกพร้อมการแก้ไข
+#[derive(Debug, Clone, PartialEq)]
+pub struct Auditing {
+    pub action: ItemAction,          // 🏷️ ชนิดของการแก้ไข
+    pub actor: String,               // 👤 ผู้แก้ไข
+    pub request_id: Option<String>,  // 🆔 request id
+    pub created_at: NaiveDateTime,   // 📅 เวลาจาก timer helper
+}
+
+impl Auditing {
+    // 🏗️ Constructor: การแก้ไขของ actor ณ เวลาปัจจุบัน
+    pub fn new(action: ItemAction, actor: &Actor, t: IntoTimerHelperShared) -> Self {
+        Self {
+            action,
+            actor: actor.name.clone(),
+            request_id: actor.request_id.clone(),
+            created_at: t.now(),
+        }
+    }
+
+    // 📜 สร้าง revision จากภาพก่อน/หลัง (None = ยังไม่มี item)
+    pub fn recording(&self, item_id: i32, before: Option<&Items>, after: Option<&Items>) -> ItemRevisions {
+        let before = before.map(Items::snapshot);
+        let after = after.map(Items::snapshot);
+
+        ItemRevisions {
+            item_id,
+            revision: None,
+            action: self.action.to_string(),
+            actor: self.actor.clone(),
+            request_id: self.request_id.clone(),
+            diff: Json(diff(before.as_ref(), after.as_ref())),
+            before: before.map(Json),
+            after: after.map(Json),
+            created_at: self.created_at,
+        }
+    }
+}
+
+// 🔍 field ที่ค่าต่างกันระหว่างภาพก่อน/หลัง: {"field": {"before": ..., "after": ...}}
+fn diff(before: Option<&Value>, after: Option<&Value>) -> Value {
+    let empty = Map::new();
+    let fields = |snapshot: Option<&Value>| snapshot.and_then(Value::as_object).unwrap_or(&empty).clone();
+    let (before, after) = (fields(before), fields(after));
+
+    let mut changes = Map::new();
+    for name in before.keys().chain(after.keys()) {
+        if UNTRACKED_FIELDS.contains(&name.as_str()) || changes.contains_key(name) {
+            continue;
+        }
+        let old = before.get(name).cloned().unwrap_or(Value::Null);
+        let new = after.get(name).cloned().unwrap_or(Value::Null);
+        if old != new {
+            changes.insert(name.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+    Value::Object(changes)
+}
@@ -8,7 +8,7 @@
 //
 // 2️⃣ Open/Closed Principle (OCP):
 //    สามารถเพิ่ม method ใหม่ได้โดยไม่แก้ไข struct เดิม
-//    เพิ่ม category ใหม่ได้ใน get_category() โดยไม่แก้ไข logic เดิม
+//    เพิ่ม category ใหม่ได้จาก catalog โดยไม่แก้ไข logic เดิม
 //
 // 3️⃣ Liskov Substitution Principle (LSP):
 //    Items entity สามารถใช้ในที่ที่ต้องการ domain object ได้
@@ -20,25 +20,42 @@
 //    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
 
 use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::types::Json;
 
 use crate::{
     models::{
         error::{APIError, IntoErrorResponse},
-        item::{Category, Item as ItemModel},
+        item::{Category, Item as ItemModel, ItemAttributes, ItemStats, Rarity},
     },
     time_helper::IntoTimerHelperShared,
 };
 
 // 📦 Items struct: ข้อมูลหลักของ Item ในระบบ
+// 🎲 แต่ละแถวคือ instance (ชิ้นจริงที่มีเจ้าของ) - แบบของ item อยู่ที่ ItemDefinitions ผ่าน definition_id
 // - derive Debug: สำหรับการ debug และ print
 // - derive Clone: ให้สามารถ copy ข้อมูลได้
 // - derive sqlx::FromRow: แปลงข้อมูลจากฐานข้อมูลเป็น struct
 // - derive PartialEq: สำหรับการเปรียบเทียบความเท่ากัน
-#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+// - derive Serialize/Deserialize: ภาพก่อน/หลังใน item_revisions (ย้อนกลับได้)
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Serialize, Deserialize)]
 pub struct Items{
     pub id: Option<i32>,           // 🆔 Primary key (None สำหรับข้อมูลใหม่)
     pub name: String,              // 📝 ชื่อของ item
     pub category: String,          // 🏷️ ประเภทของ item (เก็บเป็น String)
+    pub owner_id: Option<i32>,     // 👤 ผู้เล่นที่เป็นเจ้าของ (None = item กลางของระบบ)
+    pub weight: i32,               // ⚖️ น้ำหนัก (นับรวมใน limit ของกระเป๋าผู้เล่น)
+    pub rarity: String,            // 💎 ความหายาก (เก็บเป็น String เหมือน category)
+    pub required_level: i32,       // 🎚️ เลเวลขั้นต่ำที่ใช้ได้
+    pub stats: Json<ItemStats>,    // 📊 ค่าสถานะตามประเภท (JSONB)
+    pub attributes: Json<ItemAttributes>, // 🧩 attributes ตาม schema ของประเภท (JSONB)
+    pub attributes_schema_version: Option<i32>, // 🧾 เวอร์ชัน schema ที่ใช้ตรวจ (None = ไม่มี schema)
+    pub definition_id: Option<i32>, // 📜 แบบของ item (None = item แบบเดิมที่ไม่มีแบบ)
+    pub durability: Option<i32>,   // 🔧 ความทนทานที่เหลือของชิ้นนี้ (None = ไม่สึกหรอ)
+    pub deleted_at: Option<NaiveDateTime>, // 🗑️ เวลาที่ถูกลบ (None = ยังใช้งานอยู่)
+    #[serde(default)]
+    pub version: i32,              // 🔢 เพิ่มขึ้นทุกครั้งที่แก้ไข (ภาพใน revision ก่อนมี version เป็น 0)
     pub created_at: NaiveDateTime, // 📅 วันที่สร้าง
     pub updated_at: NaiveDateTime, // 🔄 วันที่อัปเดตล่าสุด
 }
@@ -51,6 +68,17 @@ impl Items {
             id: None,                    // 🆔 ยังไม่มี ID (จะได้จากฐานข้อมูลหลังจาก insert)
             name,                        // 📝 ชื่อที่รับมา
             category,                    // 🏷️ ประเภทที่รับมา
+            owner_id: None,              // 👤 ยังไม่มีเจ้าของ (กำหนดภายหลังได้)
+            weight: 0,                   // ⚖️ ไม่มีน้ำหนัก (กำหนดภายหลังได้)
+            rarity: Rarity::Common.to_string(), // 💎 ทั่วไป
+            required_level: 1,           // 🎚️ ใช้ได้ตั้งแต่เลเวล 1
+            stats: Json(ItemStats::default()), // 📊 ยังไม่มีค่าสถานะ
+            attributes: Json(ItemAttributes::new()), // 🧩 ยังไม่มี attributes
+            attributes_schema_version: None, // 🧾 ยังไม่ได้ตรวจกับ schema
+            definition_id: None,         // 📜 ไม่มีแบบ
+            durability: None,            // 🔧 ไม่สึกหรอ
+            deleted_at: None,            // 🗑️ ยังไม่ถูกลบ
+            version: 1,                  // 🔢 version แรก
             created_at: t.now(),         // 📅 เวลาปัจจุบันจาก timer helper
             updated_at: t.now(),         // 🔄 เวลาปัจจุบันจาก timer helper
         }
@@ -59,30 +87,184 @@ impl Items {
     // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
     // Entity (Domain) -> Model (API Response)
     pub fn to_model(&self) -> Result<ItemModel, Box<dyn IntoErrorResponse>>{
-        // 🔍 แปลง String category เป็น Category enum
-        let category = match self.get_category(){
-            Some(category) => category,
-            None => {
-                // ❌ ถ้าไม่พบประเภทที่ถูกต้อง ส่ง error กลับ
-                return Err(Box::new(APIError::InvalidCategory(self.category.clone())));
-            }
+        // 🔍 แปลง String category เป็น Category enum (มีอยู่ใน catalog แน่นอนเพราะมี foreign key)
+        let category = self.get_category();
+
+        // 💎 แปลง String rarity เป็น Rarity enum
+        let rarity = match self.get_rarity() {
+            Some(rarity) => rarity,
+            None => return Err(Box::new(APIError::InvalidRarity(self.rarity.clone()))),
+        };
+
+        // 🆔 ต้องมี ID (Entity ที่ยังไม่ได้ insert จะไม่มี ID) - ไม่ unwrap เพื่อไม่ให้ panic
+        let id = match self.id {
+            Some(id) => id,
+            None => return Err(Box::new(APIError::MissingEntityId(self.name.clone()))),
         };
 
         // ✅ สร้าง ItemModel สำหรับ API response
         Ok(ItemModel {
-            id: self.id.unwrap(),        // 🆔 ต้องมี ID (unwrap เพราะถ้าเรียก to_model แปลว่ามี ID แล้ว)
+            id,                          // 🆔 ID จากฐานข้อมูล
             name: self.name.to_string(), // 📝 ชื่อ
             category,                    // 🏷️ ประเภทที่แปลงแล้ว
+            owner_id: self.owner_id,     // 👤 เจ้าของ
+            weight: self.weight,         // ⚖️ น้ำหนัก
+            rarity,                      // 💎 ความหายากที่แปลงแล้ว
+            required_level: self.required_level, // 🎚️ เลเวลขั้นต่ำ
+            stats: self.stats.0.clone(), // 📊 ค่าสถานะ
+            attributes: self.attributes.0.clone(), // 🧩 attributes
+            attributes_schema_version: self.attributes_schema_version, // 🧾 เวอร์ชัน schema
+            definition_id: self.definition_id, // 📜 แบบของ item
+            durability: self.durability, // 🔧 ความทนทาน
+            deleted_at: self.deleted_at, // 🗑️ เวลาที่ถูกลบ
+            version: self.version,       // 🔢 version ปัจจุบัน
         })
     }
 
+    // 📸 ค่าทั้งหมดของแถวนี้ในรูป JSON สำหรับ item_revisions
+    pub fn snapshot(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    // ⏪ อ่านภาพจาก item_revisions กลับเป็น Entity (None = ภาพเสีย)
+    pub fn from_snapshot(snapshot: &Value) -> Option<Items> {
+        serde_json::from_value(snapshot.clone()).ok()
+    }
+
     // 🏷️ แปลง String category เป็น Category enum
-    // เป็น business logic ที่กำหนดว่ามีประเภทไหนบ้างในระบบ
-    pub fn get_category(&self) -> Option<Category>{
-        match self.category.as_str(){
-            "Staff" => Some(Category::Staff),  // 🪄 ไม้เท้าวิเศษ
-            "Sword" => Some(Category::Sword),  // ⚔️ ดาบ
-            _ => None,                         // ❓ ประเภทที่ไม่รู้จัก
+    // ประเภทที่มีในระบบอยู่ใน catalog (ตาราง categories) - ที่ไม่ใช่ built-in เป็น Category::Catalog
+    pub fn get_category(&self) -> Category{
+        Category::from(self.category.clone())
+    }
+
+    // 💎 แปลง String rarity เป็น Rarity enum
+    pub fn get_rarity(&self) -> Option<Rarity>{
+        match self.rarity.as_str(){
+            "Common" => Some(Rarity::Common),
+            "Uncommon" => Some(Rarity::Uncommon),
+            "Rare" => Some(Rarity::Rare),
+            "Epic" => Some(Rarity::Epic),
+            "Legendary" => Some(Rarity::Legendary),
+            _ => None,
         }
     }
-}
\ No newline at end of file
+}
+
+// 🔄 Upserted: ผลของ upsert - เพิ่มใหม่ หรือแทนที่ item เดิมที่มีเจ้าของ + ประเภท + ชื่อเดียวกัน
+#[derive(Debug, Clone, PartialEq)]
+pub enum Upserted {
+    Created(Items), // ➕ ยังไม่มี -> เพิ่มใหม่
+    Updated(Items), // ✏️ มีอยู่แล้ว -> แทนที่ค่า (version + 1)
+}
+
+// 🔎 ItemsFilter: เงื่อนไขกรองรายการ Item (None = ไม่กรองด้วย field นั้น)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemsFilter {
+    pub rarity: Option<String>,    // 💎 ความหายาก
+    pub min_level: Option<i32>,    // 🎚️ required_level >= min_level
+    pub max_level: Option<i32>,    // 🎚️ required_level <= max_level
+    pub categories: Option<Vec<String>>, // 🌳 category อยู่ในรายการนี้ (ประเภทและลูกหลาน)
+    pub definition_id: Option<i32>, // 📜 instance ของแบบนี้
+    pub include_deleted: bool,     // 🗑️ รวม item ที่ถูกลบแล้ว (admin)
+    pub updated_from: Option<NaiveDateTime>, // ⏰ updated_at >= updated_from
+    pub updated_to: Option<NaiveDateTime>,   // ⏰ updated_at < updated_to
+    pub expression: Option<ItemsExpression>, // 🧮 เงื่อนไขจาก ?filter= (ต้องจริงด้วย)
+}
+
+// 🧮 ItemsExpression: เงื่อนไขแบบ RSQL ที่ parse แล้ว (models::filter)
+// 🔒 Postgres แปลงเป็น SQL ที่ bind ค่าทุกตัว (items_postgres::filtered_query)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemsExpression {
+    And(Vec<ItemsExpression>),     // ; ทุกเงื่อนไขต้องจริง
+    Or(Vec<ItemsExpression>),      // , เงื่อนไขใดเงื่อนไขหนึ่งจริง
+    Comparison(ItemsField, ItemsOperator, Vec<ItemsValue>), // field operator ค่า (in / out มีหลายค่า)
+}
+
+// 📋 ItemsField: field ที่กรองได้ (whitelist - ชื่อคอลัมน์ใน SQL มาจากที่นี่เท่านั้น)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemsField {
+    Id,
+    Name,
+    Category,
+    OwnerId,
+    Weight,
+    Rarity,
+    RequiredLevel,
+    DefinitionId,
+    Durability,
+    CreatedAt,
+    UpdatedAt,
+}
+
+// ⚖️ ItemsOperator: ตัวเปรียบเทียบ (Like / NotLike = == / != ที่มี * ในค่า)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemsOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    In,
+    NotIn,
+    Like,
+    NotLike,
+}
+
+// 🔢 ItemsValue: ค่าที่แปลงตามชนิดของ field แล้ว
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemsValue {
+    Integer(i32),
+    Text(String),                  // 🔤 Like / NotLike: * แทนข้อความใด ๆ
+    Timestamp(NaiveDateTime),
+}
+
+impl ItemsField {
+    pub const ALL: [ItemsField; 11] = [
+        Self::Id,
+        Self::Name,
+        Self::Category,
+        Self::OwnerId,
+        Self::Weight,
+        Self::Rarity,
+        Self::RequiredLevel,
+        Self::DefinitionId,
+        Self::Durability,
+        Self::CreatedAt,
+        Self::UpdatedAt,
+    ];
+
+    // 🗄️ ชื่อคอลัมน์ในตาราง items (และชื่อ field ใน filter)
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::Category => "category",
+            Self::OwnerId => "owner_id",
+            Self::Weight => "weight",
+            Self::Rarity => "rarity",
+            Self::RequiredLevel => "required_level",
+            Self::DefinitionId => "definition_id",
+            Self::Durability => "durability",
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+// 🔍 ItemsSearch: เงื่อนไขค้นหาชื่อ item (ไม่รวม item ที่ถูกลบ)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemsSearch {
+    pub query: String,             // 🔤 ข้อความที่ค้น (substring / สะกดใกล้เคียง / คำครบคำ)
+    pub categories: Option<Vec<String>>, // 🌳 category อยู่ในรายการนี้ (ประเภทและลูกหลาน)
+    pub min_similarity: f32,       // 📏 ความใกล้เคียงขั้นต่ำของชื่อที่สะกดผิด (0-1)
+    pub limit: i64,                // 🔢 จำนวนผลลัพธ์สูงสุด
+}
+
+// 🏆 ItemsSearched: item ที่ค้นเจอพร้อมคะแนนความเกี่ยวข้อง (มากก่อน)
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct ItemsSearched {
+    #[sqlx(flatten)]
+    pub item: Items,
+    pub score: f32,
+}
@@ -1 +1,6 @@
-pub mod items;
\ No newline at end of file
+pub mod categories;
+pub mod category_schemas;
+pub mod item_definitions;
+pub mod item_revisions;
+pub mod items;
+pub mod players;
\ No newline at end of file
@@ -0,0 +1,57 @@
+// === 🏛️ Domain Layer: Players Entity ===
+// 📖 ผู้เล่นในเกม - เป็นเจ้าของ Items ผ่าน items.owner_id
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Players entity เก็บเฉพาะข้อมูลของผู้เล่น ไม่รู้เรื่อง item ที่ครอบครอง
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ใช้ IntoTimerHelperShared abstraction แทนการสร้าง timestamp โดยตรง
+
+use chrono::NaiveDateTime;
+
+use crate::{
+    models::{
+        error::{APIError, IntoErrorResponse},
+        player::Player as PlayerModel,
+    },
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📦 Players struct: ข้อมูลหลักของผู้เล่นในระบบ
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq)]
+pub struct Players {
+    pub id: Option<i32>,           // 🆔 Primary key (None สำหรับข้อมูลใหม่)
+    pub name: String,              // 📝 ชื่อผู้เล่น (ไม่ซ้ำ)
+    pub tier: String,              // 🏅 ระดับผู้เล่น (ใช้เลือก limit ของกระเป๋า)
+    pub created_at: NaiveDateTime, // 📅 วันที่สร้าง
+    pub updated_at: NaiveDateTime, // 🔄 วันที่อัปเดตล่าสุด
+}
+
+impl Players {
+    // 🏗️ Constructor: สร้าง Players ใหม่
+    pub fn new(name: String, tier: String, t: IntoTimerHelperShared) -> Self {
+        Self {
+            id: None,
+            name,
+            tier,
+            created_at: t.now(),
+            updated_at: t.now(),
+        }
+    }
+
+    // 🔄 แปลง Entity เป็น Model สำหรับส่งผ่าน API
+    pub fn to_model(&self) -> Result<PlayerModel, Box<dyn IntoErrorResponse>> {
+        let id = match self.id {
+            Some(id) => id,
+            None => return Err(Box::new(APIError::MissingEntityId(self.name.clone()))),
+        };
+
+        Ok(PlayerModel {
+            id,
+            name: self.name.to_string(),
+            tier: self.tier.to_string(),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+// === 🌐 Interface Layer: Batch Handler ===
+// 📖 จัดการ HTTP request ของ batch-get และ batch mutation
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Handler แปลงรายงานเป็น HTTP status - การทำแต่ละ operation อยู่ใน BatchUsecase
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<BatchUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{Json, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    handlers::extract::{AuditActor, JsonBody},
+    models::batch::{BatchGetting, BatchMutating, BatchOperationStatus},
+    usecases::batch::BatchUsecase,
+};
+
+// 🔍 HTTP POST handler: อ่านหลาย item ตาม id - id ที่ไม่พบได้ status not_found (ตอบ 200 เสมอ)
+pub async fn items_batch_getting(
+    JsonBody(body): JsonBody<BatchGetting>,
+    batch_usecase: Arc<BatchUsecase>,
+) -> impl IntoResponse {
+    match batch_usecase.getting(body.ids).await {
+        Ok(items) => (StatusCode::OK, Json(items)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ✏️ HTTP POST handler: เพิ่ม/แก้ไข/ลบหลายรายการ ตอบรายงานราย operation
+// 📤 commit แล้ว -> 200 / all_or_nothing ถูกยกเลิก -> status ของรายการที่ล้ม (เช่น 404 / 409 / 422)
+pub async fn items_batch_mutating(
+    AuditActor(actor): AuditActor,
+    JsonBody(body): JsonBody<BatchMutating>,
+    batch_usecase: Arc<BatchUsecase>,
+) -> impl IntoResponse {
+    let report = match batch_usecase.mutating(body, &actor).await {
+        Ok(report) => report,
+        Err(e) => return e.error().into_response(),
+    };
+
+    let status = match report.committed {
+        true => StatusCode::OK,
+        false => report
+            .results
+            .iter()
+            .find(|result| result.status == BatchOperationStatus::Failed)
+            .and_then(|result| result.status_code)
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::CONFLICT),
+    };
+    (status, Json(report)).into_response()
+}
@@ -0,0 +1,94 @@
+// === 🌐 Interface Layer: Categories Handler ===
+// 📖 จัดการ HTTP requests ของ catalog ประเภท item (admin)
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<CategoriesUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{Json, extract::Path, http::StatusCode, response::IntoResponse};
+use serde_json::Value;
+
+use crate::{
+    handlers::extract::JsonBody,
+    models::category::{CategoryAdding, CategoryUpdating},
+    usecases::categories::CategoriesUsecase,
+};
+
+// 📋 HTTP GET handler: ดูประเภททั้งหมดใน catalog
+pub async fn categories_listing(categories_usecase: Arc<CategoriesUsecase>) -> impl IntoResponse {
+    match categories_usecase.listing().await {
+        Ok(categories) => (StatusCode::OK, Json(categories)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🌳 HTTP GET handler: ดูประเภทในรูป tree พร้อมกติกาที่สืบทอดแล้ว
+pub async fn categories_tree_getting(categories_usecase: Arc<CategoriesUsecase>) -> impl IntoResponse {
+    match categories_usecase.tree_getting().await {
+        Ok(tree) => (StatusCode::OK, Json(tree)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 📮 HTTP POST handler: เพิ่มประเภทใหม่
+pub async fn category_adding(
+    JsonBody(body): JsonBody<CategoryAdding>,
+    categories_usecase: Arc<CategoriesUsecase>,
+) -> impl IntoResponse {
+    match categories_usecase.adding(body).await {
+        Ok(category) => (StatusCode::CREATED, Json(category)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ✏️ HTTP PUT handler: แก้ชื่อ/ประเภทแม่/กติกา
+pub async fn category_updating(
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<CategoryUpdating>,
+    categories_usecase: Arc<CategoriesUsecase>,
+) -> impl IntoResponse {
+    match categories_usecase.updating(id, body).await {
+        Ok(category) => (StatusCode::OK, Json(category)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🗑️ HTTP DELETE handler: ลบประเภท
+pub async fn category_removing(
+    Path(id): Path<i32>,
+    categories_usecase: Arc<CategoriesUsecase>,
+) -> impl IntoResponse {
+    match categories_usecase.removing(id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🧾 HTTP GET handler: ดู JSON Schema ทุกเวอร์ชันของประเภท
+pub async fn category_schemas_listing(
+    Path(id): Path<i32>,
+    categories_usecase: Arc<CategoriesUsecase>,
+) -> impl IntoResponse {
+    match categories_usecase.schemas_listing(id).await {
+        Ok(schemas) => (StatusCode::OK, Json(schemas)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🧾 HTTP POST handler: เพิ่ม JSON Schema เวอร์ชันใหม่ของประเภท (body คือ schema)
+pub async fn category_schema_adding(
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<Value>,
+    categories_usecase: Arc<CategoriesUsecase>,
+) -> impl IntoResponse {
+    match categories_usecase.schema_adding(id, body).await {
+        Ok(schema) => (StatusCode::CREATED, Json(schema)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
@@ -0,0 +1,110 @@
+// === 📥 Interface Layer: Request Extractors ===
+// 📖 Extractor ที่แปลง error ตอนอ่าน request ให้อยู่ในรูปแบบ error ของโปรเจค
+
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{FromRequest, FromRequestParts, Query, Request, rejection::JsonRejection},
+    http::{StatusCode, request::Parts},
+};
+use serde::de::DeserializeOwned;
+use std::convert::Infallible;
+
+use crate::models::{
+    audit::Actor,
+    error::{APIError, ErrorResponse, IntoErrorResponse},
+};
+
+// 📦 JsonBody: เหมือน axum::Json แต่ตอบ error เป็น {"error": "..."}
+// ขนาด body สูงสุดมาจาก DefaultBodyLimit ที่ตั้งไว้ใน main.rs
+pub struct JsonBody<T>(pub T);
+
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ErrorResponse;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(body)) => Ok(Self(body)),
+            Err(rejection) => Err(rejection_error(rejection).error()),
+        }
+    }
+}
+
+// 📦 RawBody: body ทั้งก้อน (เช่นไฟล์นำเข้า) ที่ตอบ error เป็น {"error": "..."} เหมือน JsonBody
+pub struct RawBody(pub Bytes);
+
+impl<S> FromRequest<S> for RawBody
+where
+    S: Send + Sync,
+{
+    type Rejection = ErrorResponse;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Bytes::from_request(req, state).await {
+            Ok(body) => Ok(Self(body)),
+            Err(rejection) => Err(match rejection.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => APIError::PayloadTooLarge,
+                status_code => APIError::InvalidRequestBody(status_code, rejection.body_text()),
+            }
+            .error()),
+        }
+    }
+}
+
+// 🔄 แปลง JsonRejection ของ axum เป็น APIError
+fn rejection_error(rejection: JsonRejection) -> APIError {
+    match rejection.status() {
+        StatusCode::PAYLOAD_TOO_LARGE => APIError::PayloadTooLarge,
+        status_code => APIError::InvalidRequestBody(status_code, rejection.body_text()),
+    }
+}
+
+// 🔎 QueryParams: เหมือน axum::extract::Query แต่ตอบ error เป็น {"error": "..."}
+pub struct QueryParams<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for QueryParams<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ErrorResponse;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Query::<T>::from_request_parts(parts, state).await {
+            Ok(Query(query)) => Ok(Self(query)),
+            Err(rejection) => Err(APIError::InvalidQuery(rejection.body_text()).error()),
+        }
+    }
+}
+
+// 👤 AuditActor: ผู้แก้ไขจาก header X-Actor (ไม่ส่งมา = "anonymous") และ x-request-id
+// 📜 ใช้บันทึกประวัติการแก้ไข item - ระบบยังไม่มี authentication จึงเชื่อ header ตามที่ส่งมา
+pub struct AuditActor(pub Actor);
+
+impl<S> FromRequestParts<S> for AuditActor
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+
+        Ok(Self(Actor {
+            name: header("x-actor").unwrap_or_else(|| "anonymous".to_string()),
+            request_id: header("x-request-id"),
+        }))
+    }
+}
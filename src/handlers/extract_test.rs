@@ -0,0 +1,48 @@
+// === 🧪 Test Module: Request Extractor Tests ===
+// 📖 ทดสอบว่า body ที่เกิน DefaultBodyLimit ได้ 413 ในรูปแบบ error ของโปรเจค
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        extract::DefaultBodyLimit,
+        http::{Request, StatusCode, header},
+        routing::post,
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use crate::handlers::extract::{JsonBody, RawBody};
+
+    async fn posting(app: Router, path: &str, body: &str) -> (StatusCode, String) {
+        let request = Request::post(path)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    // 🧪 JsonBody / RawBody เกินขนาดได้ 413 / ไม่เกินผ่านตามปกติ
+    #[tokio::test]
+    async fn body_limit_test() {
+        let app = Router::new()
+            .route("/json", post(|JsonBody(body): JsonBody<Value>| async move { body.to_string() }))
+            .route("/raw", post(|RawBody(body): RawBody| async move { body.len().to_string() }))
+            .layer(DefaultBodyLimit::max(16));
+
+        let large = r#"{"name":"a very long item name"}"#;
+        for path in ["/json", "/raw"] {
+            assert_eq!(
+                posting(app.clone(), path, large).await,
+                (StatusCode::PAYLOAD_TOO_LARGE, r#"{"error":"Request body is too large"}"#.to_string())
+            );
+        }
+
+        assert_eq!(posting(app.clone(), "/json", r#"{"a":1}"#).await, (StatusCode::OK, r#"{"a":1}"#.to_string()));
+        assert_eq!(posting(app, "/raw", r#"{"a":1}"#).await, (StatusCode::OK, "7".to_string()));
+    }
+}
@@ -0,0 +1,43 @@
+// === 🌐 Interface Layer: History Handler ===
+// 📖 จัดการ HTTP requests ของประวัติการแก้ไข item และการย้อนกลับ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<HistoryUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::Path,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{handlers::extract::AuditActor, models::etag::Precondition, usecases::history::HistoryUsecase};
+
+// 📜 HTTP GET handler: ประวัติการแก้ไขของ item
+pub async fn item_history_listing(Path(id): Path<i32>, history_usecase: Arc<HistoryUsecase>) -> impl IntoResponse {
+    match history_usecase.listing(id).await {
+        Ok(revisions) => (StatusCode::OK, Json(revisions)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ⏪ HTTP POST handler: ย้อน item กลับไปเป็นค่าของ revision ที่ระบุ - If-Match ไม่ตรง -> 412
+pub async fn item_reverting(
+    AuditActor(actor): AuditActor,
+    Path((id, revision)): Path<(i32, i32)>,
+    headers: HeaderMap,
+    history_usecase: Arc<HistoryUsecase>,
+) -> impl IntoResponse {
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    match history_usecase.reverting(id, revision, Precondition::from_request(if_match, id, None), &actor).await {
+        Ok(item) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
@@ -0,0 +1,47 @@
+// === 🌐 Interface Layer: Import Handler ===
+// 📖 จัดการ HTTP request ของการนำเข้า item ทีละมาก ๆ (JSON array / NDJSON / CSV ตาม Content-Type)
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Handler เลือกรูปแบบไฟล์และส่งต่อ การอ่านไฟล์อยู่ใน models::import การตรวจอยู่ใน ImportUsecase
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<ImportUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{
+    handlers::extract::{AuditActor, QueryParams, RawBody},
+    models::{
+        error::IntoErrorResponse,
+        import::{ImportFormat, ImportQuery},
+    },
+    usecases::import::ImportUsecase,
+};
+
+// 📥 HTTP POST handler: นำเข้า item (?dry_run=true = ตรวจอย่างเดียว) ตอบรายงานรายแถว
+pub async fn items_importing(
+    AuditActor(actor): AuditActor,
+    QueryParams(query): QueryParams<ImportQuery>,
+    headers: HeaderMap,
+    RawBody(body): RawBody,                 // 📦 ขนาดสูงสุดจาก [import] max_body_bytes
+    import_usecase: Arc<ImportUsecase>,
+) -> impl IntoResponse {
+    let content_type = headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok());
+    let lines = match ImportFormat::from_content_type(content_type).and_then(|format| format.parsing(&body)) {
+        Ok(lines) => lines,
+        Err(e) => return e.error().into_response(),
+    };
+
+    match import_usecase.importing(lines, query.dry_run, &actor).await {
+        Ok(report) => (StatusCode::OK, Json(report)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
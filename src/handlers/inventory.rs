@@ -0,0 +1,65 @@
+// === 🌐 Interface Layer: Inventory Handler ===
+// 📖 จัดการ HTTP requests และ responses ของกระเป๋าผู้เล่น
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<InventoryUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::Path,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{
+    handlers::extract::{AuditActor, JsonBody},
+    models::{etag::Precondition, inventory::ItemTransferring, item::StaffAdding},
+    usecases::inventory::InventoryUsecase,
+};
+
+// 🎒 HTTP GET handler: ดูช่องและน้ำหนักที่ใช้ไปเทียบกับ limit
+pub async fn inventory_getting(
+    Path(id): Path<i32>,
+    inventory_usecase: Arc<InventoryUsecase>,
+) -> impl IntoResponse {
+    match inventory_usecase.getting(id).await {
+        Ok(inventory) => (StatusCode::OK, Json(inventory)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 📮 HTTP POST handler: เพิ่ม item เข้ากระเป๋าผู้เล่น (ตรวจ limit ของ tier)
+pub async fn inventory_storing(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<StaffAdding>,
+    inventory_usecase: Arc<InventoryUsecase>,
+) -> impl IntoResponse {
+    match inventory_usecase.storing(id, body, &actor).await {
+        Ok(item) => (StatusCode::CREATED, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🔁 HTTP POST handler: โอน item ให้ผู้เล่นอื่น (If-Match ไม่ตรง -> 412 / "version" ใน body ไม่ตรง -> 409)
+pub async fn item_transferring(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    JsonBody(body): JsonBody<ItemTransferring>,
+    inventory_usecase: Arc<InventoryUsecase>,
+) -> impl IntoResponse {
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    let precondition = Precondition::from_request(if_match, id, body.version);
+    match inventory_usecase.transferring(id, body.to_player_id, precondition, &actor).await {
+        Ok(item) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
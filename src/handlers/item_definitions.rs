@@ -0,0 +1,75 @@
+// === 🌐 Interface Layer: Item Definitions Handler ===
+// 📖 จัดการ HTTP requests ของแบบ item (designer) และการสร้าง instance จากแบบ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<ItemDefinitionsUsecase> / Arc<InstancesUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{Json, extract::Path, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    handlers::extract::{AuditActor, JsonBody},
+    models::item_definition::{InstanceSpawning, ItemDefinitionAdding},
+    usecases::{instances::InstancesUsecase, item_definitions::ItemDefinitionsUsecase},
+};
+
+// 📋 HTTP GET handler: ดูแบบทั้งหมด
+pub async fn item_definitions_listing(item_definitions_usecase: Arc<ItemDefinitionsUsecase>) -> impl IntoResponse {
+    match item_definitions_usecase.listing().await {
+        Ok(definitions) => (StatusCode::OK, Json(definitions)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🔍 HTTP GET handler: ดูแบบตาม ID
+pub async fn item_definition_getting(
+    Path(id): Path<i32>,
+    item_definitions_usecase: Arc<ItemDefinitionsUsecase>,
+) -> impl IntoResponse {
+    match item_definitions_usecase.getting(id).await {
+        Ok(definition) => (StatusCode::OK, Json(definition)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 📮 HTTP POST handler: เพิ่มแบบใหม่ (designer)
+pub async fn item_definition_adding(
+    JsonBody(body): JsonBody<ItemDefinitionAdding>,
+    item_definitions_usecase: Arc<ItemDefinitionsUsecase>,
+) -> impl IntoResponse {
+    match item_definitions_usecase.adding(body).await {
+        Ok(definition) => (StatusCode::CREATED, Json(definition)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ✏️ HTTP PUT handler: แก้แบบ (designer) - instance ที่มีอยู่ไม่เปลี่ยน
+pub async fn item_definition_updating(
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<ItemDefinitionAdding>,
+    item_definitions_usecase: Arc<ItemDefinitionsUsecase>,
+) -> impl IntoResponse {
+    match item_definitions_usecase.updating(id, body).await {
+        Ok(definition) => (StatusCode::OK, Json(definition)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🎲 HTTP POST handler: สร้าง instance จากแบบ (ส่ง owner_id = เข้ากระเป๋าผู้เล่น)
+pub async fn instance_spawning(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<InstanceSpawning>,
+    instances_usecase: Arc<InstancesUsecase>,
+) -> impl IntoResponse {
+    match instances_usecase.spawning(id, body, &actor).await {
+        Ok(item) => (StatusCode::CREATED, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
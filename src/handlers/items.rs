@@ -0,0 +1,107 @@
+// === 🌐 Interface Layer: Items Handler ===
+// 📖 จัดการ HTTP requests สำหรับอ่าน Item - รองรับ conditional GET ด้วย ETag / If-None-Match
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Handler แปลง HTTP <-> Use Case เท่านั้น การสร้าง ETag อยู่ใน models::etag
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<ItemsUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    body::Body,
+    extract::Path,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{
+    handlers::extract::QueryParams,         // 🔎 query string -> {"error": ...}
+    models::{
+        etag::{Tagged, none_matched},       // 🏷️ ETag
+        export::ExportQuery,                // 📤 รูปแบบและเงื่อนไขของ export
+        item::ItemsQuery,                   // 🔎 เงื่อนไขกรองรายการ
+    },
+    usecases::items::ItemsUsecase,          // 🧠 Use case สำหรับ business logic
+};
+
+// 📋 HTTP GET handler: ดูรายการ Item (กรองด้วย ?category=&definition_id=&rarity=&min_level=&max_level=&filter= ได้)
+pub async fn items_listing(
+    QueryParams(query): QueryParams<ItemsQuery>, // 🔎 เงื่อนไขกรองจาก query string
+    headers: HeaderMap,
+    items_usecase: Arc<ItemsUsecase>,
+) -> impl IntoResponse {
+    // 🗑️ include_deleted ใช้ได้เฉพาะ /admin/items
+    let query = ItemsQuery {
+        include_deleted: false,
+        ..query
+    };
+    match items_usecase.listing(query).await {
+        Ok(items) => tagged_response(&headers, items),
+        Err(e) => e.error().into_response(),                // ❌ ผิดพลาด: แปลง error เป็น HTTP response
+    }
+}
+
+// 📋 HTTP GET handler (admin): ดูรายการ Item เหมือน /items และรวม item ที่ถูกลบได้ด้วย ?include_deleted=true
+pub async fn admin_items_listing(
+    QueryParams(query): QueryParams<ItemsQuery>,
+    headers: HeaderMap,
+    items_usecase: Arc<ItemsUsecase>,
+) -> impl IntoResponse {
+    match items_usecase.listing(query).await {
+        Ok(items) => tagged_response(&headers, items),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 📤 HTTP GET handler: ส่งออก Item ทั้งตาราง (?format=ndjson|csv|json&category=&updated_from=&updated_to=)
+// 🌊 body เป็น stream ทีละแถว ไม่รวมทั้งไฟล์ไว้ในหน่วยความจำ
+pub async fn items_exporting(
+    QueryParams(query): QueryParams<ExportQuery>,
+    items_usecase: Arc<ItemsUsecase>,
+) -> impl IntoResponse {
+    let format = query.format;
+    match items_usecase.exporting(query).await {
+        Ok(stream) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, format.content_type().to_string()),
+                (header::CONTENT_DISPOSITION, format!("attachment; filename=\"items.{}\"", format.extension())),
+            ],
+            Body::from_stream(stream),
+        )
+            .into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🔍 HTTP GET handler: ดู Item ตาม ID
+pub async fn item_getting(
+    Path(id): Path<i32>,                    // 🆔 ID จาก path
+    headers: HeaderMap,                     // 🏷️ ใช้อ่าน If-None-Match
+    items_usecase: Arc<ItemsUsecase>,       // 🧠 Dependency injection: Use case
+) -> impl IntoResponse {
+    match items_usecase.getting(id).await {
+        Ok(item) => tagged_response(&headers, item),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 📤 ตอบ 304 ถ้า If-None-Match ตรงกับ ETag ไม่เช่นนั้นตอบ 200 พร้อมข้อมูล
+// ทั้งสองกรณีแนบ ETag header กลับไปเสมอ
+fn tagged_response<T: Serialize>(headers: &HeaderMap, tagged: Tagged<T>) -> Response {
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok());
+
+    if none_matched(if_none_match, &tagged.etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, tagged.etag)]).into_response();
+    }
+
+    (StatusCode::OK, [(header::ETAG, tagged.etag)], Json(tagged.value)).into_response()
+}
@@ -1 +1,13 @@
-pub mod staff;
\ No newline at end of file
+pub mod batch;
+pub mod categories;
+pub mod extract;
+pub mod extract_test;
+pub mod history;
+pub mod import;
+pub mod inventory;
+pub mod item_definitions;
+pub mod items;
+pub mod players;
+pub mod search;
+pub mod staff;
+pub mod trash;
\ No newline at end of file
@@ -0,0 +1,62 @@
+// === 🌐 Interface Layer: Players Handler ===
+// 📖 จัดการ HTTP requests และ responses ของผู้เล่น
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<PlayersUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{Json, extract::Path, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    handlers::extract::JsonBody,
+    models::player::{PlayerRegistering, PlayerTierUpdating},
+    usecases::players::PlayersUsecase,
+};
+
+// 📮 HTTP POST handler: สมัครผู้เล่นใหม่
+pub async fn player_registering(
+    JsonBody(body): JsonBody<PlayerRegistering>,
+    players_usecase: Arc<PlayersUsecase>,
+) -> impl IntoResponse {
+    match players_usecase.registering(body).await {
+        Ok(player) => (StatusCode::CREATED, Json(player)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🏅 HTTP PUT handler: เปลี่ยน tier ของผู้เล่น (admin)
+pub async fn player_tier_updating(
+    Path(id): Path<i32>,
+    JsonBody(body): JsonBody<PlayerTierUpdating>,
+    players_usecase: Arc<PlayersUsecase>,
+) -> impl IntoResponse {
+    match players_usecase.tier_updating(id, body).await {
+        Ok(player) => (StatusCode::OK, Json(player)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🔍 HTTP GET handler: ดูข้อมูลผู้เล่น
+pub async fn player_getting(Path(id): Path<i32>, players_usecase: Arc<PlayersUsecase>) -> impl IntoResponse {
+    match players_usecase.getting(id).await {
+        Ok(player) => (StatusCode::OK, Json(player)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🎒 HTTP GET handler: ดู item ทั้งหมดของผู้เล่น
+pub async fn player_items_listing(
+    Path(id): Path<i32>,
+    players_usecase: Arc<PlayersUsecase>,
+) -> impl IntoResponse {
+    match players_usecase.items_listing(id).await {
+        Ok(items) => (StatusCode::OK, Json(items)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
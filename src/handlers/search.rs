@@ -0,0 +1,31 @@
+// === 🌐 Interface Layer: Search Handler ===
+// 📖 จัดการ HTTP request ของการค้นหาชื่อ item
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    Handler อ่าน query string แล้วส่งต่อ - การค้นและให้คะแนนอยู่ใน SearchUsecase / ItemsRepository
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<SearchUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{Json, http::StatusCode, response::IntoResponse};
+
+use crate::{
+    handlers::extract::QueryParams,
+    models::search::ItemSearchQuery,
+    usecases::search::SearchUsecase,
+};
+
+// 🔎 HTTP GET handler: ค้นหาชื่อ item (?q=...&category=...&limit=...) ผลเรียงตามคะแนนพร้อมไฮไลต์
+pub async fn items_searching(
+    QueryParams(query): QueryParams<ItemSearchQuery>,
+    search_usecase: Arc<SearchUsecase>,
+) -> impl IntoResponse {
+    match search_usecase.searching(query).await {
+        Ok(result) => (StatusCode::OK, Json(result)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
@@ -20,25 +20,90 @@
 
 use std::sync::Arc;
 
-use axum::{http::StatusCode, response::IntoResponse, Json}; // 🌐 Axum web framework
+use axum::{extract::Path, http::{HeaderMap, StatusCode, header}, response::IntoResponse, Json}; // 🌐 Axum web framework
 
 use crate::{
-    models::item::StaffAdding,          // 📋 Input model สำหรับ API
+    handlers::extract::{AuditActor, JsonBody, RawBody}, // 📥 JSON extractor ที่ตอบ error ตามรูปแบบโปรเจค / ผู้แก้ไข
+    models::{
+        error::IntoErrorResponse,               // 📋 แปลง error ของ patch เป็น response
+        etag::Precondition,                     // 🔒 If-Match / version ใน body
+        item::{AttributesUpdating, StaffAdding, StaffUpserting}, // 📋 Input model สำหรับ API
+        patch::ItemPatch,                       // 🩹 Merge Patch / JSON Patch
+    },
     usecases::staff::StaffUsecase       // 🧠 Use case สำหรับ business logic
 };
 
 // 📮 HTTP POST handler: เพิ่ม Staff ใหม่
 // รับ JSON input และส่ง JSON response กลับ
 pub async fn staff_adding(
-    Json(body): Json<StaffAdding>,      // 📥 ดึงข้อมูล JSON จาก request body
+    AuditActor(actor): AuditActor,         // 👤 ผู้เพิ่ม (บันทึกในประวัติ)
+    JsonBody(body): JsonBody<StaffAdding>, // 📥 ดึงข้อมูล JSON จาก request body (จำกัดขนาดด้วย DefaultBodyLimit)
     staff_usecase: Arc<StaffUsecase>,   // 🧠 Dependency injection: Use case
 ) ->impl IntoResponse {
     // 🔄 เรียก Use Case เพื่อทำ business logic
-    let staff = match staff_usecase.adding(body).await{
+    let staff = match staff_usecase.adding(body, &actor).await{
         Ok(r) => r,                                      // ✅ สำเร็จ: ได้ข้อมูล Staff ใหม่
         Err(e) => return e.error().into_response(),      // ❌ ผิดพลาด: แปลง error เป็น HTTP response
     };
 
     // 📤 ส่ง response กลับ: HTTP 201 Created พร้อมข้อมูล Staff
     (StatusCode::CREATED, Json(staff)).into_response()
-}
\ No newline at end of file
+}
+
+// 🔄 HTTP PUT handler: เพิ่ม Staff ใหม่ หรือแทนที่ค่าของ Staff กลางของระบบที่ชื่อเดียวกัน
+// 📤 เพิ่มใหม่ -> 201 Created / แทนที่ของเดิม -> 200 OK
+// 🔒 แทนที่ของเดิม: If-Match ไม่ตรง -> 412 / "version" ใน body ไม่ตรง -> 409
+pub async fn staff_upserting(
+    AuditActor(actor): AuditActor,
+    headers: HeaderMap,
+    JsonBody(body): JsonBody<StaffUpserting>,
+    staff_usecase: Arc<StaffUsecase>,
+) -> impl IntoResponse {
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    match staff_usecase.upserting(body, if_match, &actor).await {
+        Ok((item, true)) => (StatusCode::CREATED, Json(item)).into_response(),
+        Ok((item, false)) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ✏️ HTTP PUT handler: แทนที่ attributes ของ item (attributes ผิด schema -> 422 พร้อม error ราย field)
+// 🔒 If-Match ไม่ตรง -> 412 / "version" ใน body ไม่ตรง -> 409
+pub async fn attributes_updating(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    JsonBody(body): JsonBody<AttributesUpdating>,
+    staff_usecase: Arc<StaffUsecase>,
+) -> impl IntoResponse {
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    let precondition = Precondition::from_request(if_match, id, body.version);
+    match staff_usecase.attributes_updating(id, body, precondition, &actor).await {
+        Ok(item) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// 🩹 HTTP PATCH handler: แก้ไข item บางส่วน
+// 📄 Content-Type: application/merge-patch+json (RFC 7396) หรือ application/json-patch+json (RFC 6902) - แบบอื่นได้ 415
+// 🔒 If-Match ไม่ตรง -> 412 / operation "test" ไม่ผ่าน -> 409 / path ไม่มีอยู่หรือผลลัพธ์ไม่ผ่านการตรวจ -> 422
+pub async fn item_patching(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    RawBody(body): RawBody,
+    staff_usecase: Arc<StaffUsecase>,
+) -> impl IntoResponse {
+    let content_type = headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok());
+    let patch = match ItemPatch::from_request(content_type, &body) {
+        Ok(patch) => patch,
+        Err(e) => return e.error().into_response(),
+    };
+
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    let precondition = Precondition::from_request(if_match, id, None);
+    match staff_usecase.patching(id, patch, precondition, &actor).await {
+        Ok(item) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
@@ -0,0 +1,47 @@
+// === 🌐 Interface Layer: Trash Handler ===
+// 📖 จัดการ HTTP requests ของการลบ item (soft delete) และการกู้คืน
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    แต่ละ function จัดการ HTTP request/response ของ endpoint เดียว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Arc<TrashUsecase> ที่ inject มาจาก main.rs
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::Path,
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{handlers::extract::AuditActor, models::etag::Precondition, usecases::trash::TrashUsecase};
+
+// 🗑️ HTTP DELETE handler: ลบ item (กู้คืนได้ภายในระยะเก็บรักษา) - If-Match ไม่ตรง -> 412
+pub async fn item_removing(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    headers: HeaderMap,
+    trash_usecase: Arc<TrashUsecase>,
+) -> impl IntoResponse {
+    let if_match = headers.get(header::IF_MATCH).and_then(|v| v.to_str().ok());
+    match trash_usecase.removing(id, Precondition::from_request(if_match, id, None), &actor).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
+
+// ♻️ HTTP POST handler: กู้คืน item ที่ถูกลบ
+pub async fn item_restoring(
+    AuditActor(actor): AuditActor,
+    Path(id): Path<i32>,
+    trash_usecase: Arc<TrashUsecase>,
+) -> impl IntoResponse {
+    match trash_usecase.restoring(id, &actor).await {
+        Ok(item) => (StatusCode::OK, Json(item)).into_response(),
+        Err(e) => e.error().into_response(),
+    }
+}
@@ -7,13 +7,20 @@
 // 4️⃣ อ่าน usecases - ตรรกะทางธุรกิจ (Application Layer)
 // 5️⃣ อ่าน handlers - จัดการ HTTP requests (Interface Layer)
 // 6️⃣ อ่าน models - โครงสร้างข้อมูลสำหรับ API
-// 7️⃣ อ่าง setting, database, time_helper - เครื่องมือช่วยเหลือ
+// 7️⃣ อ่าง setting, database, time_helper, roll_helper - เครื่องมือช่วยเหลือ
 
 pub mod database;      // 🗄️  การเชื่อมต่อฐานข้อมูล
 pub mod entities;      // 🏛️  Domain Layer - ข้อมูลหลักของระบบ
 pub mod handlers;      // 🌐  Interface Layer - จัดการ HTTP requests/responses
+pub mod middlewares;   // 🚦  Interface Layer - middleware ที่ครอบทุก route (rate limit ฯลฯ)
 pub mod models;        // 📋  Data Transfer Objects - โครงสร้างข้อมูลสำหรับ API
 pub mod repositories;  // 💾  Infrastructure Layer - เข้าถึงข้อมูลจากฐานข้อมูล
+pub mod roll_helper;   // 🎲  สุ่มค่าสถานะของ instance
+pub mod server;        // 🚀  การ bind/listen ของ server (TCP, TLS, Unix socket)
+pub mod server_test;   // 🧪  ทดสอบลำดับการเลือก listener
 pub mod setting;       // ⚙️  การตั้งค่าระบบ
+pub mod setting_test;  // 🧪  ทดสอบการตรวจค่าใน Setting.toml
 pub mod time_helper;   // ⏰  เครื่องมือจัดการเวลา
+pub mod tls;           // 🔐  HTTPS ด้วย rustls + reload certificate
+pub mod tls_test;      // 🧪  ทดสอบ URL ของ HTTP -> HTTPS redirect
 pub mod usecases;      // 🧠  Application Layer - ตรรกะทางธุรกิจ
\ No newline at end of file
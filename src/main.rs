@@ -19,22 +19,78 @@
 //    การสร้าง dependencies จากภายนอกและ inject เข้าไป
 //    ใช้ Arc สำหรับ shared ownership ของ dependencies
 
-use axum::{Router, http::Method, routing::post};
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, Request},
+    http::Method,
+    middleware,
+    routing::{get, post, put},
+};
 use rust_clean_architecture_v1::{
     database,                                          // 🗄️ จัดการการเชื่อมต่อฐานข้อมูล
-    handlers::staff::staff_adding,                     // 🌐 Handler - จัดการ HTTP requests
-    repositories::staff::StaffRepository,              // 💾 Repository - เข้าถึงฐานข้อมูล
+    handlers::{                                        // 🌐 Handler - จัดการ HTTP requests
+        batch::{items_batch_getting, items_batch_mutating},
+        categories::{
+            categories_listing, categories_tree_getting, category_adding, category_removing, category_schema_adding,
+            category_schemas_listing, category_updating,
+        },
+        history::{item_history_listing, item_reverting},
+        import::items_importing,
+        inventory::{inventory_getting, inventory_storing, item_transferring},
+        item_definitions::{
+            instance_spawning, item_definition_adding, item_definition_getting, item_definition_updating,
+            item_definitions_listing,
+        },
+        items::{admin_items_listing, item_getting, items_exporting, items_listing},
+        players::{player_getting, player_items_listing, player_registering, player_tier_updating},
+        search::items_searching,
+        staff::{attributes_updating, item_patching, staff_adding, staff_upserting},
+        trash::{item_removing, item_restoring},
+    },
+    middlewares::{                                     // 🚦 Middleware ที่ครอบทุก route
+        client_identity::{self, ClientIdentities},
+        compression,
+        concurrency,
+        idempotency::{self, IdempotencyStore},
+        panic::panic_handling,
+        rate_limit::{self, RateLimiter},
+        timeout::{self, RequestTimeouts},
+    },
+    repositories::{                                    // 💾 Repository - เข้าถึงฐานข้อมูล
+        categories_postgres::CategoriesPostgresRepository,
+        item_definitions_postgres::ItemDefinitionsPostgresRepository,
+        items_postgres::ItemsPostgresRepository,
+        players_postgres::PlayersPostgresRepository,
+        unit_of_work_postgres::PostgresUnitOfWork,
+    },
+    roll_helper::RollHelper,                           // 🎲 สุ่มค่าสถานะของ instance
+    server,                                            // 🚀 เลือกช่องทาง listen ของ server
     setting::Setting,                                  // ⚙️ การตั้งค่าระบบ
     time_helper::TimerHelper,                          // ⏰ เครื่องมือจัดการเวลา
-    usecases::staff::StaffUsecase,                     // 🧠 Use Case - ตรรกะทางธุรกิจ
+    usecases::{                                        // 🧠 Use Case - ตรรกะทางธุรกิจ
+        batch::BatchUsecase,
+        categories::CategoriesUsecase,
+        history::HistoryUsecase,
+        import::ImportUsecase,
+        instances::InstancesUsecase,
+        inventory::InventoryUsecase,
+        item_definitions::ItemDefinitionsUsecase,
+        items::ItemsUsecase,
+        players::PlayersUsecase,
+        search::SearchUsecase,
+        staff::StaffUsecase,
+        trash::TrashUsecase,
+    },
 };
-use std::{net::SocketAddr, sync::Arc};               // 🔧 Standard library tools
-use tokio::net::TcpListener;                          // 🌐 Async TCP listener
+use std::sync::Arc;                                   // 🔧 Standard library tools
 use tower_http::{                                     // 🔌 HTTP middleware
+    catch_panic::CatchPanicLayer,
+    compression::CompressionLayer,
     cors::{Any, CorsLayer},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     trace::TraceLayer,
 };
-use tracing::info;                                    // 📝 Logging
+use tracing::{info, info_span};                       // 📝 Logging
 
 #[tokio::main]
 async fn main() {
@@ -50,13 +106,90 @@ async fn main() {
     // 🗄️ ขั้นตอนที่ 3: เชื่อมต่อฐานข้อมูล (Infrastructure Layer)
     let db_pool = database::conn_getting(Arc::clone(&setting)).await.unwrap();
     info!("database connection has been established.");
+    database::migrations_running(&db_pool).await.unwrap();
+    info!("database migrations have been applied.");
 
     // 🏗️ ขั้นตอนที่ 4: สร้าง Dependencies ตาม Clean Architecture
     // สร้างตามลำดับ: Repository -> Helper -> Use Case
-    let staff_repository = StaffRepository::creation(db_pool.clone());  // 💾 Infrastructure Layer
+    let items_repository = ItemsPostgresRepository::creation(db_pool.clone()); // 💾 Item ทุกประเภท
     let timer_helper = TimerHelper::Directly.creation();                // ⏰ Utility
-    let staff_usecase =                                                  // 🧠 Application Layer
-        StaffUsecase::creation(Arc::clone(&staff_repository), Arc::clone(&timer_helper));
+    let categories_repository = CategoriesPostgresRepository::creation(db_pool.clone()); // 💾 Catalog ประเภท
+    let categories_usecase =                                             // 📚 Catalog + cache สำหรับตรวจประเภท
+        CategoriesUsecase::creation(categories_repository, Arc::clone(&timer_helper));
+    let unit_of_work = PostgresUnitOfWork::creation(db_pool.clone());   // 🔒 transaction ข้าม repository
+    let staff_usecase = StaffUsecase::creation(                          // 🧠 Application Layer
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+        Arc::clone(&categories_usecase),
+    );
+    let items_usecase = ItemsUsecase::creation(                          // 🧠 อ่าน Item + ETag
+        Arc::clone(&items_repository),
+        Arc::clone(&categories_usecase),
+    );
+    let players_repository = PlayersPostgresRepository::creation(db_pool.clone()); // 💾 ผู้เล่น
+    let players_usecase = PlayersUsecase::creation(                      // 🧠 ผู้เล่น + item ที่ครอบครอง
+        Arc::clone(&players_repository),
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+        setting.inventory.clone(),
+    );
+    let inventory_usecase = InventoryUsecase::creation(                  // 🧠 กติกาช่อง/น้ำหนักของกระเป๋า
+        Arc::clone(&items_repository),
+        Arc::clone(&players_repository),
+        Arc::clone(&unit_of_work),
+        Arc::clone(&timer_helper),
+        setting.inventory.clone(),
+        Arc::clone(&categories_usecase),
+    );
+    let item_definitions_repository = ItemDefinitionsPostgresRepository::creation(db_pool.clone()); // 💾 แบบของ item
+    let item_definitions_usecase = ItemDefinitionsUsecase::creation(     // 🧠 แบบของ item (designer)
+        Arc::clone(&item_definitions_repository),
+        Arc::clone(&timer_helper),
+        Arc::clone(&categories_usecase),
+    );
+    let instances_usecase = InstancesUsecase::creation(                  // 🧠 สร้าง instance จากแบบ
+        Arc::clone(&item_definitions_repository),
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+        RollHelper::Random.creation(),
+        Arc::clone(&categories_usecase),
+        Arc::clone(&inventory_usecase),
+    );
+    let trash_usecase = TrashUsecase::creation(                          // 🗑️ soft delete / กู้คืน / purge
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+        setting.trash.clone(),
+    );
+    trash_usecase.purge_scheduling();                                    // 🧹 ลบจริงเมื่อเกินระยะเก็บรักษา
+    let history_usecase = HistoryUsecase::creation(                      // 📜 ประวัติการแก้ไข + ย้อนกลับ
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+    );
+    let import_usecase = ImportUsecase::creation(                        // 📥 นำเข้า item ทีละมาก ๆ
+        Arc::clone(&items_repository),
+        Arc::clone(&timer_helper),
+        Arc::clone(&categories_usecase),
+        setting.import.clone(),
+    );
+    let batch_usecase = BatchUsecase::creation(                          // 📦 batch-get / batch mutation
+        Arc::clone(&items_repository),
+        Arc::clone(&unit_of_work),
+        Arc::clone(&timer_helper),
+        Arc::clone(&categories_usecase),
+        setting.batch.clone(),
+    );
+    let search_usecase = SearchUsecase::creation(                        // 🔎 ค้นหาชื่อ item
+        Arc::clone(&items_repository),
+        Arc::clone(&categories_usecase),
+        setting.search.clone(),
+    );
+    let client_identities = ClientIdentities::creation(&setting.rate_limit); // 🪪 API key / JWT subject / IP
+    let rate_limiter = RateLimiter::creation(&setting.rate_limit);       // 🚦 Token bucket ราย client
+    let request_timeouts = RequestTimeouts::creation(&setting.server);  // ⏱️ Timeout ราย route
+    let idempotency_store = IdempotencyStore::creation(                 // 🔑 response แรกของ Idempotency-Key
+        &setting.idempotency,
+        setting.server.max_body_bytes.max(setting.import.max_body_bytes),
+    );
 
     // 🌐 ขั้นตอนที่ 5: สร้าง Web Router และ Middleware (Interface Layer)
     let app = Router::new()
@@ -75,15 +208,267 @@ async fn main() {
             "/items/staff",                                             // 🛣️ Route definition
             post({                                                      // 📮 HTTP POST handler
                 let usecase = Arc::clone(&staff_usecase);               // 🔗 Dependency injection
-                move |body| staff_adding(body, usecase)                 // 🌐 Handler function
+                move |actor, body| staff_adding(actor, body, usecase)   // 🌐 Handler function
+            })
+            .put({                                                      // 🔄 เพิ่มหรือแทนที่ Staff ตามชื่อ
+                let usecase = Arc::clone(&staff_usecase);
+                move |actor, headers, body| staff_upserting(actor, headers, body, usecase)
             }),
         )
-        .layer(TraceLayer::new_for_http());                            // 📊 HTTP tracing middleware
-
-    // 🚀 ขั้นตอนที่ 6: เริ่มต้น Server
-    let addr = SocketAddr::from(([0, 0, 0, 0], setting.server.port as u16));
+        .route(
+            "/items/import",
+            post({                                                      // 📥 นำเข้า Item (JSON array / NDJSON / CSV)
+                let usecase = Arc::clone(&import_usecase);
+                move |actor, query, headers, body| items_importing(actor, query, headers, body, usecase)
+            })
+            .layer(DefaultBodyLimit::max(setting.import.max_body_bytes)), // 📦 ไฟล์นำเข้าใหญ่กว่า body ปกติ
+        )
+        .route(
+            "/items/batch-get",
+            post({                                                      // 🔍 อ่านหลาย item ตาม id
+                let usecase = Arc::clone(&batch_usecase);
+                move |body| items_batch_getting(body, usecase)
+            }),
+        )
+        .route(
+            "/items/batch",
+            post({                                                      // 📦 เพิ่ม/แก้ไข/ลบหลายรายการใน transaction เดียว
+                let usecase = Arc::clone(&batch_usecase);
+                move |actor, body| items_batch_mutating(actor, body, usecase)
+            }),
+        )
+        .route(
+            "/items",
+            get({                                                       // 📋 ดูรายการ Item (ETag)
+                let usecase = Arc::clone(&items_usecase);
+                move |query, headers| items_listing(query, headers, usecase)
+            }),
+        )
+        .route(
+            "/items/search",
+            get({                                                       // 🔎 ค้นหาชื่อ (บางส่วน / สะกดผิด / ภาษาไทย)
+                let usecase = Arc::clone(&search_usecase);
+                move |query| items_searching(query, usecase)
+            }),
+        )
+        .route(
+            "/items/export",
+            get({                                                       // 📤 ส่งออก Item (NDJSON / CSV / JSON แบบ stream)
+                let usecase = Arc::clone(&items_usecase);
+                move |query| items_exporting(query, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}",
+            get({                                                       // 🔍 ดู Item ตาม ID (ETag)
+                let usecase = Arc::clone(&items_usecase);
+                move |path, headers| item_getting(path, headers, usecase)
+            })
+            .patch({                                                    // 🩹 แก้ไขบางส่วน (Merge Patch / JSON Patch)
+                let usecase = Arc::clone(&staff_usecase);
+                move |actor, path, headers, body| item_patching(actor, path, headers, body, usecase)
+            })
+            .delete({                                                   // 🗑️ ลบ Item (soft delete)
+                let usecase = Arc::clone(&trash_usecase);
+                move |actor, path, headers| item_removing(actor, path, headers, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}/history",
+            get({                                                       // 📜 ประวัติการแก้ไขของ Item
+                let usecase = Arc::clone(&history_usecase);
+                move |path| item_history_listing(path, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}/history/{revision}/revert",
+            post({                                                      // ⏪ ย้อน Item กลับไปเป็นค่าของ revision
+                let usecase = Arc::clone(&history_usecase);
+                move |actor, path, headers| item_reverting(actor, path, headers, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}/restore",
+            post({                                                      // ♻️ กู้คืน Item ที่ถูกลบ
+                let usecase = Arc::clone(&trash_usecase);
+                move |actor, path| item_restoring(actor, path, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}/attributes",
+            put({                                                       // 🧩 แทนที่ attributes (ตรวจกับ JSON Schema)
+                let usecase = Arc::clone(&staff_usecase);
+                move |actor, path, headers, body| attributes_updating(actor, path, headers, body, usecase)
+            }),
+        )
+        .route(
+            "/items/{id}/transfer",
+            post({                                                      // 🔁 โอน Item ให้ผู้เล่นอื่น
+                let usecase = Arc::clone(&inventory_usecase);
+                move |actor, path, headers, body| item_transferring(actor, path, headers, body, usecase)
+            }),
+        )
+        .route(
+            "/item-definitions",
+            get({                                                       // 📜 ดูแบบของ item ทั้งหมด
+                let usecase = Arc::clone(&item_definitions_usecase);
+                move || item_definitions_listing(usecase)
+            }),
+        )
+        .route(
+            "/item-definitions/{id}",
+            get({                                                       // 📜 ดูแบบตาม ID
+                let usecase = Arc::clone(&item_definitions_usecase);
+                move |path| item_definition_getting(path, usecase)
+            }),
+        )
+        .route(
+            "/item-definitions/{id}/instances",
+            post({                                                      // 🎲 สร้าง instance จากแบบ
+                let usecase = Arc::clone(&instances_usecase);
+                move |actor, path, body| instance_spawning(actor, path, body, usecase)
+            }),
+        )
+        .route(
+            "/players",
+            post({                                                      // 🎮 สมัครผู้เล่น
+                let usecase = Arc::clone(&players_usecase);
+                move |body| player_registering(body, usecase)
+            }),
+        )
+        .route(
+            "/players/{id}",
+            get({                                                       // 🎮 ดูข้อมูลผู้เล่น
+                let usecase = Arc::clone(&players_usecase);
+                move |path| player_getting(path, usecase)
+            }),
+        )
+        .route(
+            "/players/{id}/items",
+            get({                                                       // 🎒 item ที่ผู้เล่นครอบครอง
+                let usecase = Arc::clone(&players_usecase);
+                move |path| player_items_listing(path, usecase)
+            })
+            .post({                                                     // 🎒 เพิ่ม item เข้ากระเป๋า (ตรวจ limit)
+                let usecase = Arc::clone(&inventory_usecase);
+                move |actor, path, body| inventory_storing(actor, path, body, usecase)
+            }),
+        )
+        .route(
+            "/players/{id}/inventory",
+            get({                                                       // 🎒 ช่อง/น้ำหนักที่ใช้ไป
+                let usecase = Arc::clone(&inventory_usecase);
+                move |path| inventory_getting(path, usecase)
+            }),
+        )
+        .route(
+            "/categories/tree",
+            get({                                                       // 🌳 tree ของประเภท + กติกาที่สืบทอด
+                let usecase = Arc::clone(&categories_usecase);
+                move || categories_tree_getting(usecase)
+            }),
+        )
+        .route(
+            "/admin/items",
+            get({                                                       // 📋 ดูรายการ Item (รวมที่ถูกลบได้)
+                let usecase = Arc::clone(&items_usecase);
+                move |query, headers| admin_items_listing(query, headers, usecase)
+            }),
+        )
+        .route(
+            "/admin/players/{id}/tier",
+            put({                                                       // 🏅 เปลี่ยน tier ของผู้เล่น
+                let usecase = Arc::clone(&players_usecase);
+                move |path, body| player_tier_updating(path, body, usecase)
+            }),
+        )
+        .route(
+            "/admin/categories",
+            get({                                                       // 📚 ดู catalog ประเภท
+                let usecase = Arc::clone(&categories_usecase);
+                move || categories_listing(usecase)
+            })
+            .post({                                                     // 📚 เพิ่มประเภท
+                let usecase = Arc::clone(&categories_usecase);
+                move |body| category_adding(body, usecase)
+            }),
+        )
+        .route(
+            "/admin/categories/{id}",
+            put({                                                       // 📚 แก้ชื่อ/ประเภทแม่/กติกา
+                let usecase = Arc::clone(&categories_usecase);
+                move |path, body| category_updating(path, body, usecase)
+            })
+            .delete({                                                   // 📚 ลบประเภท
+                let usecase = Arc::clone(&categories_usecase);
+                move |path| category_removing(path, usecase)
+            }),
+        )
+        .route(
+            "/admin/item-definitions",
+            post({                                                      // 📜 เพิ่มแบบของ item
+                let usecase = Arc::clone(&item_definitions_usecase);
+                move |body| item_definition_adding(body, usecase)
+            }),
+        )
+        .route(
+            "/admin/item-definitions/{id}",
+            put({                                                       // 📜 แก้แบบของ item
+                let usecase = Arc::clone(&item_definitions_usecase);
+                move |path, body| item_definition_updating(path, body, usecase)
+            }),
+        )
+        .route(
+            "/admin/categories/{id}/schemas",
+            get({                                                       // 🧾 ดู JSON Schema ทุกเวอร์ชัน
+                let usecase = Arc::clone(&categories_usecase);
+                move |path| category_schemas_listing(path, usecase)
+            })
+            .post({                                                     // 🧾 เพิ่ม JSON Schema เวอร์ชันใหม่
+                let usecase = Arc::clone(&categories_usecase);
+                move |path, body| category_schema_adding(path, body, usecase)
+            }),
+        )
+        .layer(middleware::from_fn_with_state(                          // 🔑 POST + Idempotency-Key ตอบ response แรกซ้ำ
+            idempotency_store,
+            idempotency::replaying,
+        ))
+        .layer(middleware::from_fn_with_state(                          // 🚦 Rate limit ราย client ราย route
+            rate_limiter,
+            rate_limit::limiting,
+        ))
+        .layer(middleware::from_fn_with_state(                          // 🪪 ระบุ client ครั้งเดียวให้ rate limit / Idempotency-Key
+            client_identities,
+            client_identity::client_identifying,
+        ))
+        .layer(concurrency::creation(                                   // 🏋️ จำกัด request พร้อมกันทั้งระบบ
+            setting.rate_limit.max_concurrent_requests,
+        ))
+        .layer(middleware::from_fn_with_state(                          // ⏱️ Timeout ราย route
+            request_timeouts,
+            timeout::timing_out,
+        ))
+        .layer(DefaultBodyLimit::max(setting.server.max_body_bytes))    // 📦 จำกัดขนาด request body
+        .layer(CatchPanicLayer::custom(panic_handling))                // 💥 panic -> 500 JSON
+        .layer(CompressionLayer::new())                                 // 🗜️ gzip/brotli/zstd ตาม Accept-Encoding
+        .layer(middleware::from_fn(compression::etag_encoding))        // 🏷️ ETag ของ body ที่บีบอัดต่อท้ายด้วย encoding
+        .layer(TraceLayer::new_for_http().make_span_with(|request: &Request| {
+            // 📊 HTTP tracing middleware - ใส่ request id ใน span เพื่อให้ทุก log ตามรอยได้
+            let request_id = request
+                .headers()
+                .get("x-request-id")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default();
+            info_span!(
+                "request",
+                method = %request.method(),
+                uri = %request.uri(),
+                request_id = %request_id,
+            )
+        }))
+        .layer(PropagateRequestIdLayer::x_request_id())                // 🔁 ส่ง x-request-id กลับใน response
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid));      // 🆔 สร้าง x-request-id ถ้า client ไม่ได้ส่งมา
 
-    let listener = TcpListener::bind(addr).await.unwrap();
-    info!("Server running on port {}", setting.server.port);
-    axum::serve(listener, app).await.unwrap();
+    // 🚀 ขั้นตอนที่ 6: เริ่มต้น Server (TCP/TLS, Unix socket หรือ socket activation ตาม Setting.toml)
+    server::serving(&setting.server, app).await.unwrap();
 }
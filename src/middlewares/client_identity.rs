@@ -0,0 +1,144 @@
+// === 🪪 Interface Layer: Client Identity Middleware ===
+// 📖 ระบุตัวตนของ client ครั้งเดียวต่อ request แล้วแนบ ClientKey ไว้ใน extensions
+//    rate limit และ Idempotency-Key ใช้ key เดียวกันนี้แยก bucket / key ของแต่ละ client
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ClientIdentities มีหน้าที่เดียว: แปลง request เป็น key ของ client ที่ยืนยันแล้ว
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เพิ่ม API key / เปลี่ยน secret ของ JWT ได้จาก Setting.toml โดยไม่แก้โค้ด
+//
+// 🔐 ลำดับ: X-API-Key ที่ตั้งไว้ใน [[rate_limit.api_keys]] -> sub ของ JWT (HS256) ที่ลงนามด้วย rate_limit.jwt_secret -> IP
+//    ค่าที่ตรวจไม่ผ่านใช้ IP เสมอ - client เปลี่ยน header เองเพื่อได้ bucket ใหม่ไม่ได้
+
+use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, header},
+    middleware::Next,
+    response::Response,
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::setting::RateLimit;
+
+// 🔗 Type alias สำหรับแชร์ ClientIdentities ระหว่าง request
+pub type SharedClientIdentities = Arc<ClientIdentities>;
+
+// 🏷️ key ของ client ที่ระบุแล้ว (อยู่ใน extensions ของ request)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientKey(pub String);
+
+// 📦 ClientIdentities: API key ที่รู้จัก และ secret ของ JWT
+pub struct ClientIdentities {
+    api_keys: HashMap<[u8; 32], String>, // 🔑 SHA-256 ของ API key -> ชื่อ (ไม่เก็บ key ตัวจริง / ค้นด้วย hash ไม่เทียบทีละ byte)
+    jwt_secret: Option<Vec<u8>>,         // 🎫 secret ของ HS256 (None = ไม่ใช้ JWT)
+}
+
+impl ClientIdentities {
+    // 🏗️ Factory method: สร้าง ClientIdentities จากการตั้งค่า
+    pub fn creation(setting: &RateLimit) -> SharedClientIdentities {
+        Arc::new(Self {
+            api_keys: setting
+                .api_keys
+                .iter()
+                .map(|api_key| (Sha256::digest(api_key.key.as_bytes()).into(), api_key.name.clone()))
+                .collect(),
+            jwt_secret: setting.jwt_secret.as_ref().map(|secret| secret.as_bytes().to_vec()),
+        })
+    }
+
+    // 🪪 key ของ client: "api_key:ชื่อ" / "sub:subject" / "ip:address" (now = วินาที unix ใช้ตรวจ exp)
+    pub fn identifying(&self, headers: &HeaderMap, peer: Option<SocketAddr>, now: i64) -> String {
+        if let Some(key) = headers.get("x-api-key").and_then(|v| v.to_str().ok())
+            && let Some(name) = self.api_keys.get(&<[u8; 32]>::from(Sha256::digest(key.trim().as_bytes())))
+        {
+            return format!("api_key:{}", name);
+        }
+
+        if let Some(token) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            && let Some(subject) = self.subject_verifying(token.trim(), now)
+        {
+            return format!("sub:{}", subject);
+        }
+
+        ip_key(headers, peer)
+    }
+
+    // 🎫 sub ของ JWT ที่ลงนามถูกต้องด้วย HS256 และยังไม่หมดอายุ (ไม่มี secret / ตรวจไม่ผ่าน = None)
+    fn subject_verifying(&self, token: &str, now: i64) -> Option<String> {
+        let secret = self.jwt_secret.as_ref()?;
+        let mut parts = token.split('.');
+        let (header, payload, signature) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let json = |part: &str| serde_json::from_slice::<Value>(&URL_SAFE_NO_PAD.decode(part).ok()?).ok();
+        if json(header)?.get("alg")?.as_str()? != "HS256" {
+            return None;                  // 🚫 ไม่รับ alg อื่น (รวม "none")
+        }
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).ok()?;
+        mac.update(format!("{}.{}", header, payload).as_bytes());
+        mac.verify_slice(&URL_SAFE_NO_PAD.decode(signature).ok()?).ok()?; // 🔐 เทียบแบบ constant time
+
+        let claims = json(payload)?;
+        if let Some(exp) = claims.get("exp") && exp.as_i64()? <= now {
+            return None;                  // ⏰ หมดอายุแล้ว
+        }
+        claims.get("sub")?.as_str().filter(|sub| !sub.is_empty()).map(str::to_string)
+    }
+}
+
+// 🌐 key จาก IP address (หรือ X-Forwarded-For บน Unix socket)
+pub fn ip_key(headers: &HeaderMap, peer: Option<SocketAddr>) -> String {
+    if let Some(addr) = peer {
+        return format!("ip:{}", addr.ip());
+    }
+
+    // 🧦 Unix socket ไม่มี peer IP: client ตัวจริงคือ reverse proxy ในเครื่อง
+    // จึงเชื่อค่าสุดท้ายของ X-Forwarded-For ที่ proxy เป็นคนเติม
+    match headers
+        .get("x-forwarded-for")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.rsplit(',').next())
+    {
+        Some(ip) => format!("ip:{}", ip.trim()),
+        None => "ip:unknown".to_string(),
+    }
+}
+
+// 🏷️ key ของ client ของ request นี้ - ไม่ผ่าน client_identifying (เช่นใน test) ใช้ IP
+pub fn client_key(request: &Request) -> String {
+    if let Some(ClientKey(key)) = request.extensions().get::<ClientKey>() {
+        return key.clone();
+    }
+    ip_key(request.headers(), peer_getting(request))
+}
+
+// 🪪 Middleware: ระบุตัวตนครั้งเดียวก่อน rate limit / Idempotency-Key
+pub async fn client_identifying(
+    State(identities): State<SharedClientIdentities>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let key = identities.identifying(request.headers(), peer_getting(&request), chrono::Utc::now().timestamp());
+    request.extensions_mut().insert(ClientKey(key));
+    next.run(request).await
+}
+
+fn peer_getting(request: &Request) -> Option<SocketAddr> {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr)
+}
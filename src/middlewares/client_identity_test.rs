@@ -0,0 +1,98 @@
+// === 🧪 Test Module: Client Identity Tests ===
+// 📖 ทดสอบลำดับการระบุ client: API key ที่ตั้งไว้ -> JWT ที่ตรวจผ่าน -> IP
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue};
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use crate::{
+        middlewares::client_identity::{ClientIdentities, ip_key},
+        setting::{ApiKey, RateLimit},
+    };
+
+    const NOW: i64 = 1_800_000_000;
+
+    fn identities() -> std::sync::Arc<ClientIdentities> {
+        ClientIdentities::creation(&RateLimit {
+            max_concurrent_requests: 1,
+            routes: Vec::new(),
+            api_keys: vec![ApiKey {
+                name: "partner-a".to_string(),
+                key: "key-a".to_string(),
+            }],
+            jwt_secret: Some("secret".to_string()),
+        })
+    }
+
+    // 🎫 JWT ที่ลงนามด้วย secret ที่กำหนด
+    fn jwt(header: &str, claims: &str, secret: &str) -> String {
+        let signing = format!("{}.{}", URL_SAFE_NO_PAD.encode(header), URL_SAFE_NO_PAD.encode(claims));
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(signing.as_bytes());
+        format!("{}.{}", signing, URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_str(&format!("Bearer {}", token)).unwrap());
+        headers
+    }
+
+    // 🧪 API key ที่ตั้งไว้มาก่อน JWT / key ที่ไม่รู้จักใช้ IP
+    #[test]
+    fn api_key_test() {
+        let peer = Some("10.0.0.1:5000".parse().unwrap());
+        let mut headers = bearer(&jwt(r#"{"alg":"HS256"}"#, r#"{"sub":"player-1"}"#, "secret"));
+        headers.insert("x-api-key", HeaderValue::from_static("key-a"));
+        assert_eq!(identities().identifying(&headers, peer, NOW), "api_key:partner-a");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("key-b"));
+        assert_eq!(identities().identifying(&headers, peer, NOW), "ip:10.0.0.1");
+    }
+
+    // 🧪 JWT ที่ลงนามถูกและยังไม่หมดอายุได้ sub / ลายเซ็นผิด / alg อื่น / หมดอายุ / ไม่มี sub ใช้ IP
+    #[test]
+    fn jwt_subject_test() {
+        let peer = Some("10.0.0.1:5000".parse().unwrap());
+        let identify = |token: String| identities().identifying(&bearer(&token), peer, NOW);
+
+        assert_eq!(identify(jwt(r#"{"alg":"HS256"}"#, r#"{"sub":"player-1"}"#, "secret")), "sub:player-1");
+        assert_eq!(
+            identify(jwt(r#"{"alg":"HS256"}"#, &format!(r#"{{"sub":"player-1","exp":{}}}"#, NOW + 60), "secret")),
+            "sub:player-1"
+        );
+        assert_eq!(identify(jwt(r#"{"alg":"HS256"}"#, r#"{"sub":"player-1"}"#, "forged")), "ip:10.0.0.1");
+        assert_eq!(identify(jwt(r#"{"alg":"none"}"#, r#"{"sub":"player-1"}"#, "secret")), "ip:10.0.0.1");
+        assert_eq!(
+            identify(jwt(r#"{"alg":"HS256"}"#, &format!(r#"{{"sub":"player-1","exp":{}}}"#, NOW), "secret")),
+            "ip:10.0.0.1"
+        );
+        assert_eq!(identify(jwt(r#"{"alg":"HS256"}"#, r#"{"name":"x"}"#, "secret")), "ip:10.0.0.1");
+        assert_eq!(identify("e30.eyJzdWIiOiJwbGF5ZXItMSJ9.sig".to_string()), "ip:10.0.0.1");
+
+        // 🚫 ไม่ตั้ง jwt_secret = ไม่เชื่อ JWT เลย
+        let identities = ClientIdentities::creation(&RateLimit {
+            max_concurrent_requests: 1,
+            routes: Vec::new(),
+            api_keys: Vec::new(),
+            jwt_secret: None,
+        });
+        let token = jwt(r#"{"alg":"HS256"}"#, r#"{"sub":"player-1"}"#, "secret");
+        assert_eq!(identities.identifying(&bearer(&token), peer, NOW), "ip:10.0.0.1");
+    }
+
+    // 🧪 IP: peer ของ TCP / Unix socket ใช้ค่าสุดท้ายของ X-Forwarded-For ที่ proxy เติม
+    #[test]
+    fn ip_key_test() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ip_key(&headers, Some("10.0.0.1:5000".parse().unwrap())), "ip:10.0.0.1");
+        assert_eq!(ip_key(&headers, None), "ip:unknown");
+
+        headers.insert("x-forwarded-for", HeaderValue::from_static("1.2.3.4, 10.0.0.9"));
+        assert_eq!(ip_key(&headers, None), "ip:10.0.0.9");
+    }
+}
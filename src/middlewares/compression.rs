@@ -0,0 +1,83 @@
+// === 🗜️ Interface Layer: ETag ของ response ที่ถูกบีบอัด ===
+// 📖 CompressionLayer ส่ง body คนละ byte ตาม Accept-Encoding แต่ handler แนบ strong ETag เดียวกัน
+//    strong ETag ต้องต่างกันเมื่อ byte ต่างกัน จึงต่อท้ายด้วย content coding เช่น "3-v4" -> "3-v4-gzip"
+//    ขาเข้าตัด suffix ออกจาก If-None-Match / If-Match ก่อนถึง handler - version ของ item เดียวกันไม่ว่าจะบีบอัดแบบไหน
+// 🎯 SOLID: Single Responsibility - handler ไม่ต้องรู้ว่า response จะถูกบีบอัดหรือไม่
+
+use axum::{
+    extract::Request,
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+
+// 🗜️ content coding ที่ CompressionLayer เลือกได้
+const ENCODINGS: [&str; 4] = ["gzip", "deflate", "br", "zstd"];
+
+// 🔄 ใช้กับ middleware::from_fn - ต้องครอบ CompressionLayer (layer ที่อยู่ถัดออกมา)
+pub async fn etag_encoding(mut request: Request, next: Next) -> Response {
+    let if_none_match = header_string(request.headers(), &header::IF_NONE_MATCH);
+    for name in [header::IF_NONE_MATCH, header::IF_MATCH] {
+        if let Some(value) = header_string(request.headers(), &name)
+            && let Ok(decoded) = HeaderValue::from_str(&tags_decoding(&value))
+        {
+            request.headers_mut().insert(name, decoded);
+        }
+    }
+
+    let mut response = next.run(request).await;
+    let Some(etag) = header_string(response.headers(), &header::ETAG) else {
+        return response;
+    };
+
+    let encoded = match header_string(response.headers(), &header::CONTENT_ENCODING) {
+        Some(encoding) => encoded_etag(&etag, &encoding),
+        // 🔁 304 ไม่มี body ให้บีบอัด - ตอบ ETag ตัวเดียวกับที่ client ถืออยู่ (รวม suffix)
+        None if response.status() == StatusCode::NOT_MODIFIED => if_none_match
+            .as_deref()
+            .and_then(|header| header.split(',').map(str::trim).find(|tag| tag_decoding(tag) == etag))
+            .map(str::to_string),
+        None => None,
+    };
+    if let Some(encoded) = encoded
+        && let Ok(value) = HeaderValue::from_str(&encoded)
+    {
+        response.headers_mut().insert(header::ETAG, value);
+    }
+    response
+}
+
+// 🏷️ strong ETag ของ body ที่บีบอัดด้วย encoding - weak ETag / encoding ที่ไม่รู้จักไม่เปลี่ยน (None)
+pub fn encoded_etag(etag: &str, encoding: &str) -> Option<String> {
+    let opaque = etag.strip_prefix('"')?.strip_suffix('"')?;
+    if !ENCODINGS.contains(&encoding) {
+        return None;
+    }
+    Some(format!("\"{}-{}\"", opaque, encoding))
+}
+
+// ✂️ ตัด suffix ของ content coding ออกจากทุก tag ใน If-None-Match / If-Match
+pub fn tags_decoding(header: &str) -> String {
+    header.split(',').map(str::trim).map(tag_decoding).collect::<Vec<_>>().join(", ")
+}
+
+// ✂️ tag เดียว: "3-v4-gzip" -> "3-v4" (W/ คงไว้)
+fn tag_decoding(tag: &str) -> String {
+    let (weak, quoted) = match tag.strip_prefix("W/") {
+        Some(quoted) => ("W/", quoted),
+        None => ("", tag),
+    };
+    let decoded = ENCODINGS.iter().find_map(|encoding| {
+        quoted
+            .strip_suffix('"')?
+            .strip_suffix(encoding)?
+            .strip_suffix('-')
+            .map(|opaque| format!("{}{}\"", weak, opaque))
+    });
+    decoded.unwrap_or_else(|| tag.to_string())
+}
+
+// 📥 ค่า header เป็น String (ไม่ใช่ ASCII = ไม่มี)
+fn header_string(headers: &HeaderMap, name: &HeaderName) -> Option<String> {
+    headers.get(name).and_then(|v| v.to_str().ok()).map(str::to_string)
+}
@@ -0,0 +1,25 @@
+// === 🧪 Test Module: Compression ETag Tests ===
+// 📖 ทดสอบการต่อ / ตัด suffix ของ content coding ใน ETag
+
+#[cfg(test)]
+mod tests {
+    use crate::middlewares::compression::{encoded_etag, tags_decoding};
+
+    // 🧪 strong ETag ได้ suffix ตาม encoding / weak ETag และ encoding ที่ไม่รู้จักไม่เปลี่ยน
+    #[test]
+    fn encoded_etag_test() {
+        assert_eq!(encoded_etag("\"3-v4\"", "gzip"), Some("\"3-v4-gzip\"".to_string()));
+        assert_eq!(encoded_etag("\"3-v4\"", "br"), Some("\"3-v4-br\"".to_string()));
+        assert_eq!(encoded_etag("W/\"3-v4\"", "gzip"), None);
+        assert_eq!(encoded_etag("\"3-v4\"", "identity"), None);
+    }
+
+    // 🧪 If-None-Match / If-Match ที่ client ส่งกลับมาได้ ETag เดิมของ handler
+    #[test]
+    fn tags_decoding_test() {
+        assert_eq!(tags_decoding("\"3-v4-gzip\""), "\"3-v4\"");
+        assert_eq!(tags_decoding("\"3-v4-zstd\", W/\"3-v3-br\""), "\"3-v4\", W/\"3-v3\"");
+        assert_eq!(tags_decoding("\"3-v4\""), "\"3-v4\"");
+        assert_eq!(tags_decoding("*"), "*");
+    }
+}
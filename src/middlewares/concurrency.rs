@@ -0,0 +1,41 @@
+// === 🏋️ Interface Layer: Concurrency Limit & Load Shedding ===
+// 📖 จำกัดจำนวน request ที่ทำงานพร้อมกันทั้งระบบ เพื่อไม่ให้ request ไปรอ connection จาก sqlx pool
+//    ถ้าเต็มแล้วจะตอบ 503 ทันที (load shed) แทนการต่อคิวจน timeout
+
+use axum::{
+    BoxError,
+    error_handling::HandleErrorLayer,
+    response::{IntoResponse, Response},
+};
+use tower::{
+    ServiceBuilder,
+    layer::util::{Identity, Stack},
+    limit::GlobalConcurrencyLimitLayer,
+    load_shed::LoadShedLayer,
+};
+
+use crate::models::error::{APIError, IntoErrorResponse};
+
+// 🔗 Type alias ของ layer ที่ประกอบเสร็จแล้ว
+pub type ConcurrencyLayer = ServiceBuilder<
+    Stack<
+        GlobalConcurrencyLimitLayer,
+        Stack<LoadShedLayer, Stack<HandleErrorLayer<OverloadedHandler, ()>, Identity>>,
+    >,
+>;
+type OverloadedHandler = fn(BoxError) -> std::future::Ready<Response>;
+
+// 🏗️ Factory method: สร้าง layer ที่รับ request พร้อมกันได้ไม่เกิน max_concurrent_requests
+// ใช้ GlobalConcurrencyLimitLayer เพราะ Router::layer จะ clone layer ให้ทุก route
+// และทุก route ต้องแชร์ semaphore ตัวเดียวกัน
+pub fn creation(max_concurrent_requests: usize) -> ConcurrencyLayer {
+    ServiceBuilder::new()
+        .layer(HandleErrorLayer::new(overloaded as OverloadedHandler))
+        .layer(LoadShedLayer::new())
+        .layer(GlobalConcurrencyLimitLayer::new(max_concurrent_requests))
+}
+
+// ❌ แปลง error จาก load shed เป็น 503 ในรูปแบบ error ของโปรเจค
+fn overloaded(_: BoxError) -> std::future::Ready<Response> {
+    std::future::ready(APIError::ServiceOverloaded.error().into_response())
+}
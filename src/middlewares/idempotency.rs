@@ -0,0 +1,355 @@
+// === 🔑 Interface Layer: Idempotency-Key Middleware ===
+// 📖 POST ที่ส่ง header Idempotency-Key: เก็บ response แรก (status + headers + body) ไว้ตาม client และ key
+//    retry ด้วย key เดิมและ request เดิมได้ response แรกกลับไปโดยไม่ทำงานซ้ำ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    IdempotencyStore มีหน้าที่เดียว: จำว่า key ไหนของ client ไหนตอบอะไรไปแล้ว
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    Handler ไม่รู้เลยว่ามี Idempotency-Key - middleware ครอบไว้จากภายนอก
+//
+// ⚠️ เก็บในหน่วยความจำของ instance เดียวกับ rate limit - หลาย instance ต้องให้ client ติดกับ instance เดิม
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, Hasher, RandomState},
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    body::{Body, Bytes, to_bytes},
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use lru::LruCache;
+
+use crate::{
+    middlewares::client_identity::client_key,
+    models::error::{APIError, IntoErrorResponse},
+    setting::Idempotency,
+};
+
+// 🏷️ header ที่ client ส่งมา / header ที่บอกว่าเป็น response ที่ตอบซ้ำ
+const IDEMPOTENCY_KEY: &str = "idempotency-key";
+const IDEMPOTENT_REPLAYED: &str = "idempotent-replayed";
+
+// 📏 ความยาวสูงสุดของ key
+const MAX_KEY_LENGTH: usize = 255;
+
+// 🔗 Type alias สำหรับแชร์ IdempotencyStore ระหว่าง request
+pub type SharedIdempotencyStore = Arc<IdempotencyStore>;
+
+// 📦 response ที่เก็บไว้ตอบซ้ำ
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredResponse {
+    pub status: StatusCode,
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+    pub body: Bytes,
+}
+
+impl StoredResponse {
+    // 📏 byte ที่ใช้เก็บ (body + headers) - นับรวมกับ max_total_bytes
+    pub fn size(&self) -> usize {
+        self.body.len()
+            + self
+                .headers
+                .iter()
+                .map(|(name, value)| name.as_str().len() + value.len())
+                .sum::<usize>()
+    }
+}
+
+// 🚦 ผลของการเริ่ม request ด้วย key หนึ่ง
+#[derive(Debug, Clone, PartialEq)]
+pub enum Admission {
+    Proceed,                // ✅ key ใหม่ (หรือหมดอายุแล้ว) ให้ handler ทำงาน
+    Replay(StoredResponse), // 🔁 request เดิม ตอบ response แรกซ้ำ
+    Mismatch,               // ❌ key เดิมแต่ request ต่างจากครั้งแรก
+    InProgress,             // ⏳ ครั้งแรกยังไม่เสร็จ
+    Exhausted,              // 👤 key ของ client นี้ครบเพดานและยังทำงานอยู่ทุกตัว
+}
+
+// 🔑 key หนึ่งของ client หนึ่งราย (response = None คือครั้งแรกยังทำงานอยู่)
+struct Entry {
+    fingerprint: u64,
+    response: Option<StoredResponse>,
+    started_at: Instant,
+}
+
+// 🗃️ key ที่จำไว้ พร้อมยอดรวมที่ใช้ตรวจเพดาน
+struct Entries {
+    cache: LruCache<(String, String), Entry>, // 🔑 (client key, Idempotency-Key) -> entry (ไม่ได้ใช้นานสุดถูกลืมก่อน)
+    total_bytes: usize,                       // 📦 ขนาด response ที่เก็บอยู่รวมกัน
+    per_client: HashMap<String, usize>,       // 👤 client key -> จำนวน key ที่จำไว้
+}
+
+impl Entries {
+    // 🧾 ปรับยอดรวมเมื่อ entry ออกจาก cache (ลบเอง / หมดอายุ / ถูก LRU ไล่ออก)
+    fn forgetting(&mut self, client: &str, entry: &Entry) {
+        self.total_bytes -= entry.response.as_ref().map_or(0, StoredResponse::size);
+        if let Some(count) = self.per_client.get_mut(client) {
+            *count -= 1;
+            if *count == 0 {
+                self.per_client.remove(client);
+            }
+        }
+    }
+
+    fn removing(&mut self, id: &(String, String)) {
+        if let Some(entry) = self.cache.pop(id) {
+            self.forgetting(&id.0, &entry);
+        }
+    }
+}
+
+// 📦 IdempotencyStore: key ที่เคยใช้ของแต่ละ client
+// 🧹 จำกัดทั้งจำนวน key / ขนาด response รวม / จำนวน key ต่อ client - ลืม key เก่าได้ก่อนหมดอายุ (retry ของ key นั้นจะทำงานซ้ำ)
+pub struct IdempotencyStore {
+    ttl: Duration,                                   // ⏰ อายุของ key
+    max_request_bytes: usize,                        // 📦 body ที่อ่านได้สูงสุด (เท่ากับ limit ที่ใหญ่ที่สุดของ route)
+    max_response_bytes: usize,                       // 📦 response ที่ใหญ่กว่านี้ไม่เก็บ
+    max_total_bytes: usize,                          // 📦 ขนาด response รวมที่เก็บได้
+    max_keys_per_client: usize,                      // 👤 key ต่อ client
+    hasher: RandomState,                             // #️⃣ hash ของ request (ใช้ภายใน instance เท่านั้น)
+    entries: Mutex<Entries>,                         // 🗃️ key ที่จำไว้
+}
+
+impl IdempotencyStore {
+    // 🏗️ Factory method: สร้าง IdempotencyStore จากการตั้งค่า
+    pub fn creation(setting: &Idempotency, max_request_bytes: usize) -> SharedIdempotencyStore {
+        Arc::new(Self {
+            ttl: Duration::from_secs(setting.ttl_secs),
+            max_request_bytes,
+            max_response_bytes: setting.max_response_bytes,
+            max_total_bytes: setting.max_total_bytes,
+            max_keys_per_client: setting.max_keys_per_client,
+            hasher: RandomState::new(),
+            entries: Mutex::new(Entries {
+                cache: LruCache::new(NonZeroUsize::new(setting.max_entries).unwrap_or(NonZeroUsize::MIN)),
+                total_bytes: 0,
+                per_client: HashMap::new(),
+            }),
+        })
+    }
+
+    // #️⃣ ลายมือชื่อของ request: method + path/query + body
+    pub fn fingerprint(&self, method: &Method, uri: &str, body: &[u8]) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        method.as_str().hash(&mut hasher);
+        uri.hash(&mut hasher);
+        body.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // 🚦 เริ่ม request ด้วย key นี้ - key ใหม่จะถูกจองไว้จนกว่าจะ completing หรือ releasing
+    pub fn admitting(&self, client: &str, key: &str, fingerprint: u64, now: Instant) -> Admission {
+        let mut entries = self.entries.lock().unwrap();
+        let id = (client.to_string(), key.to_string());
+        match entries.cache.get(&id) {
+            Some(entry) if !self.expired(entry, now) => {
+                return match (&entry.response, entry.fingerprint == fingerprint) {
+                    (_, false) => Admission::Mismatch,
+                    (Some(response), true) => Admission::Replay(response.clone()),
+                    (None, true) => Admission::InProgress,
+                };
+            }
+            Some(_) => entries.removing(&id), // ⏰ หมดอายุแล้ว
+            None => (),
+        }
+
+        // 👤 client ครบเพดาน: ลืม key ที่เสร็จแล้วและไม่ได้ใช้นานที่สุดของ client นี้ (key ที่ยังทำงานอยู่ไม่ลืม)
+        if entries.per_client.get(client).is_some_and(|count| *count >= self.max_keys_per_client) {
+            let oldest = entries
+                .cache
+                .iter()
+                .rev()
+                .find(|((owner, _), entry)| owner == client && entry.response.is_some())
+                .map(|(id, _)| id.clone());
+            match oldest {
+                Some(oldest) => entries.removing(&oldest),
+                None => return Admission::Exhausted,
+            }
+        }
+
+        let entry = Entry {
+            fingerprint,
+            response: None,
+            started_at: now,
+        };
+        *entries.per_client.entry(client.to_string()).or_default() += 1;
+        // 🧹 cache เต็ม: key ที่ไม่ได้ใช้นานที่สุดถูกไล่ออก
+        if let Some((evicted, entry)) = entries.cache.push(id, entry) {
+            entries.forgetting(&evicted.0, &entry);
+        }
+        Admission::Proceed
+    }
+
+    // 💾 เก็บ response แรกของ key (อายุนับจากตอนเริ่ม request) - ส่งกลับว่าได้เก็บหรือไม่
+    // 📦 ขนาดรวมเกินเพดาน: ลืม key ที่ไม่ได้ใช้นานที่สุดจนพอ (ถ้าต้องลืม key นี้เองก็ไม่เก็บ)
+    pub fn completing(&self, client: &str, key: &str, response: StoredResponse) -> bool {
+        let size = response.size();
+        if size > self.max_response_bytes.min(self.max_total_bytes) {
+            return false;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        let id = (client.to_string(), key.to_string());
+        if !entries.cache.contains(&id) {
+            return false;                 // 🧹 ถูกไล่ออกระหว่างรอ handler
+        }
+        while entries.total_bytes + size > self.max_total_bytes {
+            let Some((evicted, entry)) = entries.cache.pop_lru() else {
+                return false;
+            };
+            entries.forgetting(&evicted.0, &entry);
+            if evicted == id {
+                return false;
+            }
+        }
+        match entries.cache.get_mut(&id) {
+            Some(entry) => entry.response = Some(response),
+            None => return false,
+        }
+        entries.total_bytes += size;
+        true
+    }
+
+    // 🔓 ปล่อย key ที่จองไว้ (ครั้งแรกล้มหรือไม่ได้เก็บ) ให้ retry ทำงานใหม่ได้
+    pub fn releasing(&self, client: &str, key: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        let id = (client.to_string(), key.to_string());
+        if entries.cache.peek(&id).is_some_and(|entry| entry.response.is_none()) {
+            entries.removing(&id);
+        }
+    }
+
+    fn expired(&self, entry: &Entry, now: Instant) -> bool {
+        now.saturating_duration_since(entry.started_at) >= self.ttl
+    }
+}
+
+// 🔓 จอง key ไว้ระหว่างรอ handler - ถ้า request ถูกยกเลิกกลางทาง (เช่น timeout) จะปล่อย key ให้เอง
+struct Reservation<'a> {
+    store: &'a IdempotencyStore,
+    client: &'a str,
+    key: &'a str,
+    settled: bool,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        if !self.settled {
+            self.store.releasing(self.client, self.key);
+        }
+    }
+}
+
+// 🔑 ตรวจรูปแบบของ key: ASCII ที่พิมพ์ได้ ยาว 1-255 ตัว
+pub fn key_validating(value: &HeaderValue) -> Result<String, APIError> {
+    let key = match value.to_str() {
+        Ok(key) => key.trim(),
+        Err(_) => return Err(APIError::InvalidIdempotencyKey("must be printable ASCII".to_string())),
+    };
+    if key.is_empty() || key.len() > MAX_KEY_LENGTH {
+        return Err(APIError::InvalidIdempotencyKey(format!(
+            "length must be 1-{} characters",
+            MAX_KEY_LENGTH
+        )));
+    }
+    Ok(key.to_string())
+}
+
+// 🔑 Middleware: POST ที่มี Idempotency-Key ผ่าน store ก่อนถึง handler
+// 💥 response 5xx ไม่ถูกเก็บ เพื่อให้ retry ได้ทำงานใหม่
+pub async fn replaying(
+    State(store): State<SharedIdempotencyStore>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let key = match request.headers().get(IDEMPOTENCY_KEY) {
+        Some(value) => match key_validating(value) {
+            Ok(key) => key,
+            Err(e) => return e.error().into_response(),
+        },
+        None => return next.run(request).await,
+    };
+    // 🔑 client เดียวกับ rate limit (API key / JWT subject / IP) - client หลัง NAT เดียวกันที่ไม่ยืนยันตัวตนใช้ key ร่วมกัน จึงควรใช้ UUID เป็น Idempotency-Key
+    let client = client_key(&request);
+
+    // 📥 อ่าน body ทั้งก้อนเพื่อทำลายมือชื่อ แล้วประกอบ request กลับให้ handler อ่านได้ตามปกติ
+    let (parts, body) = request.into_parts();
+    let body = match to_bytes(body, store.max_request_bytes).await {
+        Ok(body) => body,
+        Err(_) => return APIError::PayloadTooLarge.error().into_response(),
+    };
+    let fingerprint = store.fingerprint(&parts.method, &parts.uri.to_string(), &body);
+
+    match store.admitting(&client, &key, fingerprint, Instant::now()) {
+        Admission::Proceed => (),
+        Admission::Replay(stored) => return replayed_response(stored),
+        Admission::Mismatch => return APIError::IdempotencyKeyReused(key).error().into_response(),
+        Admission::InProgress => return APIError::IdempotencyKeyInProgress(key).error().into_response(),
+        Admission::Exhausted => {
+            // 👤 key ของ client นี้ยังทำงานอยู่ครบเพดาน - รอให้ตัวใดตัวหนึ่งเสร็จก่อน
+            let mut response = APIError::TooManyRequests(1).error().into_response();
+            response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(1));
+            return response;
+        }
+    }
+
+    let mut reservation = Reservation {
+        store: &store,
+        client: &client,
+        key: &key,
+        settled: false,
+    };
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if response.status().is_server_error() {
+        return response;                  // 🔓 Reservation ปล่อย key ให้
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(_) => return APIError::InternalServerError.error().into_response(),
+    };
+    // 💾 ไม่ได้เก็บ (ใหญ่เกิน / ถูกลืมไปแล้ว) = Reservation ปล่อย key ให้ retry ทำงานใหม่
+    reservation.settled = store.completing(
+        &client,
+        &key,
+        StoredResponse {
+            status: parts.status,
+            headers: header_pairs(&parts.headers),
+            body: body.clone(),
+        },
+    );
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+// 🔁 response ที่เก็บไว้ พร้อม Idempotent-Replayed: true
+fn replayed_response(stored: StoredResponse) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = stored.status;
+    let headers = response.headers_mut();
+    for (name, value) in stored.headers {
+        headers.append(name, value);
+    }
+    headers.insert(IDEMPOTENT_REPLAYED, HeaderValue::from_static("true"));
+    response
+}
+
+fn header_pairs(headers: &HeaderMap) -> Vec<(HeaderName, HeaderValue)> {
+    headers
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
@@ -0,0 +1,140 @@
+// === 🧪 Test Module: Idempotency-Key Tests ===
+// 📖 ทดสอบการจอง / เก็บ / ตอบซ้ำของ key โดยกำหนดเวลาเองแทนการรอเวลาจริง
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use axum::{
+        body::Bytes,
+        http::{HeaderValue, Method, StatusCode},
+    };
+
+    use crate::{
+        middlewares::idempotency::{Admission, IdempotencyStore, StoredResponse, key_validating},
+        setting::Idempotency,
+    };
+
+    fn store() -> std::sync::Arc<IdempotencyStore> {
+        capped_store(100, 1024 * 1024, 100)
+    }
+
+    fn capped_store(max_entries: usize, max_total_bytes: usize, max_keys_per_client: usize) -> std::sync::Arc<IdempotencyStore> {
+        IdempotencyStore::creation(
+            &Idempotency {
+                ttl_secs: 60,
+                max_response_bytes: 1024,
+                max_entries,
+                max_total_bytes,
+                max_keys_per_client,
+            },
+            1024,
+        )
+    }
+
+    fn created() -> StoredResponse {
+        StoredResponse {
+            status: StatusCode::CREATED,
+            headers: Vec::new(),
+            body: Bytes::from_static(b"{\"id\":1}"),
+        }
+    }
+
+    // 🧪 retry ด้วย request เดิมได้ response แรก / payload ต่างได้ Mismatch / client อื่นไม่ปนกัน
+    #[test]
+    fn admitting_test() {
+        let store = store();
+        let now = Instant::now();
+        let fingerprint = store.fingerprint(&Method::POST, "/items/staff", b"{\"name\":\"a\"}");
+        let other = store.fingerprint(&Method::POST, "/items/staff", b"{\"name\":\"b\"}");
+
+        assert_eq!(store.admitting("ip:1", "k1", fingerprint, now), Admission::Proceed);
+        assert_eq!(store.admitting("ip:1", "k1", fingerprint, now), Admission::InProgress); // ⏳ ครั้งแรกยังไม่เสร็จ
+
+        store.completing("ip:1", "k1", created());
+        assert_eq!(store.admitting("ip:1", "k1", fingerprint, now), Admission::Replay(created()));
+        assert_eq!(store.admitting("ip:1", "k1", other, now), Admission::Mismatch);
+
+        // 👥 client อื่นใช้ key เดียวกันได้อิสระ
+        assert_eq!(store.admitting("ip:2", "k1", other, now), Admission::Proceed);
+
+        // ⏰ หมดอายุแล้วใช้ key เดิมกับ request ใหม่ได้
+        let later = now + Duration::from_secs(60);
+        assert_eq!(store.admitting("ip:1", "k1", other, later), Admission::Proceed);
+    }
+
+    // 🧪 ครั้งแรกล้ม (ปล่อย key) -> retry ได้ทำงานใหม่
+    #[test]
+    fn releasing_test() {
+        let store = store();
+        let now = Instant::now();
+
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Proceed);
+        store.releasing("ip:1", "k1");
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Proceed);
+
+        // 💾 key ที่เก็บ response แล้วไม่ถูกปล่อย
+        store.completing("ip:1", "k1", created());
+        store.releasing("ip:1", "k1");
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Replay(created()));
+    }
+
+    // 🧪 เพดานจำนวน key ทั้งหมด: key ที่ไม่ได้ใช้นานที่สุดถูกลืม (retry ของ key นั้นทำงานใหม่)
+    #[test]
+    fn max_entries_test() {
+        let store = capped_store(2, 1024 * 1024, 100);
+        let now = Instant::now();
+        for (client, key) in [("ip:1", "k1"), ("ip:2", "k2"), ("ip:3", "k3")] {
+            assert_eq!(store.admitting(client, key, 7, now), Admission::Proceed);
+            assert!(store.completing(client, key, created()));
+        }
+
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Proceed);
+        assert_eq!(store.admitting("ip:3", "k3", 7, now), Admission::Replay(created()));
+    }
+
+    // 🧪 เพดานขนาด response รวม: เก็บ response ใหม่โดยลืม key เก่าสุด / ใหญ่กว่าเพดานทั้งก้อนไม่เก็บ
+    #[test]
+    fn max_total_bytes_test() {
+        let size = created().size();
+        let store = capped_store(100, size * 2, 100);
+        let now = Instant::now();
+        for key in ["k1", "k2", "k3"] {
+            assert_eq!(store.admitting("ip:1", key, 7, now), Admission::Proceed);
+            assert!(store.completing("ip:1", key, created()));
+        }
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Proceed);
+        assert_eq!(store.admitting("ip:1", "k3", 7, now), Admission::Replay(created()));
+
+        let store = capped_store(100, size - 1, 100);
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Proceed);
+        assert!(!store.completing("ip:1", "k1", created()));
+    }
+
+    // 🧪 เพดานต่อ client: ลืม key ที่เสร็จแล้วเก่าสุดของ client นั้น - ยังทำงานอยู่ครบเพดานได้ Exhausted / client อื่นไม่กระทบ
+    #[test]
+    fn max_keys_per_client_test() {
+        let store = capped_store(100, 1024 * 1024, 2);
+        let now = Instant::now();
+        assert_eq!(store.admitting("ip:2", "k1", 7, now), Admission::Proceed);
+        assert!(store.completing("ip:2", "k1", created()));
+        for key in ["k1", "k2"] {
+            assert_eq!(store.admitting("ip:1", key, 7, now), Admission::Proceed);
+            assert!(store.completing("ip:1", key, created()));
+        }
+
+        assert_eq!(store.admitting("ip:1", "k3", 7, now), Admission::Proceed);
+        assert_eq!(store.admitting("ip:1", "k4", 7, now), Admission::Proceed);
+        assert_eq!(store.admitting("ip:1", "k5", 7, now), Admission::Exhausted);
+        assert_eq!(store.admitting("ip:1", "k1", 7, now), Admission::Exhausted);
+        assert_eq!(store.admitting("ip:2", "k1", 7, now), Admission::Replay(created()));
+    }
+
+    // 🧪 key ว่างหรือยาวเกินใช้ไม่ได้
+    #[test]
+    fn key_validating_test() {
+        assert_eq!(key_validating(&HeaderValue::from_static(" retry-1 ")).ok(), Some("retry-1".to_string()));
+        assert!(key_validating(&HeaderValue::from_static("")).is_err());
+        assert!(key_validating(&HeaderValue::from_str(&"k".repeat(256)).unwrap()).is_err());
+    }
+}
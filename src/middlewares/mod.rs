@@ -0,0 +1,13 @@
+pub mod client_identity;
+pub mod client_identity_test;
+pub mod compression;
+pub mod compression_test;
+pub mod concurrency;
+pub mod idempotency;
+pub mod idempotency_test;
+pub mod panic;
+pub mod panic_test;
+pub mod rate_limit;
+pub mod rate_limit_test;
+pub mod timeout;
+pub mod timeout_test;
@@ -0,0 +1,25 @@
+// === 💥 Interface Layer: Panic to 500 ===
+// 📖 แปลง panic ใน handler ให้เป็น 500 ในรูปแบบ error ของโปรเจค แทนการตัด connection ทิ้ง
+//    log จะอยู่ภายใน span ของ TraceLayer จึงมี request id ติดไปด้วย
+
+use std::any::Any;
+
+use axum::response::{IntoResponse, Response};
+use tracing::error;
+
+use crate::models::error::{APIError, IntoErrorResponse};
+
+// 🚑 ใช้กับ CatchPanicLayer::custom
+pub fn panic_handling(panic: Box<dyn Any + Send + 'static>) -> Response {
+    // 🔍 panic message มักเป็น &str หรือ String
+    let message = if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    };
+
+    error!("Handler panicked: {}", message); // 📝 Log พร้อม request id จาก span
+    APIError::InternalServerError.error().into_response()
+}
@@ -0,0 +1,44 @@
+// === 🧪 Test Module: Panic to 500 Tests ===
+// 📖 ทดสอบว่า panic ใน handler ได้ 500 ในรูปแบบ error ของโปรเจค แทนการตัด connection
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+        routing::get,
+    };
+    use tower::ServiceExt;
+    use tower_http::catch_panic::CatchPanicLayer;
+
+    use crate::middlewares::panic::panic_handling;
+
+    async fn str_panicking() -> &'static str {
+        panic!("boom")
+    }
+
+    async fn string_panicking() -> String {
+        panic!("{}", "boom".to_string())
+    }
+
+    // 🧪 panic ทั้งแบบ &str และ String ได้ 500 JSON
+    #[tokio::test]
+    async fn panic_handling_test() {
+        let app = Router::new()
+            .route("/str", get(str_panicking))
+            .route("/string", get(string_panicking))
+            .layer(CatchPanicLayer::custom(panic_handling));
+
+        for path in ["/str", "/string"] {
+            let response = app
+                .clone()
+                .oneshot(Request::get(path).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            assert_eq!(&body[..], br#"{"error":"Internal server error"}"#);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// === 🚦 Interface Layer: Rate Limit Middleware ===
+// 📖 จำกัดจำนวน request ต่อ client ด้วย token bucket แยกตาม route ที่กำหนดใน Setting.toml
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    RateLimiter มีหน้าที่เดียว: ตัดสินว่า client นี้ยังเรียก route นี้ได้อีกหรือไม่
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เพิ่ม route ที่ต้องการจำกัดได้จาก Setting.toml โดยไม่แก้โค้ด
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    Handler ไม่รู้เลยว่ามี rate limit - middleware ครอบไว้จากภายนอก
+
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{HeaderValue, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use lru::LruCache;
+
+use crate::{
+    middlewares::client_identity::client_key,
+    models::error::{APIError, IntoErrorResponse},
+    setting::{RateLimit, RouteRateLimit},
+};
+
+// 🧹 จำนวน bucket สูงสุด - เกินแล้วทิ้ง bucket ที่ไม่ได้ใช้นานที่สุด (LRU) ทีละอัน
+const MAX_BUCKETS: NonZeroUsize = NonZeroUsize::new(10_000).unwrap();
+
+// 🔗 Type alias สำหรับแชร์ RateLimiter ระหว่าง request
+pub type SharedRateLimiter = Arc<RateLimiter>;
+
+// 🪣 Token bucket ของ client หนึ่งราย บน route หนึ่ง
+struct Bucket {
+    tokens: f64,          // 🪙 token ที่เหลืออยู่
+    refilled_at: Instant, // ⏰ เวลาที่เติม token ล่าสุด
+}
+
+// 📦 RateLimiter: เก็บกฎของแต่ละ route และ bucket ของแต่ละ client
+pub struct RateLimiter {
+    rules: HashMap<(String, String), RouteRateLimit>, // 🛣️ (METHOD, path) -> กฎ
+    buckets: Mutex<LruCache<(String, String, String), Bucket>>, // 🪣 (METHOD, path, client key) -> bucket
+}
+
+impl RateLimiter {
+    // 🏗️ Factory method: สร้าง RateLimiter จากการตั้งค่า
+    pub fn creation(setting: &RateLimit) -> SharedRateLimiter {
+        let rules = setting
+            .routes
+            .iter()
+            .map(|rule| ((rule.method.to_uppercase(), rule.path.clone()), rule.clone()))
+            .collect();
+
+        Arc::new(Self {
+            rules,
+            buckets: Mutex::new(LruCache::new(MAX_BUCKETS)),
+        })
+    }
+
+    // 🎯 หยิบ token หนึ่งอัน: Ok(()) ถ้าผ่าน, Err(วินาทีที่ต้องรอ) ถ้าเกิน limit
+    // route ที่ไม่มีกฎจะผ่านเสมอ
+    pub fn acquire(&self, method: &str, path: &str, key: &str, now: Instant) -> Result<(), u64> {
+        let route = (method.to_string(), path.to_string());
+        let rule = match self.rules.get(&route) {
+            Some(rule) => rule,
+            None => return Ok(()),
+        };
+        let capacity = f64::from(rule.capacity);
+
+        // 🧹 client ใหม่ตอนที่ bucket เต็ม: ทิ้ง bucket ที่ไม่ได้ใช้นานที่สุด (O(1) ไม่ไล่ทั้ง map)
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.get_or_insert_mut((route.0, route.1, key.to_string()), || Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+
+        // 🔄 เติม token ตามเวลาที่ผ่านไป แต่ไม่เกิน capacity
+        let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rule.refill_per_second).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        // ⏳ คำนวณเวลาที่ต้องรอจนกว่าจะมี token ครบหนึ่งอัน (ปัดขึ้น อย่างน้อย 1 วินาที)
+        let retry_after = if rule.refill_per_second > 0.0 {
+            ((1.0 - bucket.tokens) / rule.refill_per_second).ceil() as u64
+        } else {
+            u64::MAX
+        };
+        Err(retry_after.max(1))
+    }
+}
+
+// 🚦 Middleware: ตรวจ rate limit ก่อนส่งต่อให้ handler
+// ใช้กับ Router::layer เพื่อให้รู้ MatchedPath ของ route
+pub async fn limiting(
+    State(rate_limiter): State<SharedRateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = match request.extensions().get::<MatchedPath>() {
+        Some(path) => path.as_str().to_string(),
+        None => return next.run(request).await,
+    };
+    let key = client_key(&request);    // 🪪 API key / JWT subject / IP (client_identity)
+
+    match rate_limiter.acquire(request.method().as_str(), &path, &key, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            // ❌ เกิน limit: ตอบ 429 ในรูปแบบ error ของโปรเจค พร้อม Retry-After header
+            let mut response = APIError::TooManyRequests(retry_after).error().into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// === 🧪 Test Module: Rate Limit Tests ===
+// 📖 ทดสอบ token bucket โดยกำหนดเวลาเองแทนการรอเวลาจริง
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        middlewares::rate_limit::RateLimiter,
+        setting::{RateLimit, RouteRateLimit},
+    };
+
+    fn rate_limiter() -> std::sync::Arc<RateLimiter> {
+        RateLimiter::creation(&RateLimit {
+            max_concurrent_requests: 1,
+            routes: vec![RouteRateLimit {
+                method: "post".to_string(),
+                path: "/items/staff".to_string(),
+                capacity: 2,
+                refill_per_second: 0.5,
+            }],
+            api_keys: Vec::new(),
+            jwt_secret: None,
+        })
+    }
+
+    // 🧪 ใช้ token หมดแล้วต้องรอ และได้ token คืนเมื่อเวลาผ่านไป
+    #[test]
+    fn acquire_test() {
+        let rate_limiter = rate_limiter();
+        let now = Instant::now();
+
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Ok(()));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Ok(()));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Err(2)); // ⏳ 0.5 token/s -> รอ 2 วินาที
+
+        // 👥 client อื่นมี bucket ของตัวเอง
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:2", now), Ok(()));
+
+        // 🔄 ผ่านไป 2 วินาทีได้ token คืนหนึ่งอัน
+        let later = now + Duration::from_secs(2);
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", later), Ok(()));
+
+        // 🛣️ route ที่ไม่มีกฎผ่านเสมอ
+        assert_eq!(rate_limiter.acquire("GET", "/items/staff", "ip:1", now), Ok(()));
+    }
+
+    // 🧪 bucket มีจำนวนจำกัด: client ใหม่ตอนที่เต็มแทนที่ bucket ที่ไม่ได้ใช้นานที่สุด
+    #[test]
+    fn acquire_bounded_test() {
+        let rate_limiter = rate_limiter();
+        let now = Instant::now();
+
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Ok(()));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Ok(()));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Err(2));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:2", now), Ok(()));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:2", now), Ok(()));
+
+        // 👥 ip:2 + client ใหม่ 9,999 ราย = เต็มพอดี -> bucket ของ ip:1 (ไม่ได้ใช้นานที่สุด) ถูกทิ้งไป
+        for client in 0..9_999 {
+            assert_eq!(rate_limiter.acquire("POST", "/items/staff", &format!("ip:new-{}", client), now), Ok(()));
+        }
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:2", now), Err(2));
+        assert_eq!(rate_limiter.acquire("POST", "/items/staff", "ip:1", now), Ok(()));
+    }
+}
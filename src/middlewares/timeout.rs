@@ -0,0 +1,72 @@
+// === ⏱️ Interface Layer: Request Timeout Middleware ===
+// 📖 ยกเลิก request ที่ประมวลผลนานเกินกำหนด โดยแต่ละ route ตั้ง timeout ของตัวเองได้ใน Setting.toml
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    models::error::{APIError, IntoErrorResponse},
+    setting::Server,
+};
+
+// 🔗 Type alias สำหรับแชร์ RequestTimeouts ระหว่าง request
+pub type SharedRequestTimeouts = Arc<RequestTimeouts>;
+
+// 📦 RequestTimeouts: timeout เริ่มต้น และ timeout เฉพาะ route
+pub struct RequestTimeouts {
+    default: Duration,                                 // ⏱️ ใช้กับ route ที่ไม่ได้กำหนด
+    routes: HashMap<(String, String), Duration>,       // 🛣️ (METHOD, path) -> timeout
+}
+
+impl RequestTimeouts {
+    // 🏗️ Factory method: สร้างจากการตั้งค่า server
+    pub fn creation(setting: &Server) -> SharedRequestTimeouts {
+        let routes = setting
+            .route_timeouts
+            .iter()
+            .map(|route| {
+                (
+                    (route.method.to_uppercase(), route.path.clone()),
+                    Duration::from_secs(route.timeout_secs),
+                )
+            })
+            .collect();
+
+        Arc::new(Self {
+            default: Duration::from_secs(setting.request_timeout_secs),
+            routes,
+        })
+    }
+
+    // 🔍 หา timeout ของ route
+    pub fn getting(&self, method: &str, path: &str) -> Duration {
+        self.routes
+            .get(&(method.to_string(), path.to_string()))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+// ⏱️ Middleware: ครอบ handler ด้วย tokio timeout
+pub async fn timing_out(
+    State(timeouts): State<SharedRequestTimeouts>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+    let timeout = timeouts.getting(request.method().as_str(), &path);
+
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => APIError::RequestTimeout(timeout.as_secs()).error().into_response(), // ❌ หมดเวลา
+    }
+}
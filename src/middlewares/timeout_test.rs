@@ -0,0 +1,62 @@
+// === 🧪 Test Module: Request Timeout Tests ===
+// 📖 ทดสอบ timeout ราย route ผ่าน router จริง (timeout 0 วินาที = หมดเวลาทันทีที่ handler ยังไม่เสร็จ)
+
+#[cfg(test)]
+mod tests {
+    use std::{net::IpAddr, time::Duration};
+
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+        middleware,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        middlewares::timeout::{RequestTimeouts, timing_out},
+        setting::{RouteTimeout, Server},
+    };
+
+    fn app() -> Router {
+        let timeouts = RequestTimeouts::creation(&Server {
+            host: IpAddr::from([127, 0, 0, 1]),
+            port: 2000,
+            unix_socket: None,
+            request_timeout_secs: 30,
+            route_timeouts: vec![RouteTimeout {
+                method: "get".to_string(),
+                path: "/slow".to_string(),
+                timeout_secs: 0,
+            }],
+            max_body_bytes: 1024,
+            tls: None,
+        });
+
+        Router::new()
+            .route("/slow", get(|| async { tokio::time::sleep(Duration::from_secs(60)).await }))
+            .route("/fast", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(timeouts, timing_out))
+    }
+
+    async fn getting(path: &str) -> (StatusCode, String) {
+        let response = app()
+            .oneshot(Request::get(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    // 🧪 route ที่ตั้ง timeout เองหมดเวลาได้ 408 / route อื่นใช้ค่าเริ่มต้น
+    #[tokio::test]
+    async fn route_timeout_test() {
+        let (status, body) = getting("/slow").await;
+        assert_eq!(status, StatusCode::REQUEST_TIMEOUT);
+        assert_eq!(body, r#"{"error":"Request timed out after 0 seconds"}"#);
+
+        assert_eq!(getting("/fast").await, (StatusCode::OK, "ok".to_string()));
+    }
+}
@@ -0,0 +1,53 @@
+// === 📋 API Models: Audit ===
+// 📖 ผู้ที่แก้ไข item (actor) และประวัติการแก้ไขแต่ละครั้ง (revision)
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+
+// 👤 Actor: ผู้ที่ส่ง request มา (จาก header X-Actor) และ request id สำหรับตามรอยใน log
+#[derive(Clone, PartialEq, Debug)]
+pub struct Actor {
+    pub name: String,               // 👤 ชื่อ GM / ระบบที่เรียก
+    pub request_id: Option<String>, // 🆔 x-request-id ของ request
+}
+
+// 🏷️ ItemAction: ชนิดของการแก้ไขที่บันทึกในประวัติ
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemAction {
+    Create,    // ➕ สร้าง item
+    Update,    // ✏️ แก้ไขค่า
+    Transfer,  // 🔁 เปลี่ยนเจ้าของ
+    Delete,    // 🗑️ soft delete
+    Restore,   // ♻️ กู้คืน
+    Revert,    // ⏪ ย้อนกลับไป revision ก่อนหน้า
+}
+
+impl Display for ItemAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create => write!(f, "create"),
+            Self::Update => write!(f, "update"),
+            Self::Transfer => write!(f, "transfer"),
+            Self::Delete => write!(f, "delete"),
+            Self::Restore => write!(f, "restore"),
+            Self::Revert => write!(f, "revert"),
+        }
+    }
+}
+
+// 📜 ItemRevision Model: การแก้ไข item หนึ่งครั้ง
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ItemRevision {
+    pub item_id: i32,               // 🆔 item ที่ถูกแก้ไข
+    pub revision: i32,              // 🔢 ลำดับการแก้ไขของ item นี้ (เริ่มที่ 1)
+    pub action: ItemAction,         // 🏷️ ชนิดของการแก้ไข
+    pub actor: String,              // 👤 ผู้แก้ไข
+    pub request_id: Option<String>, // 🆔 request id
+    pub before: Option<Value>,      // 📸 ค่าทั้งหมดก่อนแก้ไข (null = ยังไม่มี item)
+    pub after: Option<Value>,       // 📸 ค่าทั้งหมดหลังแก้ไข
+    pub diff: Value,                // 🔍 เฉพาะ field ที่เปลี่ยน: {"field": {"before": ..., "after": ...}}
+    pub created_at: NaiveDateTime,  // 📅 เวลาที่แก้ไข
+}
@@ -0,0 +1,135 @@
+// === 📋 API Models: Batch ===
+// 📖 อ่านหลาย item ใน request เดียว (POST /items/batch-get) และเพิ่ม/แก้ไข/ลบหลายรายการใน transaction เดียว (POST /items/batch)
+// 🎯 SOLID: Single Responsibility - เฉพาะรูปแบบของ request / รายงานผลราย operation
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::item::{Item, StaffAdding};
+
+// 📥 BatchGetting: id ที่ต้องการ (ซ้ำได้ - ผลลัพธ์เรียงตามลำดับที่ส่งมา)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct BatchGetting {
+    pub ids: Vec<i32>,
+}
+
+// 🏷️ BatchGetStatus: พบหรือไม่พบ item ของ id นั้น
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchGetStatus {
+    Found,    // ✅ พบ
+    NotFound, // 🔍 ไม่มี item นี้ (หรือถูกลบแล้ว)
+}
+
+// 📋 BatchGot: ผลของ id หนึ่ง
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchGot {
+    pub id: i32,                    // 🆔 id ที่ขอ
+    pub status: BatchGetStatus,     // 🏷️ พบหรือไม่พบ
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,         // 📦 item (เฉพาะที่พบ)
+}
+
+// 📊 BatchItems: ผลของทุก id ตามลำดับที่ขอ
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchItems {
+    pub found: usize,               // ✅ จำนวนที่พบ
+    pub not_found: usize,           // 🔍 จำนวนที่ไม่พบ
+    pub items: Vec<BatchGot>,       // 📋 ผลราย id
+}
+
+// 🔒 BatchMode: ทำอย่างไรเมื่อบาง operation ล้ม
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    #[default]
+    AllOrNothing, // 🔒 ล้มหนึ่งรายการ = ไม่บันทึกเลยทั้ง batch
+    BestEffort,   // 📋 บันทึกรายการที่สำเร็จ รายงานรายการที่ล้ม
+}
+
+// ✏️ BatchOperation: operation หนึ่งรายการ ({"op": "create" | "update" | "delete", ...})
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    // ➕ เพิ่ม item ใหม่ (ตรวจเหมือน POST /items/staff)
+    Create {
+        item: StaffAdding,
+    },
+    // ✏️ แทนที่ทุก field ที่แก้ไขได้ของ item (owner_id ต้องว่างหรือเป็นเจ้าของเดิม)
+    Update {
+        id: i32,
+        item: StaffAdding,
+        #[serde(default)]
+        version: Option<i32>,       // 🔢 version ที่แก้ไขจาก (ไม่ส่ง = ไม่ตรวจ)
+    },
+    // 🗑️ ลบแบบ soft delete
+    Delete {
+        id: i32,
+        #[serde(default)]
+        version: Option<i32>,       // 🔢 version ที่ลบจาก (ไม่ส่ง = ไม่ตรวจ)
+    },
+}
+
+impl BatchOperation {
+    // 🏷️ ชื่อ operation สำหรับรายงาน
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Create { .. } => "create",
+            Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
+        }
+    }
+
+    // 🆔 item ที่ operation อ้างถึง (create ยังไม่มี)
+    pub fn target(&self) -> Option<i32> {
+        match self {
+            Self::Create { .. } => None,
+            Self::Update { id, .. } | Self::Delete { id, .. } => Some(*id),
+        }
+    }
+}
+
+// 📥 BatchMutating: operation ทั้งหมดตามลำดับที่จะทำ
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchMutating {
+    #[serde(default)]
+    pub mode: BatchMode,
+    pub operations: Vec<BatchOperation>,
+}
+
+// 🏷️ BatchOperationStatus: ผลของ operation หนึ่งรายการ
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOperationStatus {
+    Succeeded,  // ✅ บันทึกแล้ว
+    Failed,     // ❌ ล้ม (เหตุผลใน error)
+    RolledBack, // ↩️ สำเร็จแล้วแต่ถูกยกเลิกเพราะรายการอื่นล้ม (all_or_nothing)
+    Skipped,    // ⏭️ ไม่ได้ทำเพราะรายการก่อนหน้าล้ม (all_or_nothing)
+}
+
+// 📋 BatchOperationResult: รายงานของ operation หนึ่งรายการ
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchOperationResult {
+    pub index: usize,               // 🔢 ลำดับใน operations (เริ่มที่ 0)
+    pub op: String,                 // 🏷️ create / update / delete
+    pub status: BatchOperationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,            // 🆔 item ที่เกี่ยวข้อง
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,         // 📦 item หลังบันทึก (create / update ที่สำเร็จ)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,      // 💬 เหตุผลที่ล้ม
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,   // 🔢 HTTP status ที่รายการนี้จะได้ถ้าส่งแยก
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_version: Option<i32>, // 🔢 version ปัจจุบัน (เมื่อ version ไม่ตรง)
+}
+
+// 📊 BatchReport: สรุปผลทั้ง batch
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchReport {
+    pub mode: BatchMode,
+    pub committed: bool,            // ✅ false = ไม่มีอะไรถูกบันทึก
+    pub succeeded: usize,           // ✅ จำนวนที่บันทึกแล้ว
+    pub failed: usize,              // ❌ จำนวนที่ล้ม
+    pub results: Vec<BatchOperationResult>, // 📋 ผลตามลำดับ operations
+}
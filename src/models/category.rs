@@ -0,0 +1,148 @@
+// === 📋 API Models: Category Catalog ===
+// 📖 Data Transfer Objects ของ catalog ประเภท item (admin) และกติกาที่สืบทอดตาม tree
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    entities::categories::Categories as CategoriesEntity,
+    models::item::{ItemStats, StaffAdding},
+    time_helper::IntoTimerHelperShared,
+};
+use sqlx::types::Json;
+
+// 📏 CategoryRules: กติกาของ item ในประเภทนี้ (เก็บเป็น JSONB)
+// 🌳 ประเภทลูกได้กติกาของประเภทแม่ทั้งหมด และเข้มขึ้นได้อย่างเดียว (ดู inheriting)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct CategoryRules {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_stats: Vec<String>,         // ✅ stats ที่ต้องมี
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_stats: Option<Vec<String>>,  // 📋 stats ที่มีได้ (None = ได้ทุกตัว)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_required_level: Option<i32>,     // 🎚️ required_level ขั้นต่ำ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_weight: Option<i32>,             // ⚖️ น้ำหนักสูงสุดต่อชิ้น
+}
+
+impl CategoryRules {
+    // 🌳 รวมกติกาของประเภทแม่ (parent) กับประเภทนี้ - ผลลัพธ์เข้มเท่ากับหรือมากกว่าทั้งสอง
+    pub fn inheriting(&self, parent: &CategoryRules) -> CategoryRules {
+        let mut required_stats = parent.required_stats.clone();
+        for name in &self.required_stats {
+            if !required_stats.contains(name) {
+                required_stats.push(name.clone());
+            }
+        }
+
+        let allowed_stats = match (&parent.allowed_stats, &self.allowed_stats) {
+            (Some(parent), Some(own)) => Some(own.iter().filter(|name| parent.contains(name)).cloned().collect()),
+            (parent, own) => own.clone().or_else(|| parent.clone()),
+        };
+
+        CategoryRules {
+            required_stats,
+            allowed_stats,
+            min_required_level: self.min_required_level.max(parent.min_required_level),
+            max_weight: match (self.max_weight, parent.max_weight) {
+                (Some(own), Some(parent)) => Some(own.min(parent)),
+                (own, parent) => own.or(parent),
+            },
+        }
+    }
+
+    // 🏷️ rules อ้างถึงได้เฉพาะชื่อ stats ที่มีใน ItemStats
+    pub fn unknown_stat(&self) -> Option<&str> {
+        self.required_stats
+            .iter()
+            .chain(self.allowed_stats.iter().flatten())
+            .map(String::as_str)
+            .find(|name| !ItemStats::NAMES.contains(name))
+    }
+
+    // ✅ ตรวจ item ใหม่กับกติกา - Err คือเหตุผลที่ไม่ผ่าน
+    pub fn checking(&self, category: &str, staff: &StaffAdding) -> Result<(), String> {
+        let present = staff.stats.present();
+
+        for name in &self.required_stats {
+            if !present.iter().any(|(present, _)| present == name) {
+                return Err(format!("{} requires {}", category, name));
+            }
+        }
+        if let Some(allowed) = &self.allowed_stats
+            && let Some((name, _)) = present.iter().find(|(name, _)| !allowed.iter().any(|allowed| allowed == name))
+        {
+            return Err(format!("{} does not have {}", category, name));
+        }
+        if let Some(min_level) = self.min_required_level
+            && staff.required_level < min_level
+        {
+            return Err(format!("{} requires required_level of at least {}", category, min_level));
+        }
+        if let Some(max_weight) = self.max_weight
+            && staff.weight > max_weight
+        {
+            return Err(format!("{} weighs at most {}", category, max_weight));
+        }
+
+        Ok(())
+    }
+}
+
+// 🏷️ CategoryEntry Model: ประเภทหนึ่งรายการใน catalog
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CategoryEntry {
+    pub id: i32,                 // 🆔 Primary key จากฐานข้อมูล
+    pub name: String,            // 🏷️ ชื่อประเภท
+    pub parent_id: Option<i32>,  // 🌳 ประเภทแม่ (None = ราก)
+    pub rules: CategoryRules,    // 📏 กติกาของประเภทนี้เอง (ไม่รวมที่สืบทอด)
+}
+
+// 🌳 CategoryNode Model: ประเภทหนึ่งใน tree พร้อมประเภทลูก
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CategoryNode {
+    pub id: i32,                          // 🆔 Primary key จากฐานข้อมูล
+    pub name: String,                     // 🏷️ ชื่อประเภท
+    pub rules: CategoryRules,             // 📏 กติกาของประเภทนี้เอง
+    pub effective_rules: CategoryRules,   // 📏 กติกาที่ใช้จริง (รวมที่สืบทอดจากประเภทแม่)
+    pub children: Vec<CategoryNode>,      // 🌿 ประเภทลูก
+}
+
+// 📥 CategoryAdding Model: ข้อมูลสำหรับเพิ่มประเภทใหม่
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct CategoryAdding {
+    pub name: String,            // 🏷️ ชื่อประเภท
+    #[serde(default)]
+    pub parent_id: Option<i32>,  // 🌳 ประเภทแม่ (ไม่ส่งมา = ราก)
+    #[serde(default)]
+    pub rules: CategoryRules,    // 📏 กติกาเพิ่มเติมของประเภทนี้
+}
+
+impl CategoryAdding {
+    // 🔄 แปลง Model เป็น Domain Entity
+    pub fn to_entity(&self, t: IntoTimerHelperShared) -> CategoriesEntity {
+        CategoriesEntity {
+            parent_id: self.parent_id,
+            rules: Json(self.rules.clone()),
+            ..CategoriesEntity::new(self.name.trim().to_string(), t)
+        }
+    }
+}
+
+// 📥 CategoryUpdating Model: แก้ชื่อ/ประเภทแม่/กติกา (item ที่ใช้อยู่เปลี่ยนชื่อตามด้วย ON UPDATE CASCADE)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct CategoryUpdating {
+    pub name: String,            // 🏷️ ชื่อ (built-in ต้องคงชื่อเดิม)
+    #[serde(default)]
+    pub parent_id: Option<i32>,  // 🌳 ประเภทแม่ใหม่
+    #[serde(default)]
+    pub rules: CategoryRules,    // 📏 กติกาใหม่ของประเภทนี้
+}
+
+// 🧾 CategorySchemaEntry Model: JSON Schema ของ attributes หนึ่งเวอร์ชัน
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CategorySchemaEntry {
+    pub category_id: i32,        // 📚 ประเภทเจ้าของ schema
+    pub version: i32,            // 🔢 เวอร์ชัน (เริ่มที่ 1)
+    pub schema: Value,           // 🧾 JSON Schema
+}
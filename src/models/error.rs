@@ -7,29 +7,42 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use serde::Serialize;
 use serde_json::json;
 
+use crate::models::etag::Precondition;
+
 // 📋 ErrorResponse: โครงสร้างมาตรฐานสำหรับ HTTP Error Response
 // 🎯 SOLID: Single Responsibility - เฉพาะจัดการ error response
 #[derive(Debug)]
 pub struct ErrorResponse {
     pub error: String,          // 💬 ข้อความ error
     pub status_code: StatusCode, // 🔢 HTTP status code
+    pub fields: Vec<FieldError>, // 🧩 error ราย field (ว่าง = ไม่ส่ง "fields")
+    pub current_version: Option<i32>, // 🔢 version ปัจจุบันของ item (มีเฉพาะเมื่อ version ไม่ตรง)
+}
+
+// 🧩 FieldError: error ของ field หนึ่ง (field เป็น JSON Pointer เช่น "/element")
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,          // 📍 ตำแหน่งของ field ที่ผิด ("" = ทั้ง object)
+    pub message: String,        // 💬 เหตุผลที่ไม่ผ่าน
 }
 
 // 🔄 แปลง ErrorResponse เป็น HTTP Response
 // 🎯 SOLID: Interface Segregation - implement เฉพาะที่จำเป็น
 impl IntoResponse for ErrorResponse {
     fn into_response(self) -> Response {
-        (
-            self.status_code,
-            Json(json!(
-                {
-                    "error": self.error    // 📤 JSON format: {"error": "message"}
-                }
-            )),
-        )
-            .into_response()
+        // 📤 JSON format: {"error": "message"} หรือ {"error": "message", "fields": [...]}
+        let mut body = match self.fields.is_empty() {
+            true => json!({ "error": self.error }),
+            false => json!({ "error": self.error, "fields": self.fields }),
+        };
+        // 🔢 version ไม่ตรง: บอก version ปัจจุบันให้ client อ่านใหม่แล้วลองอีกครั้ง
+        if let Some(version) = self.current_version {
+            body["current_version"] = json!(version);
+        }
+        (self.status_code, Json(body)).into_response()
     }
 }
 
@@ -43,9 +56,58 @@ pub trait IntoErrorResponse {
 // 🎯 SOLID: Open/Closed Principle - เพิ่ม error type ใหม่ได้โดยไม่แก้โค้ดเดิม
 pub enum APIError {
     InvalidCategory(String),      // 🏷️ ประเภทที่ไม่ถูกต้อง
+    CategoryNotFound(i32),        // 📚 ไม่พบประเภทใน catalog
+    CategoryAlreadyExists(String), // 📚 ชื่อประเภทซ้ำ
+    CategoryInUse(String),        // 📚 ยังมี item ใช้ประเภทนี้อยู่
+    BuiltInCategory(String),      // 🔒 ประเภทที่โค้ดใช้อยู่ แก้ไข/ลบไม่ได้
+    InvalidCategoryParent(String), // 🌳 ประเภทแม่ไม่มีอยู่หรือทำให้ tree วนซ้ำ
+    InvalidCategoryRules(String), // 📏 กติกาอ้างถึง stats ที่ไม่มี
+    FetchingCategoryError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะอ่าน catalog
+    SavingCategoryError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะแก้ไข catalog
+    InvalidRarity(String),        // 💎 ความหายากที่ไม่ถูกต้อง
+    InvalidItemAttributes(String), // 📊 rarity/level/stats ไม่ตรงกับกติกาของประเภท
+    InvalidItemName(String),      // 📝 ชื่อ item ใช้ไม่ได้ (ว่าง / มีอักขระควบคุม)
+    ItemOwnerNotWritable(String), // 👤 ตั้ง/ย้าย owner_id ผ่านทางนี้ไม่ได้ (endpoint ที่ต้องใช้แทน)
+    InvalidAttributes(Vec<FieldError>), // 🧩 attributes ไม่ผ่าน JSON Schema ของประเภท
+    InvalidCategorySchema(String), // 🧾 JSON Schema ของประเภทใช้ไม่ได้
+    InvalidQuery(String),         // 🔎 query string อ่านไม่ได้
+    InvalidFilter(usize, String, String), // 🧮 ?filter= ผิด (ตำแหน่งตัวอักษร, token, เหตุผล)
+    InvalidBatch(String),         // 📦 batch ว่างหรือมีรายการเกินกำหนด
+    InvalidPatch(String),         // 🩹 patch ผิดรูปแบบ / path ไม่มีอยู่ / แก้ field ที่อ่านอย่างเดียว
+    PatchTestFailed(String),      // 🩹 operation "test" ของ JSON Patch ไม่ผ่าน (path)
     ItemAlreadyExists(String),    // 🔄 Item ที่มีอยู่แล้ว
+    ItemDefinitionNotFound(i32),  // 📜 ไม่พบแบบของ item
+    ItemDefinitionAlreadyExists(String), // 📜 ชื่อแบบซ้ำภายในประเภทเดียวกัน
+    FetchingItemDefinitionError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะอ่านแบบ
+    SavingItemDefinitionError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะบันทึกแบบ
     AddingItemError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะเพิ่มข้อมูล
     ItemNotFound(i32),           // 🔍 ไม่พบ Item ที่ต้องการ
+    FetchingItemError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะอ่านข้อมูล
+    UpdatingItemError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะแก้ไขข้อมูล
+    RemovingItemError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะลบหรือ purge
+    ItemRestoreConflict(i32),     // ♻️ กู้คืนไม่ได้เพราะมี item ชื่อเดียวกันแล้ว
+    ItemRevisionNotFound(i32, i32), // 📜 ไม่พบ revision ของ item (item_id, revision)
+    ItemRevisionNotRevertible(i32, String), // ⏪ ย้อนกลับไป revision นี้ไม่ได้ (revision, เหตุผล)
+    VersionConflict(Precondition, i32), // 🔢 version ที่แก้ไขจากไม่ใช่ version ปัจจุบัน (เงื่อนไข, version ปัจจุบัน)
+    UpsertTargetNotFound(String), // 🔢 upsert มีเงื่อนไข version แต่ไม่มี item ชื่อนี้ให้แทนที่
+    InventoryFull(String),        // 🎒 กระเป๋าผู้เล่นเต็ม (ช่องหรือน้ำหนัก)
+    PlayerNotFound(i32),          // 🎮 ไม่พบผู้เล่น
+    PlayerAlreadyExists(String),  // 🎮 ชื่อผู้เล่นซ้ำ
+    InvalidPlayerTier(String),    // 🏅 tier ที่ไม่มีใน inventory.tiers
+    AddingPlayerError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะเพิ่มผู้เล่น
+    UpdatingPlayerError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะแก้ไขผู้เล่น
+    FetchingPlayerError(sqlx::Error), // 💾 Error จากฐานข้อมูลขณะอ่านผู้เล่น
+    TooManyRequests(u64),         // 🚦 เรียกถี่เกิน rate limit (จำนวนวินาทีที่ต้องรอ)
+    ServiceOverloaded,            // 🏋️ ระบบรับ request พร้อมกันเต็มแล้ว
+    RequestTimeout(u64),          // ⏱️ ประมวลผลนานเกิน timeout ของ route (วินาที)
+    PayloadTooLarge,              // 📦 request body ใหญ่เกินกำหนด
+    InvalidRequestBody(StatusCode, String), // 📥 request body อ่านหรือแปลงไม่ได้
+    UnsupportedMediaType(String), // 📄 Content-Type ที่ไม่รองรับ
+    InvalidIdempotencyKey(String), // 🔑 Idempotency-Key ว่างหรือยาวเกิน
+    IdempotencyKeyReused(String), // 🔑 ใช้ Idempotency-Key เดิมกับ request ที่ต่างจากครั้งแรก
+    IdempotencyKeyInProgress(String), // 🔑 request แรกของ key นี้ยังทำงานไม่เสร็จ
+    MissingEntityId(String),      // 🆔 Entity ไม่มี ID ทั้งที่ควรมีแล้ว
+    InternalServerError,          // 💥 ข้อผิดพลาดที่ไม่คาดคิด (เช่น panic)
 }
 
 // 🔄 Implementation ของ IntoErrorResponse trait สำหรับ APIError
@@ -57,21 +119,373 @@ impl IntoErrorResponse for APIError {
             Self::InvalidCategory(category) => ErrorResponse {
                 error: format!("Invalid category: {}", category),
                 status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📚 ไม่พบประเภท - เป็น not found error
+            Self::CategoryNotFound(id) => ErrorResponse {
+                error: format!("Category not found: {}", id),
+                status_code: StatusCode::NOT_FOUND,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📚 ชื่อประเภทซ้ำ - เป็น conflict error
+            Self::CategoryAlreadyExists(name) => ErrorResponse {
+                error: format!("Category is already exists: {}", name),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📚 ยังมี item อ้างถึง - ต้องย้าย item ออกก่อนจึงลบได้
+            Self::CategoryInUse(name) => ErrorResponse {
+                error: format!("Category is in use: {}", name),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔒 ประเภท built-in - ขัดกับกติกาในโค้ด
+            Self::BuiltInCategory(name) => ErrorResponse {
+                error: format!("Built-in category cannot be changed: {}", name),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🌳 ประเภทแม่ผิด - อ่านได้แต่ใช้ไม่ได้
+            Self::InvalidCategoryParent(reason) => ErrorResponse {
+                error: format!("Invalid category parent: {}", reason),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📏 กติกาผิด - อ่านได้แต่ใช้ไม่ได้
+            Self::InvalidCategoryRules(reason) => ErrorResponse {
+                error: format!("Invalid category rules: {}", reason),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💾 Error จากฐานข้อมูล - เป็น server error
+            Self::FetchingCategoryError(err) => ErrorResponse {
+                error: format!("Failed to fetch category {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::SavingCategoryError(err) => ErrorResponse {
+                error: format!("Failed to save category {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💎 ความหายากไม่ถูกต้อง - เป็น client error เหมือนประเภท
+            Self::InvalidRarity(rarity) => ErrorResponse {
+                error: format!("Invalid rarity: {}", rarity),
+                status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📝 ชื่อใช้ไม่ได้ - อ่านได้แต่ไม่ผ่านกติกา
+            Self::InvalidItemName(reason) => ErrorResponse {
+                error: format!("Invalid item name: {}", reason),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📊 ค่าอ่านได้แต่ผิดกติกา - เป็น unprocessable entity
+            // 👤 เจ้าของต้องผ่านกติกากระเป๋า - บอก endpoint ที่ตรวจให้
+            Self::ItemOwnerNotWritable(endpoint) => ErrorResponse {
+                error: format!("owner_id cannot be set here, use {}", endpoint),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::InvalidItemAttributes(reason) => ErrorResponse {
+                error: format!("Invalid item attributes: {}", reason),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🧩 attributes ไม่ผ่าน schema - บอกทุก field ที่ผิดในครั้งเดียว
+            Self::InvalidAttributes(fields) => ErrorResponse {
+                error: format!("Invalid attributes: {} field(s) failed schema validation", fields.len()),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: fields.clone(),
+                current_version: None,
+            },
+            // 🧾 schema ผิด - อ่านได้แต่ใช้ตรวจไม่ได้
+            Self::InvalidCategorySchema(reason) => ErrorResponse {
+                error: format!("Invalid category schema: {}", reason),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔎 query string ผิดรูปแบบ - เป็น client error
+            Self::InvalidQuery(message) => ErrorResponse {
+                error: format!("Invalid query: {}", message),
+                status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🧮 filter ผิดรูปแบบ / field ไม่อยู่ใน whitelist / ค่าผิดชนิด - ชี้ token ที่ผิด
+            Self::InvalidFilter(position, token, message) => ErrorResponse {
+                error: format!("Invalid filter at position {} near '{}': {}", position, token, message),
+                status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📦 batch ว่างหรือใหญ่เกิน - แบ่งเป็นหลาย request
+            Self::InvalidBatch(message) => ErrorResponse {
+                error: format!("Invalid batch: {}", message),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🩹 patch ใช้กับ item นี้ไม่ได้ - แก้ patch แล้วส่งใหม่
+            Self::InvalidPatch(message) => ErrorResponse {
+                error: format!("Invalid patch: {}", message),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🩹 ค่าปัจจุบันไม่ตรงกับที่ patch คาดไว้ - อ่าน item ใหม่ก่อน
+            Self::PatchTestFailed(path) => ErrorResponse {
+                error: format!("Patch test failed at {}", path),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
             },
             // 🔄 Item มีอยู่แล้ว - เป็น conflict error
             Self::ItemAlreadyExists(name) => ErrorResponse {
                 error: format!("Item is already exists: {}", name),
                 status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📜 ไม่พบแบบ - เป็น not found error
+            Self::ItemDefinitionNotFound(id) => ErrorResponse {
+                error: format!("Item definition not found: {}", id),
+                status_code: StatusCode::NOT_FOUND,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📜 ชื่อแบบซ้ำ - เป็น conflict error
+            Self::ItemDefinitionAlreadyExists(name) => ErrorResponse {
+                error: format!("Item definition is already exists: {}", name),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💾 Error จากฐานข้อมูล - เป็น server error
+            Self::FetchingItemDefinitionError(err) => ErrorResponse {
+                error: format!("Failed to fetch item definition {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::SavingItemDefinitionError(err) => ErrorResponse {
+                error: format!("Failed to save item definition {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
             },
             // 💾 Error จากฐานข้อมูล - เป็น server error
             Self::AddingItemError(err) => ErrorResponse {
                 error: format!("Failed to add item {:?}", err),
                 status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💾 Error จากฐานข้อมูลขณะอ่าน - เป็น server error
+            Self::FetchingItemError(err) => ErrorResponse {
+                error: format!("Failed to fetch item {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💾 Error จากฐานข้อมูลขณะแก้ไข - เป็น server error
+            Self::UpdatingItemError(err) => ErrorResponse {
+                error: format!("Failed to update item {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::RemovingItemError(err) => ErrorResponse {
+                error: format!("Failed to remove item {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // ♻️ ชื่อถูกใช้ไปแล้วหลังจากลบ - ขัดกับสถานะปัจจุบัน จึงเป็น conflict
+            Self::ItemRestoreConflict(id) => ErrorResponse {
+                error: format!("Item {} cannot be restored: an item with the same name already exists", id),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📜 ไม่พบ revision - เป็น not found error
+            Self::ItemRevisionNotFound(item_id, revision) => ErrorResponse {
+                error: format!("Revision {} of item {} not found", revision, item_id),
+                status_code: StatusCode::NOT_FOUND,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // ⏪ ค่าเดิมขัดกับสถานะปัจจุบัน (ชื่อซ้ำ / ประเภทถูกลบ) - เป็น conflict
+            Self::ItemRevisionNotRevertible(revision, reason) => ErrorResponse {
+                error: format!("Cannot revert to revision {}: {}", revision, reason),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔢 มีคนแก้ไขไปก่อน - If-Match ไม่ตรงเป็น 412 / field version ใน body ไม่ตรงเป็น 409
+            Self::VersionConflict(precondition, current) => ErrorResponse {
+                error: match precondition.expected() {
+                    Some(expected) => format!("Version conflict: expected version {}, current version is {}", expected, current),
+                    None => format!("Version conflict: item was modified concurrently, current version is {}", current),
+                },
+                status_code: match precondition {
+                    Precondition::IfMatch(_) => StatusCode::PRECONDITION_FAILED,
+                    _ => StatusCode::CONFLICT,
+                },
+                fields: Vec::new(),
+                current_version: Some(*current),
+            },
+            // 🔢 เงื่อนไข version ไม่มีทางตรงเมื่อไม่มีแถวเดิม - ไม่เพิ่มใหม่แทน
+            Self::UpsertTargetNotFound(name) => ErrorResponse {
+                error: format!("Precondition failed: no item named {} to replace", name),
+                status_code: StatusCode::PRECONDITION_FAILED,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🎒 กระเป๋าเต็ม - ขัดกับสถานะปัจจุบันของผู้เล่น จึงเป็น conflict
+            Self::InventoryFull(reason) => ErrorResponse {
+                error: format!("Inventory is full: {}", reason),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
             },
             // 🔍 ไม่พบข้อมูล - เป็น not found error
             Self::ItemNotFound(id) => ErrorResponse {
                 error: format!("Item not found: {}", id),
                 status_code: StatusCode::NOT_FOUND,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🎮 ไม่พบผู้เล่น - เป็น not found error
+            Self::PlayerNotFound(id) => ErrorResponse {
+                error: format!("Player not found: {}", id),
+                status_code: StatusCode::NOT_FOUND,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🎮 ผู้เล่นมีอยู่แล้ว - เป็น conflict error
+            Self::PlayerAlreadyExists(name) => ErrorResponse {
+                error: format!("Player is already exists: {}", name),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💾 Error จากฐานข้อมูล - เป็น server error
+            Self::AddingPlayerError(err) => ErrorResponse {
+                error: format!("Failed to add player {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🏅 tier ไม่มีในการตั้งค่า - เป็น client error เหมือนประเภท
+            Self::InvalidPlayerTier(tier) => ErrorResponse {
+                error: format!("Invalid player tier: {}", tier),
+                status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::UpdatingPlayerError(err) => ErrorResponse {
+                error: format!("Failed to update player {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            Self::FetchingPlayerError(err) => ErrorResponse {
+                error: format!("Failed to fetch player {:?}", err),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🚦 เรียกถี่เกินไป - client ต้องรอตาม Retry-After
+            Self::TooManyRequests(retry_after) => ErrorResponse {
+                error: format!("Too many requests, retry after {} seconds", retry_after),
+                status_code: StatusCode::TOO_MANY_REQUESTS,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🏋️ ระบบยุ่งเกินไป - ตัด request ทิ้งแทนการรอ connection จาก pool
+            Self::ServiceOverloaded => ErrorResponse {
+                error: "Service is overloaded, please try again later".to_string(),
+                status_code: StatusCode::SERVICE_UNAVAILABLE,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // ⏱️ ประมวลผลนานเกินไป
+            Self::RequestTimeout(secs) => ErrorResponse {
+                error: format!("Request timed out after {} seconds", secs),
+                status_code: StatusCode::REQUEST_TIMEOUT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📦 body ใหญ่เกิน - เป็น client error
+            Self::PayloadTooLarge => ErrorResponse {
+                error: "Request body is too large".to_string(),
+                status_code: StatusCode::PAYLOAD_TOO_LARGE,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📄 Content-Type ที่ไม่รองรับ - ส่งใหม่ในรูปแบบที่รองรับ
+            Self::UnsupportedMediaType(content_type) => ErrorResponse {
+                error: format!("Unsupported content type: {}", content_type),
+                status_code: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔑 key ใช้ไม่ได้ - เป็น client error
+            Self::InvalidIdempotencyKey(reason) => ErrorResponse {
+                error: format!("Invalid Idempotency-Key: {}", reason),
+                status_code: StatusCode::BAD_REQUEST,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔑 key เดิมแต่ payload ต่างจากครั้งแรก - ต้องใช้ key ใหม่
+            Self::IdempotencyKeyReused(key) => ErrorResponse {
+                error: format!("Idempotency-Key was used with a different request: {}", key),
+                status_code: StatusCode::UNPROCESSABLE_ENTITY,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🔑 ครั้งแรกยังไม่เสร็จ - retry อีกครั้งภายหลังจะได้ผลของครั้งแรก
+            Self::IdempotencyKeyInProgress(key) => ErrorResponse {
+                error: format!("A request with this Idempotency-Key is still in progress: {}", key),
+                status_code: StatusCode::CONFLICT,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 📥 body ผิดรูปแบบ - ใช้ status เดิมจาก extractor (400/415/422)
+            Self::InvalidRequestBody(status_code, message) => ErrorResponse {
+                error: format!("Invalid request body: {}", message),
+                status_code: *status_code,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 🆔 ข้อมูลจากฐานข้อมูลไม่สมบูรณ์ - เป็น server error
+            Self::MissingEntityId(name) => ErrorResponse {
+                error: format!("Entity has no id: {}", name),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
+            },
+            // 💥 ไม่เปิดเผยรายละเอียดให้ client - ดูใน log ด้วย request id แทน
+            Self::InternalServerError => ErrorResponse {
+                error: "Internal server error".to_string(),
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                fields: Vec::new(),
+                current_version: None,
             },
         }
     }
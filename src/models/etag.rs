@@ -0,0 +1,94 @@
+// === 🏷️ ETag Models ===
+// 📖 สร้าง strong ETag จาก id + version และเทียบกับ If-None-Match เพื่อตอบ 304 Not Modified
+// 🔒 If-Match (หรือ field version ใน body) ใช้เป็นเงื่อนไขของการแก้ไข - optimistic concurrency
+// 🎯 SOLID: Single Responsibility - เฉพาะเรื่อง conditional request
+
+use chrono::NaiveDateTime;
+
+use crate::models::error::{APIError, IntoErrorResponse};
+
+// 📦 Tagged: ข้อมูลที่ส่งกลับพร้อม ETag ของมัน
+pub struct Tagged<T> {
+    pub value: T,      // 📤 ข้อมูลที่จะส่งให้ client
+    pub etag: String,  // 🏷️ ETag ในรูปแบบ "..." (มี double quote แล้ว)
+}
+
+// 🏷️ ETag ของ item เดียว: เปลี่ยนทุกครั้งที่ version เปลี่ยน (ทุกการแก้ไข)
+pub fn item_etag(id: i32, version: i32) -> String {
+    format!("\"{}-v{}\"", id, version)
+}
+
+// 🏷️ ETag ของรายการ: hash (FNV-1a) ของ id + updated_at ทุกแถวตามลำดับ
+// ใช้ FNV แทน DefaultHasher เพราะต้องได้ค่าเดิมทุกครั้งแม้ restart หรือ build ใหม่
+pub fn list_etag(items: impl IntoIterator<Item = (i32, NaiveDateTime)>) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut count: usize = 0;
+    for (id, updated_at) in items {
+        let bytes = [id.to_be_bytes().as_slice(), &updated_at.and_utc().timestamp_micros().to_be_bytes()].concat();
+        for byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        count += 1;
+    }
+    format!("\"{}-{:016x}\"", count, hash)
+}
+
+// 🔍 ตรวจ If-None-Match: true ถ้า client มีข้อมูลล่าสุดอยู่แล้ว (ตอบ 304 ได้)
+// ใช้ weak comparison ตาม RFC 9110 จึงตัด W/ ออกก่อนเทียบ
+pub fn none_matched(if_none_match: Option<&str>, etag: &str) -> bool {
+    match if_none_match {
+        Some(header) => header
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag),
+        None => false,
+    }
+}
+
+// 🔒 Precondition: version ที่ client แก้ไขจาก - ไม่ตรงกับปัจจุบันแปลว่ามีคนแก้ไขไปก่อนแล้ว
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Precondition {
+    #[default]
+    Any,          // ✅ ไม่ตรวจ (ไม่ส่งทั้ง If-Match และ version / If-Match: *)
+    Version(i32), // 📥 field version ใน body - ไม่ตรงได้ 409
+    IfMatch(i32), // 🏷️ If-Match header - ไม่ตรงได้ 412
+}
+
+impl Precondition {
+    // 📥 อ่านจาก If-Match ก่อน แล้วจึง field version ใน body
+    // 🏷️ If-Match ที่ไม่มี ETag ของ item นี้ (หรือเป็น weak ETag) ไม่มีทางตรง จึงเป็น version 0
+    pub fn from_request(if_match: Option<&str>, id: i32, version: Option<i32>) -> Self {
+        let prefix = format!("\"{}-v", id);
+        match (if_match, version) {
+            (Some(header), _) => {
+                let tags: Vec<&str> = header.split(',').map(str::trim).collect();
+                if tags.contains(&"*") {
+                    return Self::Any;
+                }
+                let expected = tags
+                    .iter()
+                    .find_map(|tag| tag.strip_prefix(prefix.as_str())?.strip_suffix('"')?.parse().ok());
+                Self::IfMatch(expected.unwrap_or(0))
+            }
+            (None, Some(version)) => Self::Version(version),
+            (None, None) => Self::Any,
+        }
+    }
+
+    // 🔢 version ที่ต้องตรง (None = ไม่ตรวจ)
+    pub fn expected(&self) -> Option<i32> {
+        match self {
+            Self::Any => None,
+            Self::Version(version) | Self::IfMatch(version) => Some(*version),
+        }
+    }
+
+    // 🔍 เทียบกับ version ปัจจุบันของ item
+    pub fn checking(&self, current: i32) -> Result<(), Box<dyn IntoErrorResponse>> {
+        match self.expected() {
+            Some(expected) if expected != current => Err(Box::new(APIError::VersionConflict(*self, current))),
+            _ => Ok(()),
+        }
+    }
+}
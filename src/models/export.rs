@@ -0,0 +1,203 @@
+// === 📤 Export Models ===
+// 📖 ส่งออก item ทั้งตาราง: เลือกรูปแบบ (NDJSON / CSV / JSON) และแปลงทีละแถวเป็น bytes ของ response
+// 🎯 SOLID: Single Responsibility - เฉพาะรูปแบบไฟล์ การอ่านจากฐานข้อมูลอยู่ใน ItemsUsecase::exporting
+
+use std::io;
+
+use axum::body::Bytes;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime};
+use futures_util::{
+    Stream, StreamExt,
+    stream::{self, BoxStream},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::models::{error::APIError, item::Item};
+
+// 📄 column ของ CSV ตามลำดับ (stats / attributes เป็น JSON ใน cell - อ่านกลับด้วย POST /items/import ได้)
+const CSV_COLUMNS: [&str; 16] = [
+    "id",
+    "name",
+    "category",
+    "owner_id",
+    "weight",
+    "rarity",
+    "required_level",
+    "stats",
+    "attributes",
+    "attributes_schema_version",
+    "definition_id",
+    "durability",
+    "version",
+    "deleted_at",
+    "created_at",
+    "updated_at",
+];
+
+// ⏰ รูปแบบเวลาใน CSV (ตรงกับ JSON ของ NaiveDateTime)
+const CSV_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+// 📄 ExportFormat: รูปแบบไฟล์จาก ?format= (ไม่ส่ง = ndjson)
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Ndjson, // 📄 หนึ่ง item ต่อบรรทัด
+    Csv,    // 📄 header แถวแรก
+    Json,   // 📄 JSON array เดียว
+}
+
+impl ExportFormat {
+    // 🏷️ Content-Type ของ response
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Ndjson => "application/x-ndjson",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+
+    // 📎 นามสกุลไฟล์ใน Content-Disposition
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Ndjson => "ndjson",
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    // 🌊 แปลง stream ของ item เป็น stream ของ bytes ทีละแถว (ไม่รวมทั้งไฟล์ไว้ในหน่วยความจำ)
+    // ❌ error กลาง stream ทำให้ response ถูกตัด - client เห็นไฟล์ไม่ครบแทนไฟล์ที่ดูเหมือนสมบูรณ์
+    pub fn encoding<S>(self, items: S) -> BoxStream<'static, Result<Bytes, io::Error>>
+    where
+        S: Stream<Item = Result<ExportedItem, io::Error>> + Send + 'static,
+    {
+        let header = stream::iter(self.header().map(Ok));
+        let rows = items
+            .enumerate()
+            .map(move |(index, item)| item.and_then(|item| self.row(index, &item)));
+        let footer = stream::iter(self.footer().map(Ok));
+        Box::pin(header.chain(rows).chain(footer))
+    }
+
+    fn header(&self) -> Option<Bytes> {
+        match self {
+            Self::Ndjson => None,
+            Self::Csv => Some(Bytes::from(format!("{}\n", CSV_COLUMNS.join(",")))),
+            Self::Json => Some(Bytes::from_static(b"[")),
+        }
+    }
+
+    fn footer(&self) -> Option<Bytes> {
+        match self {
+            Self::Json => Some(Bytes::from_static(b"]")),
+            _ => None,
+        }
+    }
+
+    // 📄 หนึ่งแถว (index ใช้คั่น "," ของ JSON array)
+    fn row(&self, index: usize, item: &ExportedItem) -> Result<Bytes, io::Error> {
+        let mut buffer = Vec::new();
+        match self {
+            Self::Ndjson => {
+                serde_json::to_writer(&mut buffer, item)?;
+                buffer.push(b'\n');
+            }
+            Self::Json => {
+                if index > 0 {
+                    buffer.push(b',');
+                }
+                serde_json::to_writer(&mut buffer, item)?;
+            }
+            Self::Csv => {
+                let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(&mut buffer);
+                writer.write_record(csv_record(item)?)?;
+                writer.flush()?;
+            }
+        }
+        Ok(Bytes::from(buffer))
+    }
+}
+
+// 🔎 ExportQuery: query string ของ GET /items/export
+// ⏰ updated_from <= updated_at < updated_to (RFC 3339, เวลาท้องถิ่นแบบไม่มี timezone หรือแค่วันที่)
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,         // 📄 ndjson / csv / json
+    pub category: Option<String>,     // 🌳 ประเภท (รวมประเภทลูกหลาน)
+    pub updated_from: Option<String>, // ⏰ แก้ไขตั้งแต่
+    pub updated_to: Option<String>,   // ⏰ แก้ไขก่อน
+}
+
+impl ExportQuery {
+    // ⏰ ช่วงเวลาที่ขอ - อ่านไม่ได้หรือช่วงกลับด้านได้ 400
+    pub fn range(&self) -> Result<(Option<NaiveDateTime>, Option<NaiveDateTime>), APIError> {
+        let from = self.updated_from.as_deref().map(|v| timestamp_parsing("updated_from", v)).transpose()?;
+        let to = self.updated_to.as_deref().map(|v| timestamp_parsing("updated_to", v)).transpose()?;
+
+        if let (Some(from), Some(to)) = (from, to)
+            && from >= to
+        {
+            return Err(APIError::InvalidQuery(format!(
+                "updated_from {} must be before updated_to {}",
+                from, to
+            )));
+        }
+        Ok((from, to))
+    }
+}
+
+// ⏰ เวลาในฐานข้อมูลเป็นเวลาท้องถิ่นของเครื่อง - เวลาที่มี timezone แปลงเป็นเวลาท้องถิ่นก่อน
+fn timestamp_parsing(field: &str, value: &str) -> Result<NaiveDateTime, APIError> {
+    match timestamp_reading(value) {
+        Some(timestamp) => Ok(timestamp),
+        None => Err(APIError::InvalidQuery(format!("{} is not a timestamp: {}", field, value))),
+    }
+}
+
+// ⏰ RFC 3339 / เวลาท้องถิ่นแบบ JSON / วันที่ (เที่ยงคืน) - ใช้ร่วมกับ ?filter=
+pub fn timestamp_reading(value: &str) -> Option<NaiveDateTime> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.with_timezone(&Local).naive_local());
+    }
+    if let Ok(timestamp) = NaiveDateTime::parse_from_str(value, CSV_TIMESTAMP_FORMAT) {
+        return Some(timestamp);
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+// 📦 ExportedItem: item หนึ่งแถวของไฟล์ export (Item + เวลาที่สร้าง/แก้ไขสำหรับ backup)
+#[derive(Serialize, Clone, PartialEq)]
+pub struct ExportedItem {
+    #[serde(flatten)]
+    pub item: Item,                   // 📦 ข้อมูลเดียวกับ GET /items/{id}
+    pub created_at: NaiveDateTime,    // 📅 วันที่สร้าง
+    pub updated_at: NaiveDateTime,    // 📅 วันที่แก้ไขล่าสุด
+}
+
+// 📄 ค่าของแต่ละ column ตาม CSV_COLUMNS (None = cell ว่าง)
+fn csv_record(exported: &ExportedItem) -> Result<Vec<String>, io::Error> {
+    let item = &exported.item;
+    let optional = |value: Option<i32>| value.map(|v| v.to_string()).unwrap_or_default();
+    Ok(vec![
+        item.id.to_string(),
+        item.name.clone(),
+        item.category.to_string(),
+        optional(item.owner_id),
+        item.weight.to_string(),
+        item.rarity.to_string(),
+        item.required_level.to_string(),
+        serde_json::to_string(&item.stats)?,
+        serde_json::to_string(&item.attributes)?,
+        optional(item.attributes_schema_version),
+        optional(item.definition_id),
+        optional(item.durability),
+        item.version.to_string(),
+        item.deleted_at.map(|t| t.format(CSV_TIMESTAMP_FORMAT).to_string()).unwrap_or_default(),
+        exported.created_at.format(CSV_TIMESTAMP_FORMAT).to_string(),
+        exported.updated_at.format(CSV_TIMESTAMP_FORMAT).to_string(),
+    ])
+}
@@ -0,0 +1,311 @@
+// === 🧮 Filter Models ===
+// 📖 ภาษา filter แบบ RSQL / FIQL ของ ?filter= เช่น category==Staff;rarity=in=(Epic,Legendary);created_at>2026-01-01
+//    ; = และ / , = หรือ / (...) = จัดกลุ่ม / ==  !=  <  <=  >  >=  =lt=  =le=  =gt=  =ge=  =in=  =out=
+//    ค่าที่มีอักขระพิเศษใส่ใน "..." หรือ '...' ได้ / * ในค่าของ == และ != (field ข้อความ) แทนข้อความใด ๆ
+// 🎯 SOLID: Single Responsibility - แปลงข้อความเป็น ItemsExpression เท่านั้น การกรองจริงอยู่ใน Repository
+
+use crate::{
+    entities::items::{ItemsExpression, ItemsField, ItemsOperator, ItemsValue},
+    models::{error::APIError, export::timestamp_reading, item::Rarity},
+};
+
+// 🛡️ จำกัดขนาดของ filter (กัน recursion ลึกและ bind parameter เกินที่ Postgres รับได้)
+const MAX_FILTER_CHARS: usize = 2000;
+const MAX_FILTER_DEPTH: usize = 8;
+const MAX_FILTER_VALUES: usize = 200;
+
+// 🔤 อักขระที่ต้องอยู่ในเครื่องหมายคำพูดถ้าเป็นส่วนของค่า
+const RESERVED_CHARS: &str = "\"'();,=!~<>";
+
+// 🧮 แปลง ?filter= เป็น ItemsExpression - ผิดรูปแบบได้ APIError::InvalidFilter ที่ชี้ตำแหน่งและ token
+pub fn filter_parsing(text: &str) -> Result<ItemsExpression, APIError> {
+    let mut parser = FilterParser {
+        chars: text.chars().collect(),
+        position: 0,
+        depth: 0,
+        values: 0,
+    };
+    if parser.chars.len() > MAX_FILTER_CHARS {
+        return Err(parser.failing(MAX_FILTER_CHARS, format!("filter must be at most {} characters", MAX_FILTER_CHARS)));
+    }
+    parser.skipping();
+    if parser.peek().is_none() {
+        return Err(parser.failing(0, "filter must not be empty".to_string()));
+    }
+    let expression = parser.or_parsing()?;
+    match parser.peek() {
+        None => Ok(expression),
+        Some(_) => Err(parser.failing(parser.position, "expected ';', ',' or end of filter".to_string())),
+    }
+}
+
+// 📏 ชนิดค่าของ field - กำหนดว่าแปลงค่าอย่างไรและใช้ตัวเปรียบเทียบอะไรได้
+#[derive(Clone, Copy, PartialEq)]
+enum FieldKind {
+    Integer,
+    Text,
+    Rarity,
+    Timestamp,
+}
+
+fn field_kind(field: ItemsField) -> FieldKind {
+    match field {
+        ItemsField::Id
+        | ItemsField::OwnerId
+        | ItemsField::Weight
+        | ItemsField::RequiredLevel
+        | ItemsField::DefinitionId
+        | ItemsField::Durability => FieldKind::Integer,
+        ItemsField::Name | ItemsField::Category => FieldKind::Text,
+        ItemsField::Rarity => FieldKind::Rarity,
+        ItemsField::CreatedAt | ItemsField::UpdatedAt => FieldKind::Timestamp,
+    }
+}
+
+// 🔍 อ่าน filter ทีละตัวอักษร (recursive descent) - position เป็นตำแหน่งตัวอักษร ไม่ใช่ byte
+struct FilterParser {
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,   // 📐 ความลึกของวงเล็บ
+    values: usize,  // 🔢 จำนวนค่าทั้งหมด (= bind parameter)
+}
+
+impl FilterParser {
+    // , : เงื่อนไขใดเงื่อนไขหนึ่งจริง (ผูกหลวมกว่า ;)
+    fn or_parsing(&mut self) -> Result<ItemsExpression, APIError> {
+        let mut expressions = vec![self.and_parsing()?];
+        while self.eating(',') {
+            expressions.push(self.and_parsing()?);
+        }
+        Ok(match expressions.len() {
+            1 => expressions.remove(0),
+            _ => ItemsExpression::Or(expressions),
+        })
+    }
+
+    // ; : ทุกเงื่อนไขต้องจริง
+    fn and_parsing(&mut self) -> Result<ItemsExpression, APIError> {
+        let mut expressions = vec![self.constraint_parsing()?];
+        while self.eating(';') {
+            expressions.push(self.constraint_parsing()?);
+        }
+        Ok(match expressions.len() {
+            1 => expressions.remove(0),
+            _ => ItemsExpression::And(expressions),
+        })
+    }
+
+    // (...) หรือ field operator ค่า
+    fn constraint_parsing(&mut self) -> Result<ItemsExpression, APIError> {
+        let start = self.position;
+        if !self.eating('(') {
+            return self.comparison_parsing();
+        }
+        if self.depth == MAX_FILTER_DEPTH {
+            return Err(self.failing(start, format!("groups must be nested at most {} levels", MAX_FILTER_DEPTH)));
+        }
+        self.depth += 1;
+        let expression = self.or_parsing()?;
+        self.depth -= 1;
+        match self.eating(')') {
+            true => Ok(expression),
+            false => Err(self.failing(self.position, "expected ')'".to_string())),
+        }
+    }
+
+    fn comparison_parsing(&mut self) -> Result<ItemsExpression, APIError> {
+        let start = self.position;
+        let selector: String = self.taking(|c| c.is_ascii_alphanumeric() || c == '_');
+        if selector.is_empty() {
+            return Err(self.failing(start, "expected a field".to_string()));
+        }
+        let Some(field) = ItemsField::ALL.into_iter().find(|field| field.column() == selector) else {
+            let fields: Vec<&str> = ItemsField::ALL.iter().map(|field| field.column()).collect();
+            return Err(self.failing(start, format!("unknown field, expected one of {}", fields.join(", "))));
+        };
+        self.skipping();
+
+        let operator_start = self.position;
+        let operator = self.operator_parsing()?;
+        let kind = field_kind(field);
+        if kind != FieldKind::Integer
+            && kind != FieldKind::Timestamp
+            && !matches!(operator, ItemsOperator::Equal | ItemsOperator::NotEqual | ItemsOperator::In | ItemsOperator::NotIn)
+        {
+            return Err(self.failing(operator_start, format!("operator is not supported for {}", field.column())));
+        }
+        self.skipping();
+
+        let multiple = matches!(operator, ItemsOperator::In | ItemsOperator::NotIn);
+        let mut values = Vec::new();
+        if multiple && self.eating('(') {
+            loop {
+                values.push(self.value_parsing(field, kind)?);
+                if self.eating(')') {
+                    break;
+                }
+                if !self.eating(',') {
+                    return Err(self.failing(self.position, "expected ',' or ')'".to_string()));
+                }
+            }
+        } else {
+            values.push(self.value_parsing(field, kind)?);
+        }
+        self.skipping();
+
+        // ✳️ * ในค่าของ == / != ของ field ข้อความ = เทียบแบบ LIKE
+        let operator = match (&operator, values.first()) {
+            (ItemsOperator::Equal, Some(ItemsValue::Text(text))) if kind == FieldKind::Text && text.contains('*') => ItemsOperator::Like,
+            (ItemsOperator::NotEqual, Some(ItemsValue::Text(text))) if kind == FieldKind::Text && text.contains('*') => ItemsOperator::NotLike,
+            _ => operator,
+        };
+        Ok(ItemsExpression::Comparison(field, operator, values))
+    }
+
+    fn operator_parsing(&mut self) -> Result<ItemsOperator, APIError> {
+        let start = self.position;
+        let operator = match (self.peek(), self.chars.get(self.position + 1).copied()) {
+            (Some('='), Some('=')) => "==".to_string(),
+            (Some('!'), Some('=')) => "!=".to_string(),
+            (Some('<'), Some('=')) => "<=".to_string(),
+            (Some('>'), Some('=')) => ">=".to_string(),
+            (Some('<'), _) => "<".to_string(),
+            (Some('>'), _) => ">".to_string(),
+            (Some('='), _) => {
+                let name: String = self.chars[start + 1..].iter().take_while(|c| c.is_ascii_alphabetic()).collect();
+                match self.chars.get(start + 1 + name.len()) {
+                    Some('=') => format!("={}=", name),
+                    _ => "=".to_string(),
+                }
+            }
+            _ => return Err(self.failing(start, "expected an operator".to_string())),
+        };
+        let result = match operator.as_str() {
+            "==" => ItemsOperator::Equal,
+            "!=" => ItemsOperator::NotEqual,
+            "<" | "=lt=" => ItemsOperator::Less,
+            "<=" | "=le=" => ItemsOperator::LessOrEqual,
+            ">" | "=gt=" => ItemsOperator::Greater,
+            ">=" | "=ge=" => ItemsOperator::GreaterOrEqual,
+            "=in=" => ItemsOperator::In,
+            "=out=" => ItemsOperator::NotIn,
+            _ => return Err(self.failing(start, "unknown operator".to_string())),
+        };
+        self.position += operator.chars().count();
+        Ok(result)
+    }
+
+    // 🔢 ค่าหนึ่งค่า (มีหรือไม่มีเครื่องหมายคำพูด) แปลงตามชนิดของ field
+    fn value_parsing(&mut self, field: ItemsField, kind: FieldKind) -> Result<ItemsValue, APIError> {
+        self.skipping();
+        let start = self.position;
+        let text = match self.peek() {
+            Some(quote) if quote == '"' || quote == '\'' => self.quoted_parsing(quote)?,
+            _ => {
+                let text: String = self.taking(|c| !c.is_whitespace() && !RESERVED_CHARS.contains(c));
+                if text.is_empty() {
+                    return Err(self.failing(start, "expected a value".to_string()));
+                }
+                text
+            }
+        };
+        self.skipping();
+
+        self.values += 1;
+        if self.values > MAX_FILTER_VALUES {
+            return Err(self.failing(start, format!("filter must have at most {} values", MAX_FILTER_VALUES)));
+        }
+        let failing = |reason: String| APIError::InvalidFilter(start + 1, text.clone(), reason);
+        match kind {
+            FieldKind::Integer => match text.parse::<i32>() {
+                Ok(value) => Ok(ItemsValue::Integer(value)),
+                Err(_) => Err(failing(format!("{} must be an integer", field.column()))),
+            },
+            FieldKind::Text => Ok(ItemsValue::Text(text.clone())),
+            FieldKind::Rarity => match serde_json::from_value::<Rarity>(serde_json::Value::String(text.clone())) {
+                Ok(rarity) => Ok(ItemsValue::Text(rarity.to_string())),
+                Err(_) => Err(failing("rarity must be one of Common, Uncommon, Rare, Epic, Legendary".to_string())),
+            },
+            FieldKind::Timestamp => match timestamp_reading(&text) {
+                Some(timestamp) => Ok(ItemsValue::Timestamp(timestamp)),
+                None => Err(failing(format!("{} must be a date or timestamp", field.column()))),
+            },
+        }
+    }
+
+    // 💬 "..." / '...' - \ นำหน้าตัวอักษรถัดไปให้เป็นตัวอักษรธรรมดา
+    fn quoted_parsing(&mut self, quote: char) -> Result<String, APIError> {
+        let start = self.position;
+        self.position += 1;
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            self.position += 1;
+            match c {
+                '\\' => match self.peek() {
+                    Some(escaped) => {
+                        text.push(escaped);
+                        self.position += 1;
+                    }
+                    None => break,
+                },
+                c if c == quote => return Ok(text),
+                c => text.push(c),
+            }
+        }
+        Err(self.failing(start, "unterminated quoted value".to_string()))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    // ✅ ข้ามช่องว่างแล้วกินตัวอักษรนี้ถ้าตรง
+    fn eating(&mut self, expected: char) -> bool {
+        self.skipping();
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            self.skipping();
+            return true;
+        }
+        false
+    }
+
+    fn skipping(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn taking(&mut self, accepting: impl Fn(char) -> bool) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(&accepting) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    // ❌ error ที่ชี้ตำแหน่ง (นับจาก 1) และ token ที่อยู่ตรงนั้น
+    fn failing(&self, start: usize, reason: String) -> APIError {
+        let token: String = match self.chars.get(start) {
+            None => "end of filter".to_string(),
+            // ⚖️ operator: =xx= / == / <= / ... ทั้งก้อน
+            Some('=' | '!' | '<' | '>' | '~') => {
+                let mut end = start + 1;
+                while self.chars[start] == '=' && self.chars.get(end).is_some_and(char::is_ascii_alphabetic) {
+                    end += 1;
+                }
+                if self.chars.get(end) == Some(&'=') {
+                    end += 1;
+                }
+                self.chars[start..end].iter().collect()
+            }
+            Some(c) if RESERVED_CHARS.contains(*c) => c.to_string(),
+            Some(_) => self.chars[start..]
+                .iter()
+                .take_while(|c| !c.is_whitespace() && !RESERVED_CHARS.contains(**c))
+                .take(40)
+                .collect(),
+        };
+        APIError::InvalidFilter(start + 1, token, reason)
+    }
+}
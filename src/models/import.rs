@@ -0,0 +1,167 @@
+// === 📥 Import Models ===
+// 📖 นำเข้า item ทีละมาก ๆ: แยกไฟล์ JSON array / NDJSON / CSV เป็นแถว และรายงานผลรายแถว
+// 🎯 SOLID: Single Responsibility - เฉพาะรูปแบบไฟล์และรายงาน การตรวจ/บันทึกอยู่ใน ImportUsecase
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::{error::APIError, item::StaffAdding};
+
+// 🔢 column ของ CSV ที่เป็นตัวเลข / เป็น JSON ทั้งก้อน
+const CSV_NUMBER_COLUMNS: [&str; 3] = ["owner_id", "weight", "required_level"];
+const CSV_JSON_COLUMNS: [&str; 2] = ["stats", "attributes"];
+
+// 🔎 ImportQuery: ?dry_run=true ตรวจอย่างเดียวไม่บันทึก
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ImportQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+// 📋 แถวที่อ่านจากไฟล์: ลำดับแถว (เริ่มที่ 1) กับ StaffAdding หรือเหตุผลที่อ่านไม่ได้
+pub type ImportLine = (usize, Result<StaffAdding, String>);
+
+// 📄 ImportFormat: รูปแบบไฟล์ เลือกจาก Content-Type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportFormat {
+    Json,   // 📄 application/json - JSON array ของ StaffAdding
+    Ndjson, // 📄 application/x-ndjson - StaffAdding บรรทัดละหนึ่ง
+    Csv,    // 📄 text/csv - header แถวแรก (stats / attributes เป็น JSON ใน cell)
+}
+
+impl ImportFormat {
+    // 🔍 เลือกรูปแบบจาก Content-Type (ไม่สน parameter เช่น charset) - ไม่รองรับได้ 415
+    pub fn from_content_type(content_type: Option<&str>) -> Result<Self, APIError> {
+        let mime = content_type
+            .and_then(|v| v.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match mime.as_str() {
+            "application/json" => Ok(Self::Json),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => Ok(Self::Ndjson),
+            "text/csv" => Ok(Self::Csv),
+            _ => Err(APIError::UnsupportedMediaType(mime)),
+        }
+    }
+
+    // 📋 แยกไฟล์เป็นแถว - แถวที่อ่านไม่ได้ไม่ทำให้ทั้งไฟล์ล้ม (ยกเว้นโครงสร้างไฟล์เสีย)
+    pub fn parsing(&self, body: &[u8]) -> Result<Vec<ImportLine>, APIError> {
+        match self {
+            Self::Json => json_parsing(body),
+            Self::Ndjson => ndjson_parsing(body),
+            Self::Csv => csv_parsing(body),
+        }
+    }
+}
+
+// 📄 JSON array: ลำดับแถวคือตำแหน่งใน array
+fn json_parsing(body: &[u8]) -> Result<Vec<ImportLine>, APIError> {
+    let values: Vec<Value> = serde_json::from_slice(body)
+        .map_err(|e| APIError::InvalidRequestBody(StatusCode::BAD_REQUEST, format!("Expected a JSON array: {}", e)))?;
+
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| (index + 1, staff_parsing(value)))
+        .collect())
+}
+
+// 📄 NDJSON: ลำดับแถวคือเลขบรรทัด (ข้ามบรรทัดว่าง)
+fn ndjson_parsing(body: &[u8]) -> Result<Vec<ImportLine>, APIError> {
+    let text = std::str::from_utf8(body)
+        .map_err(|e| APIError::InvalidRequestBody(StatusCode::BAD_REQUEST, format!("Body is not UTF-8: {}", e)))?;
+
+    Ok(text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let parsed = serde_json::from_str(line).map_err(|e| e.to_string()).and_then(staff_parsing);
+            (index + 1, parsed)
+        })
+        .collect())
+}
+
+// 📄 CSV: ลำดับแถวคือเลขบรรทัดในไฟล์ (header คือบรรทัด 1) / cell ว่าง = ไม่ส่ง field นั้น
+fn csv_parsing(body: &[u8]) -> Result<Vec<ImportLine>, APIError> {
+    let mut reader = csv::Reader::from_reader(body);
+    let headers = reader
+        .headers()
+        .map_err(|e| APIError::InvalidRequestBody(StatusCode::BAD_REQUEST, format!("Invalid CSV header: {}", e)))?
+        .clone();
+
+    let mut lines = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let row = record.as_ref().ok().and_then(|r| r.position()).map_or(index + 2, |p| p.line() as usize);
+        let parsed = record.map_err(|e| e.to_string()).and_then(|record| {
+            let mut fields = Map::new();
+            for (column, cell) in headers.iter().zip(record.iter()) {
+                let (column, cell) = (column.trim(), cell.trim());
+                if cell.is_empty() {
+                    continue;
+                }
+                fields.insert(column.to_string(), csv_cell_parsing(column, cell)?);
+            }
+            staff_parsing(Value::Object(fields))
+        });
+        lines.push((row, parsed));
+    }
+    Ok(lines)
+}
+
+// 🔢 แปลง cell ตามชนิดของ column
+fn csv_cell_parsing(column: &str, cell: &str) -> Result<Value, String> {
+    if CSV_NUMBER_COLUMNS.contains(&column) {
+        return cell
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("{} must be an integer", column));
+    }
+    if CSV_JSON_COLUMNS.contains(&column) {
+        return serde_json::from_str(cell).map_err(|e| format!("{} is not valid JSON: {}", column, e));
+    }
+    Ok(Value::String(cell.to_string()))
+}
+
+// 🔄 JSON ของแถวหนึ่ง -> StaffAdding (ใช้ default เดียวกับ POST /items/staff)
+fn staff_parsing(value: Value) -> Result<StaffAdding, String> {
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+// 🏷️ ImportStatus: ผลของแถวหนึ่ง (dry_run: created = จะถูกเพิ่ม)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    Created,         // ✅ เพิ่มแล้ว
+    Duplicate,       // 🔄 ชื่อซ้ำกับ item ที่มีอยู่หรือแถวก่อนหน้าในไฟล์
+    InvalidCategory, // 🏷️ ไม่มีประเภทนี้ใน catalog
+    InvalidName,     // 📝 ชื่อใช้ไม่ได้
+    Invalid,         // ❌ อ่านแถวไม่ได้หรือไม่ผ่านกติกาของประเภท
+    Failed,          // 💾 ฐานข้อมูลบันทึกไม่ได้
+}
+
+// 📋 ImportRow: รายงานของแถวหนึ่ง
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ImportRow {
+    pub row: usize,                 // 🔢 ลำดับแถวในไฟล์
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,       // 📝 ชื่อ item (ไม่มีถ้าอ่านแถวไม่ได้)
+    pub status: ImportStatus,       // 🏷️ ผลของแถว
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,            // 🆔 ID ที่ได้ (เฉพาะที่เพิ่มจริง)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,      // 💬 เหตุผลที่ไม่ผ่าน
+}
+
+// 📊 ImportReport: สรุปผลการนำเข้าทั้งไฟล์
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ImportReport {
+    pub dry_run: bool,          // 🧪 true = ยังไม่ได้บันทึก
+    pub total: usize,           // 🔢 จำนวนแถวทั้งหมด
+    pub created: usize,         // ✅ จำนวนที่เพิ่ม (หรือจะเพิ่ม)
+    pub rejected: usize,        // ❌ จำนวนที่ไม่ผ่าน
+    pub rows: Vec<ImportRow>,   // 📋 ผลรายแถวตามลำดับในไฟล์
+}
@@ -0,0 +1,23 @@
+// === 📋 API Models: Inventory ===
+// 📖 Data Transfer Objects ของกระเป๋าผู้เล่น
+
+use serde::{Deserialize, Serialize};
+
+// 🎒 Inventory Model: สรุปการใช้งานกระเป๋าเทียบกับ limit ของ tier
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Inventory {
+    pub player_id: i32,     // 🎮 เจ้าของกระเป๋า
+    pub tier: String,       // 🏅 tier ที่ใช้คำนวณ limit
+    pub used_slots: usize,  // 🔢 จำนวน item ที่มี
+    pub max_slots: usize,   // 🔢 จำนวน item สูงสุด
+    pub used_weight: i64,   // ⚖️ น้ำหนักรวมปัจจุบัน
+    pub max_weight: i64,    // ⚖️ น้ำหนักรวมสูงสุด
+}
+
+// 📥 ItemTransferring Model: ย้าย item ไปให้ผู้เล่นอีกคน
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ItemTransferring {
+    pub to_player_id: i32,  // 🎮 ผู้เล่นที่จะได้รับ item
+    #[serde(default)]
+    pub version: Option<i32>, // 🔢 version ที่โอนจาก (ไม่ส่ง = ไม่ตรวจ)
+}
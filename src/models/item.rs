@@ -2,9 +2,16 @@
 // 📖 Data Transfer Objects (DTOs) สำหรับการสื่อสารกับ API
 // 🎯 SOLID Principle: Single Responsibility - แต่ละ struct มีหน้าที่เฉพาะเจาะจง
 
-use crate::{entities::items::Items as ItemsEntity, time_helper::IntoTimerHelperShared};
+use crate::{
+    entities::items::{Items as ItemsEntity, ItemsExpression, ItemsFilter},
+    time_helper::IntoTimerHelperShared,
+};
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::types::Json;
 use std::fmt::Display;
+use unicode_normalization::UnicodeNormalization;
 
 // 🏷️ Category Enum: กำหนดประเภทของ Item ที่รองรับในระบบ
 //
@@ -13,10 +20,39 @@ use std::fmt::Display;
 // ⚖️ PartialEq: สำหรับการเปรียบเทียบ
 //
 // 🎯 SOLID: Open/Closed Principle - เพิ่มประเภทใหม่ได้โดยไม่แก้โค้ดเดิม
-#[derive(Serialize, Deserialize, Clone, PartialEq)]
+// 📚 ประเภทที่ designer เพิ่มผ่าน catalog (ตาราง categories) เป็น Catalog(name)
+//    JSON ยังเป็น string เหมือนเดิม ("Staff", "Sword", "Bow", ...)
+//    ประเภทนั้นมีอยู่จริงหรือไม่ ตรวจกับ catalog ใน CategoriesUsecase
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(from = "String", into = "String")]
 pub enum Category {
-    Staff,  // 🪄 ไม้เท้าวิเศษ
-    Sword,  // ⚔️ ดาบ
+    Staff,           // 🪄 ไม้เท้าวิเศษ
+    Sword,           // ⚔️ ดาบ
+    Catalog(String), // 📚 ประเภทจาก catalog ที่ไม่มีกติกาเฉพาะในโค้ด
+}
+
+impl Category {
+    // 🔒 ประเภทที่โค้ดมีกติกาเฉพาะ - ห้ามลบหรือเปลี่ยนชื่อใน catalog
+    pub fn is_built_in(&self) -> bool {
+        !matches!(self, Self::Catalog(_))
+    }
+}
+
+// 🔄 แปลง String เป็น Category: ชื่อที่ไม่ใช่ built-in เป็น Catalog
+impl From<String> for Category {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "Staff" => Self::Staff,
+            "Sword" => Self::Sword,
+            _ => Self::Catalog(name),
+        }
+    }
+}
+
+impl From<Category> for String {
+    fn from(category: Category) -> Self {
+        category.to_string()
+    }
 }
 
 // 🎨 Display Trait Implementation: แปลง Category เป็น String
@@ -26,24 +62,116 @@ impl Display for Category {
         match self {
             Self::Staff => write!(f, "Staff"),  // 🪄 แปลง Staff enum เป็น "Staff" string
             Self::Sword => write!(f, "Sword"),  // ⚔️ แปลง Sword enum เป็น "Sword" string
+            Self::Catalog(name) => write!(f, "{}", name), // 📚 ชื่อตาม catalog
+        }
+    }
+}
+
+// 💎 Rarity Enum: ความหายากของ Item เรียงจากน้อยไปมาก
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub enum Rarity {
+    #[default]
+    Common,     // ⚪ ทั่วไป
+    Uncommon,   // 🟢 พบได้บ้าง
+    Rare,       // 🔵 หายาก
+    Epic,       // 🟣 หายากมาก
+    Legendary,  // 🟠 ตำนาน
+}
+
+impl Display for Rarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Common => write!(f, "Common"),
+            Self::Uncommon => write!(f, "Uncommon"),
+            Self::Rare => write!(f, "Rare"),
+            Self::Epic => write!(f, "Epic"),
+            Self::Legendary => write!(f, "Legendary"),
         }
     }
 }
 
+// 📊 ItemStats: ค่าสถานะเฉพาะของแต่ละประเภท (เก็บเป็น JSONB)
+// 🪄 Staff ใช้ magic_power / ⚔️ Sword ใช้ attack + durability
+// ✅ ประเภทไหนต้องมี field ไหน มาจาก rules ของประเภทใน catalog (models::category::CategoryRules)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ItemStats {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub magic_power: Option<i32>,  // 🪄 พลังเวท
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attack: Option<i32>,       // ⚔️ พลังโจมตี
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durability: Option<i32>,   // 🛡️ ความทนทาน
+}
+
+impl ItemStats {
+    // 🏷️ ชื่อ stats ทั้งหมดที่ rules ของประเภทอ้างถึงได้
+    pub const NAMES: [&'static str; 3] = ["magic_power", "attack", "durability"];
+
+    // 📋 (ชื่อ, ค่า) ของ stats ที่ส่งมา
+    pub fn present(&self) -> Vec<(&'static str, i32)> {
+        Self::NAMES
+            .into_iter()
+            .zip([self.magic_power, self.attack, self.durability])
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect()
+    }
+}
+
+// 🧩 ItemAttributes: attributes อิสระของ item (JSON object) - รูปแบบกำหนดด้วย JSON Schema ของประเภท
+pub type ItemAttributes = Map<String, Value>;
+
 // 📦 Item Model: API response model สำหรับ Item ที่สมบูรณ์
+// 🎲 Item คือ instance - ค่าสถานะของชิ้นนี้อาจต่างจาก base_stats ของแบบ (ItemDefinition)
 // 🎯 SOLID: Single Responsibility - เฉพาะข้อมูลสำหรับ API response
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct Item {
     pub id: i32,           // 🆔 Primary key จากฐานข้อมูล
     pub name: String,      // 📝 ชื่อของ item
     pub category: Category, // 🏷️ ประเภทในรูปแบบ enum
+    pub owner_id: Option<i32>, // 👤 ผู้เล่นที่เป็นเจ้าของ (null = item กลางของระบบ)
+    pub weight: i32,       // ⚖️ น้ำหนัก
+    pub rarity: Rarity,    // 💎 ความหายาก
+    pub required_level: i32, // 🎚️ เลเวลขั้นต่ำที่ใช้ได้
+    pub stats: ItemStats,  // 📊 ค่าสถานะตามประเภท
+    pub attributes: ItemAttributes, // 🧩 attributes ตาม schema ของประเภท
+    pub attributes_schema_version: Option<i32>, // 🧾 เวอร์ชัน schema ที่ใช้ตรวจ (null = ไม่มี schema)
+    pub definition_id: Option<i32>, // 📜 แบบของ item (null = item แบบเดิมที่ไม่มีแบบ)
+    pub durability: Option<i32>, // 🔧 ความทนทานที่เหลือ (null = ไม่สึกหรอ)
+    pub version: i32,      // 🔢 version ปัจจุบัน (ส่งกลับมาตอนแก้ไขเพื่อกันการเขียนทับ)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<NaiveDateTime>, // 🗑️ เวลาที่ถูกลบ (แสดงเฉพาะ item ที่ถูกลบ)
 }
 
 impl Item {
     // 🔄 แปลง API Model เป็น Domain Entity
     // 🎯 SOLID: Dependency Inversion - รับ timer helper เป็น parameter
     pub fn to_entity(&self, t: IntoTimerHelperShared) -> ItemsEntity {
-        ItemsEntity::new(self.name.to_string(), self.category.to_string(), t)
+        ItemsEntity {
+            owner_id: self.owner_id,
+            weight: self.weight,
+            rarity: self.rarity.to_string(),
+            required_level: self.required_level,
+            stats: Json(self.stats.clone()),
+            attributes: Json(self.attributes.clone()),
+            attributes_schema_version: self.attributes_schema_version,
+            definition_id: self.definition_id,
+            durability: self.durability,
+            ..ItemsEntity::new(name_normalizing(&self.name), self.category.to_string(), t)
+        }
+    }
+
+    // 🔄 field ที่แก้ไขได้ของ item ในรูป item ใหม่ (ใช้ตรวจกับกติกาของประเภทเหมือนตอนเพิ่ม)
+    pub fn to_staff_adding(&self) -> StaffAdding {
+        StaffAdding {
+            name: self.name.clone(),
+            category: self.category.clone(),
+            owner_id: self.owner_id,
+            weight: self.weight,
+            rarity: self.rarity.clone(),
+            required_level: self.required_level,
+            stats: self.stats.clone(),
+            attributes: self.attributes.clone(),
+        }
     }
 }
 
@@ -61,12 +189,101 @@ impl Item {
 pub struct StaffAdding {
     pub name: String,      // 📝 ชื่อของ Staff ที่จะเพิ่ม
     pub category: Category, // 🏷️ ประเภทของ Item
+    #[serde(default)]
+    pub owner_id: Option<i32>, // 👤 ผู้เล่นที่เป็นเจ้าของ (/items/staff รับเฉพาะ null = item กลางของระบบ)
+    #[serde(default)]
+    pub weight: i32,       // ⚖️ น้ำหนัก (ไม่ส่งมา = 0)
+    #[serde(default)]
+    pub rarity: Rarity,    // 💎 ความหายาก (ไม่ส่งมา = Common)
+    #[serde(default = "default_required_level")]
+    pub required_level: i32, // 🎚️ เลเวลขั้นต่ำ (ไม่ส่งมา = 1)
+    #[serde(default)]
+    pub stats: ItemStats,  // 📊 ค่าสถานะตามประเภท
+    #[serde(default)]
+    pub attributes: ItemAttributes, // 🧩 attributes ตาม schema ของประเภท (ไม่ส่งมา = {})
+}
+
+// 🔤 ชื่อที่บันทึก: ตัดช่องว่างหัวท้าย / NFC (สระและวรรณยุกต์ไทยที่พิมพ์ต่างลำดับเป็นรูปเดียวกัน) / ช่องว่างหลายตัวเหลือตัวเดียว
+pub fn name_normalizing(name: &str) -> String {
+    name.nfc().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// 🔑 ชื่อที่ใช้ตรวจชื่อซ้ำ (ไม่สนตัวพิมพ์ ทุกภาษา) - repository เก็บค่านี้ลงคอลัมน์ name_key ที่มี unique index
+//    คำนวณในแอปเพราะ lower() ของฐานข้อมูลขึ้นกับ locale (locale C แปลงได้แค่ ASCII)
+pub fn name_key(name: &str) -> String {
+    lowercasing(&name_normalizing(name))
+}
+
+// 🔡 ตัวเล็กทีละตัวอักษร (char::to_lowercase) - กติกาเดียวของ name_key / คำค้น / ไฮไลต์ของการค้นหา
+//    ทีละตัวเพื่อให้ไฮไลต์ย้อนตำแหน่งกลับไปที่ชื่อเดิมได้ (str::to_lowercase เปลี่ยน Σ ท้ายคำต่างออกไป)
+pub fn lowercasing(text: &str) -> String {
+    text.chars().flat_map(char::to_lowercase).collect()
+}
+
+// 🎚️ เลเวลเริ่มต้น (ตรงกับ DEFAULT ของคอลัมน์ items.required_level)
+fn default_required_level() -> i32 {
+    1
 }
 
 impl StaffAdding {
     // 🔄 แปลง StaffAdding Model เป็น Domain Entity
     // ✅ ใช้ to_entity() method เพื่อให้เป็นไปตาม Client → Model → Entity → Database flow
     pub fn to_entity(&self, t: IntoTimerHelperShared) -> ItemsEntity {
-        ItemsEntity::new(self.name.to_string(), self.category.to_string(), t)
+        ItemsEntity {
+            owner_id: self.owner_id,
+            weight: self.weight,
+            rarity: self.rarity.to_string(),
+            required_level: self.required_level,
+            stats: Json(self.stats.clone()),
+            attributes: Json(self.attributes.clone()),
+            ..ItemsEntity::new(name_normalizing(&self.name), self.category.to_string(), t)
+        }
+    }
+}
+
+// 📥 StaffUpserting Model: body ของ PUT /items/staff = StaffAdding + version ที่แทนที่จาก
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct StaffUpserting {
+    #[serde(flatten)]
+    pub item: StaffAdding,          // 📦 ค่าเดียวกับ POST /items/staff
+    #[serde(default)]
+    pub version: Option<i32>,       // 🔢 version ที่แทนที่จาก (ไม่ส่ง = ไม่ตรวจ / ไม่มีผลเมื่อเพิ่มใหม่)
+}
+
+// 📥 AttributesUpdating Model: แทนที่ attributes ของ item ทั้งก้อน (ตรวจกับ schema เวอร์ชันล่าสุด)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct AttributesUpdating {
+    pub attributes: ItemAttributes, // 🧩 attributes ใหม่
+    #[serde(default)]
+    pub version: Option<i32>,       // 🔢 version ที่แก้ไขจาก (ไม่ส่ง = ไม่ตรวจ)
+}
+
+// 🔎 ItemsQuery Model: query string ของ GET /items (ไม่ส่ง = ไม่กรอง)
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ItemsQuery {
+    pub rarity: Option<Rarity>,     // 💎 ความหายาก
+    pub min_level: Option<i32>,     // 🎚️ required_level ขั้นต่ำ
+    pub max_level: Option<i32>,     // 🎚️ required_level สูงสุด
+    pub category: Option<String>,   // 🌳 ประเภท (รวมประเภทลูกหลาน เช่น Weapon = Sword + Staff)
+    pub definition_id: Option<i32>, // 📜 เฉพาะ instance ของแบบนี้
+    #[serde(default)]
+    pub include_deleted: bool,      // 🗑️ รวม item ที่ถูกลบแล้ว (มีผลเฉพาะ /admin/items)
+    pub filter: Option<String>,     // 🧮 เงื่อนไขแบบ RSQL เช่น category==Staff;rarity=in=(Epic,Legendary) (models::filter)
+}
+
+impl ItemsQuery {
+    // 🔄 แปลง query string เป็นเงื่อนไขของ Repository
+    // 🌳 categories คือประเภทและลูกหลาน - use case เติมให้จาก catalog / expression คือ ?filter= ที่ parse แล้ว
+    pub fn to_filter(&self, categories: Option<Vec<String>>, expression: Option<ItemsExpression>) -> ItemsFilter {
+        ItemsFilter {
+            rarity: self.rarity.as_ref().map(|rarity| rarity.to_string()),
+            min_level: self.min_level,
+            max_level: self.max_level,
+            categories,
+            definition_id: self.definition_id,
+            include_deleted: self.include_deleted,
+            expression,
+            ..ItemsFilter::default()
+        }
     }
 }
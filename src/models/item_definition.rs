@@ -0,0 +1,91 @@
+// === 📋 API Models: Item Definitions ===
+// 📖 Data Transfer Objects ของแบบ item (designer) และการสร้าง instance จากแบบ
+
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+
+use crate::{
+    entities::item_definitions::ItemDefinitions as ItemDefinitionsEntity,
+    models::item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding},
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📜 ItemDefinition Model: แบบของ item หนึ่งรายการ
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ItemDefinition {
+    pub id: i32,                     // 🆔 Primary key จากฐานข้อมูล
+    pub name: String,                // 📝 ชื่อแบบ
+    pub category: Category,          // 🏷️ ประเภท
+    pub weight: i32,                 // ⚖️ น้ำหนัก
+    pub rarity: Rarity,              // 💎 ความหายาก
+    pub required_level: i32,         // 🎚️ เลเวลขั้นต่ำ
+    pub base_stats: ItemStats,       // 📊 ค่าสถานะตั้งต้น
+    pub stat_variance: i32,          // 🎲 ช่วงสุ่ม ± เปอร์เซ็นต์
+    pub max_durability: Option<i32>, // 🔧 ความทนทานของ instance ใหม่
+    pub icon: Option<String>,        // 🖼️ ไอคอน
+}
+
+// 📥 ItemDefinitionAdding Model: ข้อมูลสำหรับเพิ่มหรือแก้แบบ (PUT แทนที่ทั้งก้อน)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ItemDefinitionAdding {
+    pub name: String,                // 📝 ชื่อแบบ
+    pub category: Category,          // 🏷️ ประเภท
+    #[serde(default)]
+    pub weight: i32,                 // ⚖️ น้ำหนัก (ไม่ส่งมา = 0)
+    #[serde(default)]
+    pub rarity: Rarity,              // 💎 ความหายาก (ไม่ส่งมา = Common)
+    #[serde(default = "default_required_level")]
+    pub required_level: i32,         // 🎚️ เลเวลขั้นต่ำ (ไม่ส่งมา = 1)
+    #[serde(default)]
+    pub base_stats: ItemStats,       // 📊 ค่าสถานะตั้งต้น
+    #[serde(default)]
+    pub stat_variance: i32,          // 🎲 ช่วงสุ่ม ± เปอร์เซ็นต์ (ไม่ส่งมา = 0)
+    #[serde(default)]
+    pub max_durability: Option<i32>, // 🔧 ความทนทาน (ไม่ส่งมา = ไม่สึกหรอ)
+    #[serde(default)]
+    pub icon: Option<String>,        // 🖼️ ไอคอน
+}
+
+// 🎚️ เลเวลเริ่มต้น (ตรงกับ DEFAULT ของคอลัมน์ item_definitions.required_level)
+fn default_required_level() -> i32 {
+    1
+}
+
+impl ItemDefinitionAdding {
+    // 🔄 แปลง Model เป็น Domain Entity
+    pub fn to_entity(&self, t: IntoTimerHelperShared) -> ItemDefinitionsEntity {
+        ItemDefinitionsEntity {
+            weight: self.weight,
+            rarity: self.rarity.to_string(),
+            required_level: self.required_level,
+            base_stats: Json(self.base_stats.clone()),
+            stat_variance: self.stat_variance,
+            max_durability: self.max_durability,
+            icon: self.icon.clone(),
+            ..ItemDefinitionsEntity::new(self.name.trim().to_string(), self.category.to_string(), t)
+        }
+    }
+
+    // 🔄 ค่าของแบบในรูป item ใหม่ (ใช้ตรวจกับกติกาของประเภทเหมือน item ทั่วไป)
+    pub fn to_staff_adding(&self) -> StaffAdding {
+        StaffAdding {
+            name: self.name.trim().to_string(),
+            category: self.category.clone(),
+            owner_id: None,
+            weight: self.weight,
+            rarity: self.rarity.clone(),
+            required_level: self.required_level,
+            stats: self.base_stats.clone(),
+            attributes: ItemAttributes::new(),
+        }
+    }
+}
+
+// 📥 InstanceSpawning Model: สร้าง instance จากแบบ
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct InstanceSpawning {
+    #[serde(default)]
+    pub owner_id: Option<i32>,       // 👤 ใส่กระเป๋าผู้เล่นคนนี้ (ตรวจ limit) / ไม่ส่งมา = item กลางของระบบ
+    #[serde(default)]
+    pub attributes: ItemAttributes,  // 🧩 attributes ของชิ้นนี้ (ตรวจกับ schema ของประเภท)
+}
@@ -1,2 +1,14 @@
+pub mod audit;
+pub mod batch;
+pub mod category;
 pub mod error;
-pub mod item;
\ No newline at end of file
+pub mod etag;
+pub mod export;
+pub mod filter;
+pub mod import;
+pub mod inventory;
+pub mod item;
+pub mod item_definition;
+pub mod patch;
+pub mod player;
+pub mod search;
\ No newline at end of file
@@ -0,0 +1,205 @@
+// === 🩹 Patch Models ===
+// 📖 แก้ไข item บางส่วนด้วย JSON Merge Patch (RFC 7396) หรือ JSON Patch (RFC 6902) ตาม Content-Type
+// 🎯 SOLID: Single Responsibility - เฉพาะการอ่าน patch และใช้กับรูป JSON ของ Item การตรวจ/บันทึกอยู่ใน StaffUsecase
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::{error::APIError, item::Item};
+
+// 🔒 field ของ Item ที่ patch ห้ามแก้ (ระบบเป็นผู้กำหนด)
+// 🕒 created_at / updated_at ไม่อยู่ในรูป JSON ของ Item - ใส่ไว้เพื่อให้ patch ที่แตะ field นี้ได้ 422 แทนการถูกเมินเงียบ ๆ
+const READ_ONLY_FIELDS: [&str; 9] = [
+    "id",
+    "owner_id",
+    "version",
+    "attributes_schema_version",
+    "definition_id",
+    "durability",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
+// ✏️ PatchOperation: operation หนึ่งรายการของ JSON Patch ({"op": "add", "path": "/name", "value": ...})
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },     // ➕ เพิ่ม / แทนที่ค่า (array: แทรกที่ index หรือ "-" = ท้าย)
+    Remove { path: String },                // 🗑️ ลบค่า (ต้องมีอยู่)
+    Replace { path: String, value: Value }, // 🔄 แทนที่ค่า (ต้องมีอยู่)
+    Move { from: String, path: String },    // 🔀 ย้ายค่าจาก from ไป path
+    Copy { from: String, path: String },    // 📋 คัดลอกค่าจาก from ไป path
+    Test { path: String, value: Value },    // ✅ ค่าที่ path ต้องเท่ากับ value ไม่เช่นนั้นยกเลิกทั้ง patch
+}
+
+// 🩹 ItemPatch: patch ที่อ่านจาก body แล้ว
+#[derive(Clone, PartialEq, Debug)]
+pub enum ItemPatch {
+    Merge(Value),              // 📄 application/merge-patch+json - null = ลบ field / object = merge ลงไป
+    Json(Vec<PatchOperation>), // 📄 application/json-patch+json - operation ทำตามลำดับ
+}
+
+impl ItemPatch {
+    // 🔍 เลือกรูปแบบจาก Content-Type (ไม่สน parameter เช่น charset) - ไม่รองรับได้ 415 / อ่าน body ไม่ได้ได้ 400
+    pub fn from_request(content_type: Option<&str>, body: &[u8]) -> Result<Self, APIError> {
+        let mime = content_type
+            .and_then(|v| v.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let malformed = |e: serde_json::Error| APIError::InvalidRequestBody(StatusCode::BAD_REQUEST, e.to_string());
+
+        match mime.as_str() {
+            "application/merge-patch+json" => serde_json::from_slice(body).map(Self::Merge).map_err(malformed),
+            "application/json-patch+json" => serde_json::from_slice(body).map(Self::Json).map_err(malformed),
+            _ => Err(APIError::UnsupportedMediaType(mime)),
+        }
+    }
+
+    // 🔄 ใช้ patch กับรูป JSON ของ item แล้วแปลงกลับเป็น Item
+    // ❌ path ไม่มีอยู่ / แก้ field อ่านอย่างเดียว / ผลไม่ใช่ Item ที่ถูกต้อง -> InvalidPatch, test ไม่ผ่าน -> PatchTestFailed
+    pub fn applying(&self, item: &Item) -> Result<Item, APIError> {
+        let before = match serde_json::to_value(item) {
+            Ok(value) => value,
+            Err(e) => return Err(APIError::InvalidPatch(e.to_string())),
+        };
+        let mut after = before.clone();
+        match self {
+            Self::Merge(patch) => merging(&mut after, patch),
+            Self::Json(operations) => {
+                for operation in operations {
+                    operating(&mut after, operation)?;
+                }
+            }
+        }
+
+        // 👤 ย้ายเจ้าของต้องตรวจกระเป๋าผู้รับและบันทึกเป็นการโอน
+        // 🕳️ key ที่ไม่มีเท่ากับ null - merge patch {"owner_id": null} บน item ที่ไม่มีเจ้าของลบ key ออกแต่ค่าไม่เปลี่ยน
+        let field_value = |document: &Value, field: &str| document.get(field).cloned().unwrap_or(Value::Null);
+        match READ_ONLY_FIELDS.iter().find(|field| field_value(&before, field) != field_value(&after, field)) {
+            Some(&"owner_id") => {
+                return Err(APIError::InvalidPatch("/owner_id is read-only, use POST /items/{id}/transfer".to_string()));
+            }
+            Some(field) => return Err(APIError::InvalidPatch(format!("/{} is read-only", field))),
+            None => (),
+        }
+        serde_json::from_value(after).map_err(|e| APIError::InvalidPatch(format!("patched item is invalid: {}", e)))
+    }
+}
+
+// 📄 RFC 7396: object ของ patch merge ลงไปทีละ key / null = ลบ key / ค่าอื่นแทนที่ทั้งก้อน
+fn merging(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merging(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+// 📄 RFC 6902: ทำ operation หนึ่งรายการ
+fn operating(document: &mut Value, operation: &PatchOperation) -> Result<(), APIError> {
+    match operation {
+        PatchOperation::Add { path, value } => adding(document, path, value.clone()),
+        PatchOperation::Remove { path } => removing(document, path).map(|_| ()),
+        PatchOperation::Replace { path, value } => match document.pointer_mut(path) {
+            Some(target) => {
+                *target = value.clone();
+                Ok(())
+            }
+            None => Err(missing_path(path)),
+        },
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{}/", from)) {
+                return Err(APIError::InvalidPatch(format!("cannot move {} into its own child {}", from, path)));
+            }
+            let value = removing(document, from)?;
+            adding(document, path, value)
+        }
+        PatchOperation::Copy { from, path } => match document.pointer(from) {
+            Some(value) => adding(document, path, value.clone()),
+            None => Err(missing_path(from)),
+        },
+        PatchOperation::Test { path, value } => match document.pointer(path) {
+            Some(current) if current == value => Ok(()),
+            Some(_) => Err(APIError::PatchTestFailed(path.clone())),
+            None => Err(missing_path(path)),
+        },
+    }
+}
+
+// ➕ เพิ่มค่าที่ path: object = ใส่ key / array = แทรกที่ index ("-" = ท้าย array)
+fn adding(document: &mut Value, path: &str, value: Value) -> Result<(), APIError> {
+    let Some((parent, key)) = splitting(path)? else {
+        *document = value;
+        return Ok(());
+    };
+    match document.pointer_mut(parent) {
+        Some(Value::Object(object)) => {
+            object.insert(key, value);
+            Ok(())
+        }
+        Some(Value::Array(array)) => {
+            let index = match key.as_str() {
+                "-" => array.len(),
+                _ => array_index(&key).filter(|index| *index <= array.len()).ok_or_else(|| missing_path(path))?,
+            };
+            array.insert(index, value);
+            Ok(())
+        }
+        _ => Err(missing_path(path)),
+    }
+}
+
+// 🗑️ ลบค่าที่ path แล้วส่งค่าที่ลบกลับ (ใช้ต่อใน move)
+fn removing(document: &mut Value, path: &str) -> Result<Value, APIError> {
+    let Some((parent, key)) = splitting(path)? else {
+        return Err(APIError::InvalidPatch("cannot remove the whole item".to_string()));
+    };
+    let removed = match document.pointer_mut(parent) {
+        Some(Value::Object(object)) => object.remove(&key),
+        Some(Value::Array(array)) => array_index(&key)
+            .filter(|index| *index < array.len())
+            .map(|index| array.remove(index)),
+        _ => None,
+    };
+    removed.ok_or_else(|| missing_path(path))
+}
+
+// 🔍 แยก JSON Pointer เป็น (pointer ของ parent, key ที่ถอด escape แล้ว) - "" (ทั้งเอกสาร) ได้ None
+fn splitting(path: &str) -> Result<Option<(&str, String)>, APIError> {
+    if path.is_empty() {
+        return Ok(None);
+    }
+    match path.rfind('/') {
+        Some(index) if path.starts_with('/') => {
+            let key = path[index + 1..].replace("~1", "/").replace("~0", "~");
+            Ok(Some((&path[..index], key)))
+        }
+        _ => Err(APIError::InvalidPatch(format!("path {} must start with /", path))),
+    }
+}
+
+// 🔢 index ของ array ใน JSON Pointer: ตัวเลขล้วน ไม่มี 0 นำหน้า
+fn array_index(key: &str) -> Option<usize> {
+    if key.is_empty() || !key.bytes().all(|b| b.is_ascii_digit()) || (key.len() > 1 && key.starts_with('0')) {
+        return None;
+    }
+    key.parse().ok()
+}
+
+fn missing_path(path: &str) -> APIError {
+    APIError::InvalidPatch(format!("path {} does not exist", path))
+}
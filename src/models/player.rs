@@ -0,0 +1,33 @@
+// === 📋 API Models: Player ===
+// 📖 Data Transfer Objects (DTOs) ของผู้เล่น
+
+use crate::{entities::players::Players as PlayersEntity, time_helper::IntoTimerHelperShared};
+use serde::{Deserialize, Serialize};
+
+// 🎮 Player Model: API response model ของผู้เล่น
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Player {
+    pub id: i32,           // 🆔 Primary key จากฐานข้อมูล
+    pub name: String,      // 📝 ชื่อผู้เล่น
+    pub tier: String,      // 🏅 ระดับผู้เล่น
+}
+
+// 📥 PlayerRegistering Model: ข้อมูลสำหรับสร้างผู้เล่นใหม่
+// 🔒 ไม่รับ tier จาก client - ผู้เล่นใหม่ได้ inventory.default_tier เสมอ (เปลี่ยนได้ทาง PUT /admin/players/{id}/tier)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlayerRegistering {
+    pub name: String,      // 📝 ชื่อผู้เล่น
+}
+
+impl PlayerRegistering {
+    // 🔄 แปลง Model เป็น Domain Entity พร้อม tier ที่ server กำหนด
+    pub fn to_entity(&self, tier: String, t: IntoTimerHelperShared) -> PlayersEntity {
+        PlayersEntity::new(self.name.to_string(), tier, t)
+    }
+}
+
+// 🏅 PlayerTierUpdating Model: เปลี่ยน tier ของผู้เล่น (admin)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlayerTierUpdating {
+    pub tier: String,      // 🏅 ต้องเป็น tier ที่มีใน inventory.tiers
+}
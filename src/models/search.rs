@@ -0,0 +1,158 @@
+// === 🔎 Search Models ===
+// 📖 ค้นหาชื่อ item (GET /items/search): query string / ผลลัพธ์ตามคะแนน / ไฮไลต์ส่วนที่ตรงกับคำค้น
+// 🎯 SOLID: Single Responsibility - เฉพาะรูปแบบของผลลัพธ์และการไฮไลต์ การค้นอยู่ใน ItemsRepository::search
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::item::Item;
+
+// 🔤 zero-width space (ใช้แบ่งคำในข้อความภาษาไทย) / นิคหิต / สระอา / สระอำ
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+const NIKHAHIT: char = '\u{0E4D}';
+const SARA_AA: char = '\u{0E32}';
+const SARA_AM: char = '\u{0E33}';
+
+// 📏 ส่วนที่ตรงกันสั้นกว่านี้ไม่ไฮไลต์ (ชื่อที่สะกดผิด)
+const MIN_FUZZY_HIGHLIGHT_CHARS: usize = 2;
+
+// 🔎 ItemSearchQuery: query string ของ GET /items/search
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ItemSearchQuery {
+    #[serde(default)]
+    pub q: String,                  // 🔤 คำค้น (บางส่วนของชื่อ / สะกดผิดได้ / ภาษาไทยได้)
+    pub category: Option<String>,   // 🌳 ประเภท (รวมประเภทลูกหลาน)
+    pub limit: Option<usize>,       // 🔢 จำนวนผลลัพธ์ (ไม่ส่ง = search.default_limit)
+}
+
+// 🏆 ItemSearchHit: item ที่ค้นเจอหนึ่งชิ้น
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ItemSearchHit {
+    pub score: f32,                 // 🏆 คะแนนความเกี่ยวข้อง (มากก่อน)
+    pub highlight: String,          // 🖍️ ชื่อที่ครอบส่วนที่ตรงด้วย <mark></mark> (escape HTML แล้ว)
+    pub item: Item,                 // 📦 item
+}
+
+// 📊 ItemSearchResult: ผลการค้นหาทั้งหมด
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ItemSearchResult {
+    pub query: String,              // 🔤 คำค้นที่ใช้ (ตัดช่องว่างหัวท้ายแล้ว)
+    pub hits: Vec<ItemSearchHit>,   // 🏆 เรียงตามคะแนน
+}
+
+// 🖍️ ครอบส่วนของชื่อที่ตรงกับคำค้นด้วย <mark></mark>
+// 🇹🇭 ภาษาไทยไม่เว้นวรรค จึงหาคำค้นทั้งก้อนเป็น substring (และแต่ละคำเมื่อมีช่องว่าง)
+//    ไม่เจอเลย (สะกดผิด) = ไฮไลต์ส่วนที่ยาวที่สุดที่ตรงกับคำค้น
+pub fn highlighting(name: &str, query: &str) -> String {
+    let original: Vec<char> = name.chars().collect();
+    let normalized = normalizing(name);
+    let haystack: Vec<char> = normalized.iter().map(|(c, _, _)| *c).collect();
+
+    let mut terms: Vec<Vec<char>> = vec![normalizing(query).into_iter().map(|(c, _, _)| c).collect()];
+    for word in query.split_whitespace() {
+        let word: Vec<char> = normalizing(word).into_iter().map(|(c, _, _)| c).collect();
+        if !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms.retain(|term| !term.is_empty());
+
+    // 📍 ส่วนที่ตรง (ตำแหน่งใน haystack) -> ตำแหน่งตัวอักษรในชื่อเดิม
+    let mut matched: Vec<(usize, usize)> = terms.iter().flat_map(|term| occurrences(&haystack, term)).collect();
+    if matched.is_empty() {
+        matched = terms
+            .iter()
+            .filter_map(|term| longest_common(&haystack, term))
+            .filter(|(start, end)| end - start >= MIN_FUZZY_HIGHLIGHT_CHARS)
+            .max_by_key(|(start, end)| end - start)
+            .into_iter()
+            .collect();
+    }
+    let mut marked = vec![false; original.len()];
+    // 🔗 ครอบทั้งช่วงในชื่อเดิม รวม zero-width space ที่ถูกตัดออกตอน normalize
+    for (start, end) in matched {
+        let (from, to) = (normalized[start].1, normalized[end - 1].2);
+        marked[from..to].iter_mut().for_each(|mark| *mark = true);
+    }
+
+    let mut highlight = String::with_capacity(name.len() + 16);
+    for (index, c) in original.iter().enumerate() {
+        if marked[index] && (index == 0 || !marked[index - 1]) {
+            highlight.push_str("<mark>");
+        }
+        match c {
+            '&' => highlight.push_str("&amp;"),
+            '<' => highlight.push_str("&lt;"),
+            '>' => highlight.push_str("&gt;"),
+            '"' => highlight.push_str("&quot;"),
+            _ => highlight.push(*c),
+        }
+        if marked[index] && (index + 1 == original.len() || !marked[index + 1]) {
+            highlight.push_str("</mark>");
+        }
+    }
+    highlight
+}
+
+// 🔤 normalize เหมือนการค้นในฐานข้อมูล: ตัวเล็กแบบ lowercasing (name_key / คำค้น) แล้ว item_search_text - ตัด zero-width space / นิคหิต (+ วรรณยุกต์) + สระอา = สระอำ
+// 📍 ทุกตัวอักษรจำตำแหน่ง [from, to) ในข้อความเดิมไว้ไฮไลต์กลับ
+fn normalizing(text: &str) -> Vec<(char, usize, usize)> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    let offset = text.chars().take_while(|c| c.is_whitespace()).count();
+    let mut normalized = Vec::with_capacity(chars.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c == ZERO_WIDTH_SPACE {
+            index += 1;
+            continue;
+        }
+        if c == NIKHAHIT {
+            let tone = chars.get(index + 1).copied().filter(|tone| ('\u{0E48}'..='\u{0E4B}').contains(tone));
+            let vowel = index + 1 + usize::from(tone.is_some());
+            if chars.get(vowel) == Some(&SARA_AA) {
+                if let Some(tone) = tone {
+                    normalized.push((tone, offset + index + 1, offset + index + 2));
+                }
+                normalized.push((SARA_AM, offset + index, offset + vowel + 1));
+                index = vowel + 1;
+                continue;
+            }
+        }
+        for lower in c.to_lowercase() { // 🔡 ทีละตัวเหมือน models::item::lowercasing
+            normalized.push((lower, offset + index, offset + index + 1));
+        }
+        index += 1;
+    }
+    normalized
+}
+
+// 📍 ทุกตำแหน่งที่ term อยู่ใน haystack (ไม่ซ้อนกัน)
+fn occurrences(haystack: &[char], term: &[char]) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut start = 0;
+    while start + term.len() <= haystack.len() {
+        if haystack[start..start + term.len()] == *term {
+            found.push((start, start + term.len()));
+            start += term.len();
+        } else {
+            start += 1;
+        }
+    }
+    found
+}
+
+// 📏 ส่วนที่ยาวที่สุดของ haystack ที่อยู่ใน term ด้วย (ตำแหน่งใน haystack)
+fn longest_common(haystack: &[char], term: &[char]) -> Option<(usize, usize)> {
+    let mut lengths = vec![0usize; term.len() + 1];
+    let mut best: Option<(usize, usize)> = None;
+    for (i, h) in haystack.iter().enumerate() {
+        for j in (0..term.len()).rev() {
+            lengths[j + 1] = if *h == term[j] { lengths[j] + 1 } else { 0 };
+            let length = lengths[j + 1];
+            if length > best.map_or(0, |(start, end)| end - start) {
+                best = Some((i + 1 - length, i + 1));
+            }
+        }
+    }
+    best
+}
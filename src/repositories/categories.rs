@@ -0,0 +1,48 @@
+// === 📋 Repository Interface: Categories ===
+// 📖 กำหนด Interface สำหรับ catalog ประเภท item และสร้าง Mock สำหรับการทดสอบ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 4️⃣ Interface Segregation Principle (ISP):
+//    แยก interface ของ catalog ออกจาก ItemsRepository
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    Use case ขึ้นอยู่กับ trait นี้แทน concrete implementation
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::entities::{categories::Categories, category_schemas::CategorySchemas};
+
+// 🔗 Type alias สำหรับ shared Repository
+pub type SharedCategoriesRepository = Arc<dyn CategoriesRepository + Send + Sync>;
+
+#[async_trait]
+#[automock]
+pub trait CategoriesRepository {
+    // 📋 ดึงประเภททั้งหมด เรียงตามชื่อ
+    async fn find_all(&self) -> Result<Vec<Categories>, sqlx::Error>;
+
+    // 🔍 ค้นหาประเภทตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<Categories, sqlx::Error>;
+
+    // ➕ เพิ่มประเภทใหม่ ส่งกลับข้อมูลที่เพิ่ม
+    async fn insert(&self, category: Categories) -> Result<Categories, sqlx::Error>;
+
+    // ✏️ แก้ชื่อ/ประเภทแม่/กติกา ตาม id ใน entity ส่งกลับข้อมูลหลังแก้ไข
+    async fn update(&self, category: Categories) -> Result<Categories, sqlx::Error>;
+
+    // 🗑️ ลบประเภท (ไม่พบ -> RowNotFound)
+    async fn delete(&self, id: i32) -> Result<(), sqlx::Error>;
+
+    // 🧾 schema ทุกเวอร์ชันของประเภท เรียงตามเวอร์ชัน
+    async fn find_schemas(&self, category_id: i32) -> Result<Vec<CategorySchemas>, sqlx::Error>;
+
+    // 🧾 schema เวอร์ชันล่าสุดของทุกประเภทที่มี schema
+    async fn find_latest_schemas(&self) -> Result<Vec<CategorySchemas>, sqlx::Error>;
+
+    // ➕ เพิ่ม schema เป็นเวอร์ชันถัดไปของประเภท ส่งกลับข้อมูลที่เพิ่ม
+    async fn insert_schema(&self, schema: CategorySchemas) -> Result<CategorySchemas, sqlx::Error>;
+}
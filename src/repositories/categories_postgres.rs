@@ -0,0 +1,176 @@
+// === 💾 Infrastructure Layer: Categories Repository (PostgreSQL) ===
+// 📖 จัดการ catalog ประเภท item ในฐานข้อมูล - เป็น concrete implementation ของ CategoriesRepository
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 3️⃣ Liskov Substitution Principle (LSP):
+//    CategoriesPostgresRepository ใช้แทน CategoriesRepository interface ได้อย่างสมบูรณ์
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ abstraction (CategoriesRepository trait) ไม่ใช่ concrete classes
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::entities::{categories::Categories, category_schemas::CategorySchemas};
+
+use super::categories::{CategoriesRepository, SharedCategoriesRepository};
+
+// 📦 CategoriesPostgresRepository struct: จัดการ catalog ในฐานข้อมูล
+pub struct CategoriesPostgresRepository {
+    db_pool: PgPool,                  // 🗄️ Database connection pool สำหรับ PostgreSQL
+}
+
+impl CategoriesPostgresRepository {
+    // 🏗️ Factory method: สร้าง Repository และ wrap ด้วย Arc สำหรับ shared ownership
+    pub fn creation(db_pool: PgPool) -> SharedCategoriesRepository {
+        Arc::new(Self { db_pool })
+    }
+}
+
+#[async_trait]
+impl CategoriesRepository for CategoriesPostgresRepository {
+    // 📋 ดึงประเภททั้งหมด
+    async fn find_all(&self) -> Result<Vec<Categories>, sqlx::Error> {
+        match sqlx::query_as::<_, Categories>("SELECT * FROM categories ORDER BY name;")
+            .fetch_all(&self.db_pool)
+            .await
+        {
+            Ok(categories) => Ok(categories),
+            Err(e) => {
+                error!("Failed to find all categories: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🔍 ค้นหาประเภทตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<Categories, sqlx::Error> {
+        match sqlx::query_as::<_, Categories>("SELECT * FROM categories WHERE id = $1;")
+            .bind(id)
+            .fetch_one(&self.db_pool)
+            .await
+        {
+            Ok(category) => Ok(category),
+            Err(e) => {
+                error!("Failed to find category by id: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ➕ เพิ่มประเภทใหม่ และส่งกลับแถวที่เพิ่ม
+    async fn insert(&self, category: Categories) -> Result<Categories, sqlx::Error> {
+        match sqlx::query_as::<_, Categories>(
+            "INSERT INTO categories (name, parent_id, rules, created_at, updated_at) VALUES ($1, $2, $3, $4, $5) RETURNING *;",
+        )
+        .bind(category.name)
+        .bind(category.parent_id)
+        .bind(category.rules)
+        .bind(category.created_at)
+        .bind(category.updated_at)
+        .fetch_one(&self.db_pool)
+        .await
+        {
+            Ok(category) => Ok(category),
+            Err(e) => {
+                error!("Failed to insert category: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ✏️ แก้ประเภท - ถ้าเปลี่ยนชื่อ items.category เปลี่ยนตามด้วย ON UPDATE CASCADE
+    async fn update(&self, category: Categories) -> Result<Categories, sqlx::Error> {
+        match sqlx::query_as::<_, Categories>(
+            "UPDATE categories SET name = $2, parent_id = $3, rules = $4, updated_at = $5 WHERE id = $1 RETURNING *;",
+        )
+        .bind(category.id)
+        .bind(category.name)
+        .bind(category.parent_id)
+        .bind(category.rules)
+        .bind(category.updated_at)
+        .fetch_one(&self.db_pool)
+        .await
+        {
+            Ok(category) => Ok(category),
+            Err(e) => {
+                error!("Failed to update category: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🗑️ ลบประเภท - ถ้ายังมี item หรือประเภทลูกใช้อยู่ฐานข้อมูลจะตอบ 23503
+    async fn delete(&self, id: i32) -> Result<(), sqlx::Error> {
+        match sqlx::query("DELETE FROM categories WHERE id = $1;")
+            .bind(id)
+            .execute(&self.db_pool)
+            .await
+        {
+            Ok(result) if result.rows_affected() == 0 => Err(sqlx::Error::RowNotFound),
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("Failed to delete category: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🧾 schema ทุกเวอร์ชันของประเภท
+    async fn find_schemas(&self, category_id: i32) -> Result<Vec<CategorySchemas>, sqlx::Error> {
+        match sqlx::query_as::<_, CategorySchemas>(
+            "SELECT * FROM category_schemas WHERE category_id = $1 ORDER BY version;",
+        )
+        .bind(category_id)
+        .fetch_all(&self.db_pool)
+        .await
+        {
+            Ok(schemas) => Ok(schemas),
+            Err(e) => {
+                error!("Failed to find category schemas: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🧾 schema เวอร์ชันล่าสุดของแต่ละประเภท
+    async fn find_latest_schemas(&self) -> Result<Vec<CategorySchemas>, sqlx::Error> {
+        match sqlx::query_as::<_, CategorySchemas>(
+            "SELECT DISTINCT ON (category_id) * FROM category_schemas ORDER BY category_id, version DESC;",
+        )
+        .fetch_all(&self.db_pool)
+        .await
+        {
+            Ok(schemas) => Ok(schemas),
+            Err(e) => {
+                error!("Failed to find latest category schemas: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ➕ เพิ่ม schema เวอร์ชันถัดไป - เพิ่มพร้อมกันสองรายการ อีกรายการได้ 23505 จาก primary key
+    async fn insert_schema(&self, schema: CategorySchemas) -> Result<CategorySchemas, sqlx::Error> {
+        match sqlx::query_as::<_, CategorySchemas>(
+            "INSERT INTO category_schemas (category_id, version, schema, created_at) \
+             SELECT $1, COALESCE(MAX(version), 0) + 1, $2, $3 FROM category_schemas WHERE category_id = $1 \
+             RETURNING *;",
+        )
+        .bind(schema.category_id)
+        .bind(schema.schema)
+        .bind(schema.created_at)
+        .fetch_one(&self.db_pool)
+        .await
+        {
+            Ok(schema) => Ok(schema),
+            Err(e) => {
+                error!("Failed to insert category schema: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+}
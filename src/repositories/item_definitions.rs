@@ -0,0 +1,36 @@
+// === 📋 Repository Interface: Item Definitions ===
+// 📖 กำหนด Interface สำหรับแบบของ item และสร้าง Mock สำหรับการทดสอบ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 4️⃣ Interface Segregation Principle (ISP):
+//    แยก interface ของแบบออกจาก ItemsRepository (instance)
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    Use case ขึ้นอยู่กับ trait นี้แทน concrete implementation
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::entities::item_definitions::ItemDefinitions;
+
+// 🔗 Type alias สำหรับ shared Repository
+pub type SharedItemDefinitionsRepository = Arc<dyn ItemDefinitionsRepository + Send + Sync>;
+
+#[async_trait]
+#[automock]
+pub trait ItemDefinitionsRepository {
+    // 📋 ดึงแบบทั้งหมด เรียงตาม ID
+    async fn find_all(&self) -> Result<Vec<ItemDefinitions>, sqlx::Error>;
+
+    // 🔍 ค้นหาแบบตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<ItemDefinitions, sqlx::Error>;
+
+    // ➕ เพิ่มแบบใหม่ ส่งกลับข้อมูลที่เพิ่ม
+    async fn insert(&self, definition: ItemDefinitions) -> Result<ItemDefinitions, sqlx::Error>;
+
+    // ✏️ แก้แบบตาม id ใน entity ส่งกลับข้อมูลหลังแก้ไข (instance ที่มีอยู่ไม่เปลี่ยน)
+    async fn update(&self, definition: ItemDefinitions) -> Result<ItemDefinitions, sqlx::Error>;
+}
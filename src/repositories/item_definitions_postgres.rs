@@ -0,0 +1,122 @@
+// === 💾 Infrastructure Layer: Item Definitions Repository (PostgreSQL) ===
+// 📖 จัดการแบบของ item ในฐานข้อมูล - เป็น concrete implementation ของ ItemDefinitionsRepository
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 3️⃣ Liskov Substitution Principle (LSP):
+//    ItemDefinitionsPostgresRepository ใช้แทน ItemDefinitionsRepository interface ได้อย่างสมบูรณ์
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ abstraction (ItemDefinitionsRepository trait) ไม่ใช่ concrete classes
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::entities::item_definitions::ItemDefinitions;
+
+use super::item_definitions::{ItemDefinitionsRepository, SharedItemDefinitionsRepository};
+
+// 📦 ItemDefinitionsPostgresRepository struct: จัดการแบบของ item ในฐานข้อมูล
+pub struct ItemDefinitionsPostgresRepository {
+    db_pool: PgPool,                  // 🗄️ Database connection pool สำหรับ PostgreSQL
+}
+
+impl ItemDefinitionsPostgresRepository {
+    // 🏗️ Factory method: สร้าง Repository และ wrap ด้วย Arc สำหรับ shared ownership
+    pub fn creation(db_pool: PgPool) -> SharedItemDefinitionsRepository {
+        Arc::new(Self { db_pool })
+    }
+}
+
+#[async_trait]
+impl ItemDefinitionsRepository for ItemDefinitionsPostgresRepository {
+    // 📋 ดึงแบบทั้งหมด
+    async fn find_all(&self) -> Result<Vec<ItemDefinitions>, sqlx::Error> {
+        match sqlx::query_as::<_, ItemDefinitions>("SELECT * FROM item_definitions ORDER BY id;")
+            .fetch_all(&self.db_pool)
+            .await
+        {
+            Ok(definitions) => Ok(definitions),
+            Err(e) => {
+                error!("Failed to find all item definitions: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🔍 ค้นหาแบบตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<ItemDefinitions, sqlx::Error> {
+        match sqlx::query_as::<_, ItemDefinitions>("SELECT * FROM item_definitions WHERE id = $1;")
+            .bind(id)
+            .fetch_one(&self.db_pool)
+            .await
+        {
+            Ok(definition) => Ok(definition),
+            Err(e) => {
+                error!("Failed to find item definition by id: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ➕ เพิ่มแบบใหม่ และส่งกลับแถวที่เพิ่ม
+    async fn insert(&self, definition: ItemDefinitions) -> Result<ItemDefinitions, sqlx::Error> {
+        match sqlx::query_as::<_, ItemDefinitions>(
+            "INSERT INTO item_definitions \
+             (name, category, weight, rarity, required_level, base_stats, stat_variance, max_durability, icon, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *;",
+        )
+        .bind(definition.name)
+        .bind(definition.category)
+        .bind(definition.weight)
+        .bind(definition.rarity)
+        .bind(definition.required_level)
+        .bind(definition.base_stats)
+        .bind(definition.stat_variance)
+        .bind(definition.max_durability)
+        .bind(definition.icon)
+        .bind(definition.created_at)
+        .bind(definition.updated_at)
+        .fetch_one(&self.db_pool)
+        .await
+        {
+            Ok(definition) => Ok(definition),
+            Err(e) => {
+                error!("Failed to insert item definition: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ✏️ แก้แบบ - instance ที่สร้างไปแล้วเก็บค่าของตัวเองไว้ จึงไม่เปลี่ยนตาม
+    async fn update(&self, definition: ItemDefinitions) -> Result<ItemDefinitions, sqlx::Error> {
+        match sqlx::query_as::<_, ItemDefinitions>(
+            "UPDATE item_definitions SET name = $2, category = $3, weight = $4, rarity = $5, required_level = $6, \
+             base_stats = $7, stat_variance = $8, max_durability = $9, icon = $10, updated_at = $11 \
+             WHERE id = $1 RETURNING *;",
+        )
+        .bind(definition.id)
+        .bind(definition.name)
+        .bind(definition.category)
+        .bind(definition.weight)
+        .bind(definition.rarity)
+        .bind(definition.required_level)
+        .bind(definition.base_stats)
+        .bind(definition.stat_variance)
+        .bind(definition.max_durability)
+        .bind(definition.icon)
+        .bind(definition.updated_at)
+        .fetch_one(&self.db_pool)
+        .await
+        {
+            Ok(definition) => Ok(definition),
+            Err(e) => {
+                error!("Failed to update item definition: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+}
@@ -23,9 +23,14 @@
 // Arc เป็นตัว smart pointer ที่ใช้แชร์ข้อมูลระหว่าง threads ได้อย่างปลอดภัย
 use std::sync::Arc;
 use async_trait::async_trait;    // 🔧 สำหรับ async functions ใน traits
+use chrono::NaiveDateTime;
+use futures_util::stream::BoxStream; // 🌊 stream ของแถวจากฐานข้อมูล
 use mockall::automock;           // 🎭 สร้าง Mock objects อัตโนมัติ
 
-use crate::entities::items::Items;
+use crate::entities::{
+    item_revisions::{Auditing, ItemRevisions},
+    items::{Items, ItemsFilter, ItemsSearch, ItemsSearched, Upserted},
+};
 
 // 🔗 Type alias สำหรับ shared Repository
 // dyn = dynamic dispatch, Send + Sync = thread-safe
@@ -42,12 +47,68 @@ pub type SharedItemsRepository = Arc<dyn ItemsRepository + Send + Sync>;
 #[async_trait]
 #[automock]
 pub trait ItemsRepository {
-    // 🔍 ค้นหา Item ตามชื่อ
-    async fn find_by_name(&self, name: String) -> Result<Items, sqlx::Error>;
+    // 🗑️ ทุกการอ่านไม่เห็น item ที่ถูกลบ (deleted_at IS NOT NULL) ยกเว้น find_all ที่ขอ include_deleted
 
-    // ➕ เพิ่ม Item ใหม่ ส่งกลับ ID ของข้อมูลที่เพิ่ม
-    async fn insert(&self, item: Items) -> Result<i32, sqlx::Error>;
+    // 🔍 ค้นหา Item ตามชื่อ ภายในเจ้าของเดียวกัน (owner_id = None คือ item กลางของระบบ)
+    async fn find_by_name(&self, owner_id: Option<i32>, name: String) -> Result<Items, sqlx::Error>;
+
+    // 🔑 item ที่ชนกับชื่อนี้ตาม unique index (เจ้าของ, ประเภท, name_key) - ไม่รวม instance ของแบบ
+    async fn find_by_key(&self, owner_id: Option<i32>, category: String, name: String) -> Result<Items, sqlx::Error>;
+
+    // 📜 ทุกการแก้ไขรับ Auditing และบันทึก revision ใน transaction เดียวกับการแก้ไข
+
+    // ➕ เพิ่ม Item ใหม่ ส่งกลับข้อมูลที่เพิ่ม (id / version จากฐานข้อมูล) ไม่ต้องอ่านซ้ำ
+    async fn insert(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // 📦 เพิ่มหลาย Item ใน transaction เดียว ส่งกลับข้อมูลตามลำดับ (ผิดแถวเดียว = ไม่เพิ่มเลยทั้ง batch)
+    async fn insert_batch(&self, items: Vec<Items>, auditing: Auditing) -> Result<Vec<Items>, sqlx::Error>;
+
+    // 🔄 เพิ่ม หรือแทนที่ item ที่มีเจ้าของ + ประเภท + ชื่อเดียวกัน (ไม่นับ instance และ item ที่ถูกลบ)
+    // 📜 revision เป็น create หรือ update ตามผลจริง (ไม่ใช้ action ใน auditing)
+    // 🔢 version ต้องตรงกับแถวเดิม - ไม่ตรงหรือไม่มีแถวเดิมถือว่าไม่พบ (RowNotFound) / None = ไม่ตรวจ
+    async fn upsert(&self, item: Items, version: Option<i32>, auditing: Auditing) -> Result<Upserted, sqlx::Error>;
 
     // 🔍 ค้นหา Item ตาม ID
     async fn find_by_id(&self, id: i32) -> Result<Items, sqlx::Error>;
+
+    // 🔍 ค้นหาหลาย Item ตาม ID ใน query เดียว เรียงตาม ID (id ที่ไม่พบไม่อยู่ในผลลัพธ์)
+    async fn find_by_ids(&self, ids: Vec<i32>) -> Result<Vec<Items>, sqlx::Error>;
+
+    // 📋 ดึง Item ทั้งหมดที่ตรงกับ filter เรียงตาม ID
+    async fn find_all(&self, filter: ItemsFilter) -> Result<Vec<Items>, sqlx::Error>;
+
+    // 📤 อ่าน Item ตามเงื่อนไขเดียวกับ find_all ทีละแถวจาก cursor (ไม่โหลดทั้งตารางเข้าหน่วยความจำ)
+    fn stream_all(&self, filter: ItemsFilter) -> BoxStream<'static, Result<Items, sqlx::Error>>;
+
+    // 🔎 ค้นหาชื่อ Item (substring / สะกดใกล้เคียง / คำครบคำ) เรียงตามคะแนนความเกี่ยวข้อง
+    async fn search(&self, search: ItemsSearch) -> Result<Vec<ItemsSearched>, sqlx::Error>;
+
+    // 👤 ดึง Item ทั้งหมดของผู้เล่น เรียงตาม ID
+    async fn find_by_owner(&self, owner_id: i32) -> Result<Vec<Items>, sqlx::Error>;
+
+    // 🔢 ทุกการแก้ไขเพิ่ม version ขึ้น 1 - version ไม่ตรงกับที่คาดถือว่าไม่พบ (RowNotFound)
+
+    // 🔁 ย้าย Item ไปให้ผู้เล่นคนใหม่ ส่งกลับข้อมูลหลังย้าย (version None = ไม่ตรวจ)
+    async fn update_owner(&self, id: i32, owner_id: i32, updated_at: NaiveDateTime, version: Option<i32>, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // 🧩 แทนที่ attributes และเวอร์ชัน schema ตาม id ใน entity ส่งกลับข้อมูลหลังแก้ไข (ต้องตรงกับ version ใน entity)
+    async fn update_attributes(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // ✏️ แทนที่ทุก field ที่แก้ไขได้ตาม id ใน entity ส่งกลับข้อมูลหลังแก้ไข (ต้องตรงกับ version ใน entity)
+    async fn update(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // 🗑️ ลบแบบ soft delete (ตั้ง deleted_at) ส่งกลับข้อมูลหลังลบ - item ที่ลบไปแล้วถือว่าไม่พบ (version None = ไม่ตรวจ)
+    async fn soft_delete(&self, id: i32, deleted_at: NaiveDateTime, version: Option<i32>, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // ♻️ กู้คืน item ที่ถูกลบ ส่งกลับข้อมูลหลังกู้คืน - item ที่ไม่ได้ถูกลบถือว่าไม่พบ
+    async fn restore(&self, id: i32, updated_at: NaiveDateTime, auditing: Auditing) -> Result<Items, sqlx::Error>;
+
+    // 🧹 ลบจริงทุก item ที่ถูกลบก่อนเวลาที่กำหนด ส่งกลับจำนวนแถวที่ลบ
+    async fn purge(&self, deleted_before: NaiveDateTime) -> Result<u64, sqlx::Error>;
+
+    // 📜 ประวัติการแก้ไขของ item เรียงตาม revision
+    async fn find_revisions(&self, item_id: i32) -> Result<Vec<ItemRevisions>, sqlx::Error>;
+
+    // 🔍 revision เดียวของ item
+    async fn find_revision(&self, item_id: i32, revision: i32) -> Result<ItemRevisions, sqlx::Error>;
 }
@@ -0,0 +1,835 @@
+// === 💾 Infrastructure Layer: Items Repository (PostgreSQL) ===
+// 📖 จัดการการเข้าถึงข้อมูล Item ในฐานข้อมูล - เป็น concrete implementation ของ ItemsRepository
+//    ใช้ได้กับทุกประเภทใน catalog (ตาราง categories) ไม่ต้องเขียน repository ใหม่ต่อประเภท
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ItemsPostgresRepository มีหน้าที่เดียว: จัดการการเข้าถึงข้อมูล Item ในฐานข้อมูล
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เพิ่มประเภทใหม่ได้จาก catalog โดยไม่แก้ไข repository
+//
+// 3️⃣ Liskov Substitution Principle (LSP):
+//    ItemsPostgresRepository สามารถใช้แทน ItemsRepository interface ได้อย่างสมบูรณ์
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ abstraction (ItemsRepository trait) ไม่ใช่ concrete classes
+
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;         // 🔧 สำหรับ async trait
+use chrono::NaiveDateTime;            // 📅 เวลา
+use futures_util::{StreamExt, stream::{self, BoxStream}}; // 🌊 stream ของแถวสำหรับ export
+use sqlx::{Arguments, Connection, PgPool, Postgres, QueryBuilder, Transaction, postgres::PgArguments}; // 🗄️ PostgreSQL connection pool / transaction
+use tokio::sync::mpsc;                // 📬 ส่งแถวจาก cursor ไปให้ผู้อ่าน stream
+use tracing::error;                   // 📝 Logging สำหรับ error
+
+use crate::{
+    entities::{
+        item_revisions::{Auditing, ItemRevisions}, // 📜 ประวัติการแก้ไข
+        items::{Items, ItemsExpression, ItemsFilter, ItemsOperator, ItemsSearch, ItemsSearched, ItemsValue, Upserted}, // 🏛️ Domain Entity
+    },
+    models::{audit::ItemAction, item::{Category, name_key, name_normalizing}}, // 📜 action ของ upsert / 🏷️ ใช้กำหนด scope ของ repository / 🔤 ชื่อและ key ของชื่อ
+};
+
+use super::{
+    items::{ItemsRepository, SharedItemsRepository}, // 📋 Interface ที่ต้อง implement
+    unit_of_work_postgres::{PgConnectionGuard, SharedTransaction, connection_acquiring}, // 🔒 transaction ของ UnitOfWork
+};
+
+// 🔎 เงื่อนไขของ find_all / stream_all - เงื่อนไขที่เป็น NULL จะไม่ถูกใช้กรอง
+// 🧮 expression ของ ?filter= ต่อท้ายด้วย AND ($10, $11, ... ) แล้วจึง ORDER BY id
+const FILTERED_ITEMS_SQL: &str = "SELECT * FROM items WHERE (($4::TEXT IS NULL OR category = $4) \
+     AND ($1::TEXT IS NULL OR rarity = $1) \
+     AND ($2::INT IS NULL OR required_level >= $2) \
+     AND ($3::INT IS NULL OR required_level <= $3) \
+     AND ($5::TEXT[] IS NULL OR category = ANY($5)) \
+     AND ($6::INT IS NULL OR definition_id = $6) \
+     AND ($7 OR deleted_at IS NULL) \
+     AND ($8::TIMESTAMP IS NULL OR updated_at >= $8) \
+     AND ($9::TIMESTAMP IS NULL OR updated_at < $9))";
+
+// 🔎 ค้นหาชื่อ: เทียบกับ name_key และคำค้น ($1) ที่แอปแปลงเป็นตัวเล็กแล้ว - ฐานข้อมูลไม่แปลงตัวพิมพ์เอง
+//    item_search_text = ชื่อที่ normalize แล้ว (สระอำ / ไม่มี zero-width space)
+//    item_search_key = รูปที่อักษรไทยเป็น ASCII ให้ pg_trgm ใช้ได้ (ดู migration item_search)
+//    substring ใช้ LIKE กับ index ก่อน แล้วยืนยันด้วย strpos บนชื่อที่ normalize (LIKE ไม่สน _ และ % ในคำค้น)
+const SEARCH_ITEMS_SQL: &str = "SELECT items.*, ( \
+         CASE WHEN strpos(item_search_text(name_key), item_search_text($1)) = 1 THEN 1.0 \
+              WHEN strpos(item_search_text(name_key), item_search_text($1)) > 0 THEN 0.5 ELSE 0.0 END \
+         + word_similarity(item_search_key($1), item_search_key(name_key)) \
+         + ts_rank(to_tsvector('simple', name_key), plainto_tsquery('simple', $1)))::REAL AS score \
+     FROM items WHERE (deleted_at IS NULL \
+     AND ($2::TEXT IS NULL OR category = $2) \
+     AND ($3::TEXT[] IS NULL OR category = ANY($3)) \
+     AND ((item_search_key(name_key) LIKE '%' || replace(replace(replace(item_search_key($1), '\\', '\\\\'), '%', '\\%'), '_', '\\_') || '%' \
+           AND strpos(item_search_text(name_key), item_search_text($1)) > 0) \
+          OR item_search_key($1) <% item_search_key(name_key) \
+          OR to_tsvector('simple', name_key) @@ plainto_tsquery('simple', $1))) \
+     ORDER BY score DESC, id LIMIT $4;";
+
+// 📬 จำนวนแถวที่รอผู้อ่าน stream ได้ก่อนหยุดอ่าน cursor (ผู้อ่านช้า = ดึงจากฐานข้อมูลช้าลงตาม)
+const STREAM_BUFFER_ROWS: usize = 256;
+
+// 📦 ItemsPostgresRepository struct: จัดการข้อมูล Item ในฐานข้อมูล
+pub struct ItemsPostgresRepository {
+    db_pool: PgPool,                  // 🗄️ Database connection pool สำหรับ PostgreSQL
+    category: Option<String>,         // 🏷️ จำกัดเฉพาะประเภทเดียว (None = ทุกประเภท)
+    transaction: Option<SharedTransaction>, // 🔒 transaction ของ UnitOfWork (None = ใช้ pool)
+}
+
+impl ItemsPostgresRepository {
+    // 🏗️ Factory method: Repository ของ Item ทุกประเภท
+    pub fn creation(db_pool: PgPool) -> SharedItemsRepository {
+        Arc::new(Self { db_pool, category: None, transaction: None }) // 🔗 Arc สำหรับให้หลาย ๆ ที่ใช้ร่วมกันได้
+    }
+
+    // 🏗️ Factory method: Repository ที่เห็นเฉพาะประเภทเดียว (เช่น StaffRepository)
+    pub fn scoped(db_pool: PgPool, category: Category) -> SharedItemsRepository {
+        Arc::new(Self { db_pool, category: Some(category.to_string()), transaction: None })
+    }
+
+    // 🏗️ Factory method: Repository ที่อ่าน/เขียนผ่าน transaction ของ UnitOfWork
+    pub fn bound(db_pool: PgPool, transaction: SharedTransaction) -> SharedItemsRepository {
+        Arc::new(Self { db_pool, category: None, transaction: Some(transaction) })
+    }
+
+    // 🔌 connection สำหรับหนึ่งคำสั่ง (transaction ของ UnitOfWork หรือจาก pool)
+    async fn connection(&self) -> Result<PgConnectionGuard, sqlx::Error> {
+        connection_acquiring(&self.db_pool, self.transaction.as_ref()).await
+    }
+}
+
+// 🔧 Implement ItemsRepository trait สำหรับ ItemsPostgresRepository
+// async_trait เพราะ Rust ยังไม่รองรับ async fn ใน trait ธรรมดา
+// 🏷️ ทุก query มีเงื่อนไข ($n::TEXT IS NULL OR category = $n) สำหรับ scope ของประเภท
+// 📜 ทุกการแก้ไขบันทึก revision ใน transaction เดียวกัน (สำเร็จหรือไม่สำเร็จพร้อมกัน)
+#[async_trait]
+impl ItemsRepository for ItemsPostgresRepository {
+    // 🔍 ค้นหา Item ตามชื่อ (ไม่สนตัวพิมพ์ / ชื่อที่ normalize แล้ว - เหมือน unique index ของชื่อ)
+    // 👤 IS NOT DISTINCT FROM ทำให้ NULL = NULL (item กลางของระบบ) เทียบกันได้
+    async fn find_by_name(&self, owner_id: Option<i32>, name: String) -> Result<Items, sqlx::Error>{
+        let item = match sqlx::query_as::<_, Items>(
+            "SELECT * FROM items WHERE (name_key = $1 AND owner_id IS NOT DISTINCT FROM $2 AND deleted_at IS NULL \
+             AND ($3::TEXT IS NULL OR category = $3)) LIMIT 1;", // 📊 SQL query
+        )
+        .bind(name_key(&name))            // 🔗 Bind parameter เพื่อป้องกัน SQL injection
+        .bind(owner_id)                   // 🔗 Bind parameter: เจ้าของ
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_one(&mut *self.connection().await?) // 🎯 ดึงข้อมูลแถวเดียว
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find item by name: {}", e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(item)
+    }
+
+    // 🔑 ค้นหา Item ด้วย key เดียวกับ unique index ของชื่อ (และ upsert) - ได้แถวเดียวเสมอ
+    async fn find_by_key(&self, owner_id: Option<i32>, category: String, name: String) -> Result<Items, sqlx::Error>{
+        let item = match sqlx::query_as::<_, Items>(
+            "SELECT * FROM items WHERE (COALESCE(owner_id, 0) = COALESCE($1, 0) AND category = $2 AND name_key = $3 \
+             AND definition_id IS NULL AND deleted_at IS NULL AND ($4::TEXT IS NULL OR category = $4));", // 📊 SQL query
+        )
+        .bind(owner_id)                   // 🔗 Bind parameter: เจ้าของ
+        .bind(category)                   // 🔗 Bind parameter: ประเภท
+        .bind(name_key(&name))            // 🔗 Bind parameter: key ของชื่อ
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_one(&mut *self.connection().await?) // 🎯 ดึงข้อมูลแถวเดียว
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find item by key: {}", e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(item)
+    }
+
+    // ➕ เพิ่ม Item ใหม่ลงฐานข้อมูล และส่งกลับแถวจาก RETURNING * (ไม่ต้อง find_by_id ซ้ำ)
+    // 📜 บันทึก revision "create" ใน transaction เดียวกัน
+    async fn insert(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 เพิ่ม item และ revision พร้อมกัน
+        let item = item_inserting(&mut tx, item, &auditing).await?;
+        tx.commit().await?;               // ✅ ยืนยันทั้ง item และ revision
+        Ok(item)
+    }
+
+    // 📦 เพิ่มหลาย Item ใน transaction เดียว ส่งกลับข้อมูลตามลำดับ - แถวใดผิดจะไม่มีแถวไหนถูกเพิ่มเลย
+    async fn insert_batch(&self, items: Vec<Items>, auditing: Auditing) -> Result<Vec<Items>, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 ทั้ง batch สำเร็จหรือไม่สำเร็จพร้อมกัน
+        let mut inserted = Vec::with_capacity(items.len());
+        for item in items {
+            inserted.push(item_inserting(&mut tx, item, &auditing).await?);
+        }
+        tx.commit().await?;               // ✅ ยืนยันทั้ง batch
+        Ok(inserted)
+    }
+
+    // 🔄 INSERT ... ON CONFLICT บน unique index ของชื่อ (items_owner_category_name_key) - ชื่อต่างตัวพิมพ์แทนที่แถวเดิมโดยคงชื่อเดิมไว้
+    // 🔒 ล็อกแถวเดิม (ถ้ามี) ไว้ก่อนเพื่อตรวจ version และบันทึกภาพก่อนแก้ไขลง revision
+    async fn upsert(&self, item: Items, version: Option<i32>, auditing: Auditing) -> Result<Upserted, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 เขียน item และ revision พร้อมกัน
+        let before = match sqlx::query_as::<_,Items>(
+            "SELECT * FROM items WHERE (COALESCE(owner_id, 0) = COALESCE($1, 0) AND category = $2 AND name_key = $3 \
+             AND definition_id IS NULL AND deleted_at IS NULL) FOR UPDATE;", // 📊 SQL query
+        )
+        .bind(item.owner_id)              // 🔗 Bind parameter: เจ้าของ
+        .bind(&item.category)             // 🔗 Bind parameter: ประเภท
+        .bind(name_key(&item.name))       // 🔗 Bind parameter: key ของชื่อ
+        .fetch_optional(&mut *tx)         // 🎯 แถวเดิม (ถ้ามี)
+        .await
+        {
+            Ok(before) => before,         // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to lock item for upsert: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        // 🔢 มี version = ต้องแทนที่แถวเดิมที่ยังเป็น version ที่ client อ่านมา (แถวหายไปก็ไม่ตรง)
+        if let Some(version) = version && before.as_ref().is_none_or(|before| before.version != version) {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        let item = match sqlx::query_as::<_,Items>(
+            "INSERT INTO items (name, category, owner_id, weight, rarity, required_level, stats, attributes, attributes_schema_version, definition_id, durability, created_at, updated_at, name_key) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) \
+             ON CONFLICT (COALESCE(owner_id, 0), category, name_key) WHERE definition_id IS NULL AND deleted_at IS NULL \
+             DO UPDATE SET weight = EXCLUDED.weight, rarity = EXCLUDED.rarity, required_level = EXCLUDED.required_level, \
+             stats = EXCLUDED.stats, attributes = EXCLUDED.attributes, attributes_schema_version = EXCLUDED.attributes_schema_version, \
+             durability = EXCLUDED.durability, updated_at = EXCLUDED.updated_at, version = items.version + 1 \
+             RETURNING *;", // 📊 SQL query
+        )
+        .bind(&item.name)                 // 🔗 Bind parameter: ชื่อ
+        .bind(item.category)              // 🔗 Bind parameter: ประเภท
+        .bind(item.owner_id)              // 🔗 Bind parameter: เจ้าของ
+        .bind(item.weight)                // 🔗 Bind parameter: น้ำหนัก
+        .bind(item.rarity)                // 🔗 Bind parameter: ความหายาก
+        .bind(item.required_level)        // 🔗 Bind parameter: เลเวลขั้นต่ำ
+        .bind(item.stats)                 // 🔗 Bind parameter: ค่าสถานะ (JSONB)
+        .bind(item.attributes)            // 🔗 Bind parameter: attributes (JSONB)
+        .bind(item.attributes_schema_version) // 🔗 Bind parameter: เวอร์ชัน schema
+        .bind(item.definition_id)         // 🔗 Bind parameter: แบบของ instance
+        .bind(item.durability)            // 🔗 Bind parameter: ความทนทาน
+        .bind(item.created_at)            // 🔗 Bind parameter: วันที่สร้าง
+        .bind(item.updated_at)            // 🔗 Bind parameter: วันที่อัปเดต
+        .bind(name_key(&item.name))       // 🔗 Bind parameter: key ของชื่อ (ใช้ตรวจชื่อซ้ำ)
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่เพิ่ม/แทนที่
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to upsert item: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        // 🔢 แถวใหม่เริ่มที่ version 1 เสมอ - แถวที่ถูกแทนที่ได้ version + 1
+        let created = item.version == 1;
+        let auditing = Auditing {
+            action: if created { ItemAction::Create } else { ItemAction::Update },
+            ..auditing
+        };
+        revision_inserting(&mut tx, auditing.recording(item.id.unwrap_or_default(), before.as_ref(), Some(&item))).await?;
+        tx.commit().await?;               // ✅ ยืนยันทั้ง item และ revision
+
+        Ok(if created { Upserted::Created(item) } else { Upserted::Updated(item) })
+    }
+
+    // 🔍 ค้นหา Item ตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<Items, sqlx::Error>{
+        let item = match sqlx::query_as::<_,Items>(
+            "SELECT * FROM items WHERE (id = $1 AND deleted_at IS NULL AND ($2::TEXT IS NULL OR category = $2));", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ที่ต้องการหา
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_one(&mut *self.connection().await?) // 🎯 ดึงข้อมูลแถวเดียว
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find item by id: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(item)
+    }
+
+    // 🔍 ค้นหาหลาย Item ตาม ID ด้วย ANY($1) - round trip เดียวไม่ว่าจะขอกี่ id
+    async fn find_by_ids(&self, ids: Vec<i32>) -> Result<Vec<Items>, sqlx::Error>{
+        let items = match sqlx::query_as::<_,Items>(
+            "SELECT * FROM items WHERE (id = ANY($1) AND deleted_at IS NULL AND ($2::TEXT IS NULL OR category = $2)) ORDER BY id;", // 📊 SQL query
+        )
+        .bind(ids)                        // 🔗 Bind parameter: ID ทั้งหมด (INT[])
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_all(&mut *self.connection().await?) // 🎯 ดึงข้อมูลทุกแถวที่พบ
+        .await
+        {
+            Ok(items) => items,           // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find items by ids: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(items)
+    }
+
+    // 📋 ดึง Item ทั้งหมด เรียงตาม ID
+    // 🔎 เงื่อนไขที่เป็น NULL จะไม่ถูกใช้กรอง
+    async fn find_all(&self, filter: ItemsFilter) -> Result<Vec<Items>, sqlx::Error>{
+        let mut query = filtered_query(filter, self.category.clone())?;
+        let items = match query.build_query_as::<Items>()
+        .fetch_all(&mut *self.connection().await?) // 🎯 ดึงข้อมูลทุกแถว
+        .await
+        {
+            Ok(items) => items,           // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find all items: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(items)
+    }
+
+    // 📤 อ่านแถวจาก cursor ของ sqlx ใน task แยก แล้วส่งต่อผ่าน channel ที่มีขนาดจำกัด
+    // 🔗 stream ของ sqlx ยืม pool ไว้ จึงส่งออกไปนอก method ตรง ๆ ไม่ได้ / ผู้อ่านเลิกอ่าน = task หยุดดึงแถว
+    fn stream_all(&self, filter: ItemsFilter) -> BoxStream<'static, Result<Items, sqlx::Error>>{
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER_ROWS);
+        let db_pool = self.db_pool.clone(); // 🌊 อ่านจาก pool เสมอ (ไม่เห็นการแก้ไขที่ยังไม่ commit ของ UnitOfWork)
+        let category = self.category.clone();
+
+        tokio::spawn(async move {
+            let mut query = match filtered_query(filter, category) {
+                Ok(query) => query,
+                Err(e) => {
+                    error!("Failed to stream items: {}",e); // 📝 Log error
+                    let _ = sender.send(Err(e)).await;
+                    return;
+                }
+            };
+            let mut rows = query.build_query_as::<Items>().fetch(&db_pool); // 🎯 ดึงทีละแถว
+            while let Some(row) = rows.next().await {
+                if let Err(e) = &row {
+                    error!("Failed to stream items: {}",e); // 📝 Log error
+                }
+                let failed = row.is_err();
+                if sender.send(row).await.is_err() || failed {
+                    break;                // 🛑 ผู้อ่านปิด stream แล้ว หรือ cursor ล้ม
+                }
+            }
+        });
+
+        Box::pin(stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|row| (row, receiver))
+        }))
+    }
+
+    // 🔎 ค้นหาชื่อด้วย index ของ migration item_search: trigram (substring / สะกดใกล้เคียง) และ full-text (คำครบคำ)
+    // 🏆 คะแนน = ขึ้นต้นด้วยคำค้น 1 / มีคำค้นอยู่ในชื่อ 0.5 + ความใกล้เคียงของ trigram (0-1) + อันดับของ full-text
+    async fn search(&self, search: ItemsSearch) -> Result<Vec<ItemsSearched>, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 threshold ของ <% ใช้เฉพาะใน transaction นี้
+        sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true);")
+            .bind(search.min_similarity.to_string()) // 🔗 Bind parameter: ความใกล้เคียงขั้นต่ำ
+            .execute(&mut *tx)
+            .await?;
+
+        let items = match sqlx::query_as::<_,ItemsSearched>(SEARCH_ITEMS_SQL)
+        .bind(search.query)               // 🔗 Bind parameter: ข้อความที่ค้น
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .bind(search.categories)          // 🔗 Bind parameter: ประเภทและลูกหลาน
+        .bind(search.limit)               // 🔗 Bind parameter: จำนวนผลลัพธ์สูงสุด
+        .fetch_all(&mut *tx)              // 🎯 ดึงผลลัพธ์ตามคะแนน
+        .await
+        {
+            Ok(items) => items,           // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to search items: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        tx.commit().await?;
+        Ok(items)
+    }
+
+    // 👤 ดึง Item ทั้งหมดของผู้เล่น
+    async fn find_by_owner(&self, owner_id: i32) -> Result<Vec<Items>, sqlx::Error>{
+        let items = match sqlx::query_as::<_,Items>(
+            "SELECT * FROM items WHERE (owner_id = $1 AND deleted_at IS NULL AND ($2::TEXT IS NULL OR category = $2)) ORDER BY id;", // 📊 SQL query
+        )
+        .bind(owner_id)                   // 🔗 Bind parameter: เจ้าของ
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_all(&mut *self.connection().await?) // 🎯 ดึงข้อมูลทุกแถว
+        .await
+        {
+            Ok(items) => items,           // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find items by owner: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(items)
+    }
+
+    // 🔁 ย้าย Item ไปให้ผู้เล่นคนใหม่
+    async fn update_owner(&self, id: i32, owner_id: i32, updated_at: NaiveDateTime, version: Option<i32>, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 แก้ไขและบันทึก revision พร้อมกัน
+        let before = self.locked_finding(&mut tx, id).await?;
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET owner_id = $2, updated_at = $3, version = version + 1 \
+             WHERE (id = $1 AND deleted_at IS NULL AND ($4::TEXT IS NULL OR category = $4) \
+             AND ($5::INT IS NULL OR version = $5)) RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(owner_id)                   // 🔗 Bind parameter: เจ้าของใหม่
+        .bind(updated_at)                 // 🔗 Bind parameter: วันที่อัปเดต
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .bind(version)                    // 🔗 Bind parameter: version ที่คาด
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to update item owner: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        tx.commit().await?;
+        Ok(item)
+    }
+
+    // 🧩 แทนที่ attributes และเวอร์ชัน schema
+    async fn update_attributes(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let id = item.id.unwrap_or_default();
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 แก้ไขและบันทึก revision พร้อมกัน
+        let before = self.locked_finding(&mut tx, id).await?;
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET attributes = $2, attributes_schema_version = $3, updated_at = $4, version = version + 1 \
+             WHERE (id = $1 AND deleted_at IS NULL AND ($5::TEXT IS NULL OR category = $5) AND version = $6) RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(item.attributes)            // 🔗 Bind parameter: attributes ใหม่ (JSONB)
+        .bind(item.attributes_schema_version) // 🔗 Bind parameter: เวอร์ชัน schema
+        .bind(item.updated_at)            // 🔗 Bind parameter: วันที่อัปเดต
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .bind(item.version)               // 🔗 Bind parameter: version ที่อ่านมา
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to update item attributes: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        tx.commit().await?;
+        Ok(item)
+    }
+
+    // ✏️ แทนที่ทุก field ที่แก้ไขได้ (ยกเว้น id / created_at / deleted_at)
+    async fn update(&self, item: Items, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let id = item.id.unwrap_or_default();
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 แก้ไขและบันทึก revision พร้อมกัน
+        let before = self.locked_finding(&mut tx, id).await?;
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET name = $2, category = $3, owner_id = $4, weight = $5, rarity = $6, required_level = $7, \
+             stats = $8, attributes = $9, attributes_schema_version = $10, definition_id = $11, durability = $12, updated_at = $13, \
+             version = version + 1, name_key = $16 \
+             WHERE (id = $1 AND deleted_at IS NULL AND ($14::TEXT IS NULL OR category = $14) AND version = $15) RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(&item.name)                 // 🔗 Bind parameter: ชื่อ
+        .bind(item.category)              // 🔗 Bind parameter: ประเภท
+        .bind(item.owner_id)              // 🔗 Bind parameter: เจ้าของ
+        .bind(item.weight)                // 🔗 Bind parameter: น้ำหนัก
+        .bind(item.rarity)                // 🔗 Bind parameter: ความหายาก
+        .bind(item.required_level)        // 🔗 Bind parameter: เลเวลขั้นต่ำ
+        .bind(item.stats)                 // 🔗 Bind parameter: ค่าสถานะ (JSONB)
+        .bind(item.attributes)            // 🔗 Bind parameter: attributes (JSONB)
+        .bind(item.attributes_schema_version) // 🔗 Bind parameter: เวอร์ชัน schema
+        .bind(item.definition_id)         // 🔗 Bind parameter: แบบของ instance
+        .bind(item.durability)            // 🔗 Bind parameter: ความทนทาน
+        .bind(item.updated_at)            // 🔗 Bind parameter: วันที่อัปเดต
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .bind(item.version)               // 🔗 Bind parameter: version ที่อ่านมา
+        .bind(name_key(&item.name))       // 🔗 Bind parameter: key ของชื่อ
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to update item: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        tx.commit().await?;
+        Ok(item)
+    }
+
+    // 🗑️ ลบแบบ soft delete: ตั้ง deleted_at (และ updated_at) แถวยังอยู่จนกว่าจะ purge
+    async fn soft_delete(&self, id: i32, deleted_at: NaiveDateTime, version: Option<i32>, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 ลบและบันทึก revision พร้อมกัน
+        let before = self.locked_finding(&mut tx, id).await?;
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET deleted_at = $2, updated_at = $2, version = version + 1 \
+             WHERE (id = $1 AND deleted_at IS NULL AND ($3::TEXT IS NULL OR category = $3) \
+             AND ($4::INT IS NULL OR version = $4)) RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(deleted_at)                 // 🔗 Bind parameter: เวลาที่ลบ
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .bind(version)                    // 🔗 Bind parameter: version ที่คาด
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to soft delete item: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        tx.commit().await?;
+        Ok(item)
+    }
+
+    // ♻️ กู้คืน item ที่ถูกลบ
+    async fn restore(&self, id: i32, updated_at: NaiveDateTime, auditing: Auditing) -> Result<Items, sqlx::Error>{
+        let mut connection = self.connection().await?;
+        let mut tx = connection.begin().await?; // 🔒 กู้คืนและบันทึก revision พร้อมกัน
+        let before = self.locked_finding(&mut tx, id).await?;
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET deleted_at = NULL, updated_at = $2, version = version + 1 \
+             WHERE (id = $1 AND deleted_at IS NOT NULL AND ($3::TEXT IS NULL OR category = $3)) RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(updated_at)                 // 🔗 Bind parameter: วันที่อัปเดต
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_one(&mut *tx)              // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,             // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to restore item: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        tx.commit().await?;
+        Ok(item)
+    }
+
+
+    // 🧹 ลบจริงทุก item ที่ถูกลบก่อน deleted_before
+    async fn purge(&self, deleted_before: NaiveDateTime) -> Result<u64, sqlx::Error>{
+        let result = match sqlx::query(
+            "DELETE FROM items WHERE (deleted_at < $1 AND ($2::TEXT IS NULL OR category = $2));", // 📊 SQL query
+        )
+        .bind(deleted_before)             // 🔗 Bind parameter: ลบที่เก่ากว่าเวลานี้
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .execute(&mut *self.connection().await?) // 🎯 รันคำสั่ง DELETE
+        .await
+        {
+            Ok(result) => result,         // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to purge items: {:?}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(result.rows_affected())
+    }
+
+    // 📜 ประวัติการแก้ไขของ item เรียงจากเก่าไปใหม่ (รวม item ที่ถูกลบ/purge แล้ว)
+    async fn find_revisions(&self, item_id: i32) -> Result<Vec<ItemRevisions>, sqlx::Error>{
+        let revisions = match sqlx::query_as::<_,ItemRevisions>(
+            "SELECT * FROM item_revisions WHERE item_id = $1 ORDER BY revision;", // 📊 SQL query
+        )
+        .bind(item_id)                    // 🔗 Bind parameter: ID ของ item
+        .fetch_all(&mut *self.connection().await?) // 🎯 ดึงข้อมูลทั้งหมด
+        .await
+        {
+            Ok(revisions) => revisions,   // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find item revisions: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(revisions)
+    }
+
+    // 🔍 revision เดียวของ item
+    async fn find_revision(&self, item_id: i32, revision: i32) -> Result<ItemRevisions, sqlx::Error>{
+        let revision = match sqlx::query_as::<_,ItemRevisions>(
+            "SELECT * FROM item_revisions WHERE (item_id = $1 AND revision = $2);", // 📊 SQL query
+        )
+        .bind(item_id)                    // 🔗 Bind parameter: ID ของ item
+        .bind(revision)                   // 🔗 Bind parameter: ลำดับ revision
+        .fetch_one(&mut *self.connection().await?) // 🎯 ดึงข้อมูลแถวเดียว
+        .await
+        {
+            Ok(revision) => revision,     // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to find item revision: {}",e); // 📝 Log error
+                return Err(e);            // ❌ ส่ง error กลับ
+            }
+        };
+        Ok(revision)
+    }
+}
+
+impl ItemsPostgresRepository {
+    // 🔒 อ่านแถวปัจจุบัน (รวมที่ถูกลบ) และล็อกไว้จนจบ transaction - เป็นภาพ "ก่อน" ของ revision
+    async fn locked_finding(&self, tx: &mut Transaction<'_, Postgres>, id: i32) -> Result<Items, sqlx::Error>{
+        match sqlx::query_as::<_,Items>(
+            "SELECT * FROM items WHERE (id = $1 AND ($2::TEXT IS NULL OR category = $2)) FOR UPDATE;", // 📊 SQL query
+        )
+        .bind(id)                         // 🔗 Bind parameter: ID ของ item
+        .bind(&self.category)             // 🔗 Bind parameter: scope ของประเภท
+        .fetch_one(&mut **tx)             // 🎯 ดึงข้อมูลแถวเดียว
+        .await
+        {
+            Ok(item) => Ok(item),         // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to lock item: {}",e); // 📝 Log error
+                Err(e)                    // ❌ ส่ง error กลับ
+            }
+        }
+    }
+}
+
+// 🔎 query ของ find_all / stream_all พร้อม bind เงื่อนไขทั้งหมด
+pub(crate) fn filtered_query(filter: ItemsFilter, category: Option<String>) -> Result<QueryBuilder<'static, Postgres>, sqlx::Error>{
+    let mut arguments = PgArguments::default();
+    arguments.add(filter.rarity).map_err(sqlx::Error::Encode)?;          // 🔗 $1: ความหายาก
+    arguments.add(filter.min_level).map_err(sqlx::Error::Encode)?;       // 🔗 $2: เลเวลขั้นต่ำ
+    arguments.add(filter.max_level).map_err(sqlx::Error::Encode)?;       // 🔗 $3: เลเวลสูงสุด
+    arguments.add(category).map_err(sqlx::Error::Encode)?;               // 🔗 $4: scope ของประเภท
+    arguments.add(filter.categories).map_err(sqlx::Error::Encode)?;      // 🔗 $5: ประเภทและลูกหลาน
+    arguments.add(filter.definition_id).map_err(sqlx::Error::Encode)?;   // 🔗 $6: แบบของ item
+    arguments.add(filter.include_deleted).map_err(sqlx::Error::Encode)?; // 🔗 $7: รวม item ที่ถูกลบ
+    arguments.add(filter.updated_from).map_err(sqlx::Error::Encode)?;    // 🔗 $8: แก้ไขตั้งแต่
+    arguments.add(filter.updated_to).map_err(sqlx::Error::Encode)?;      // 🔗 $9: แก้ไขก่อน
+
+    let mut query = QueryBuilder::with_arguments(FILTERED_ITEMS_SQL, arguments);
+    if let Some(expression) = filter.expression {
+        query.push(" AND ");
+        expression_pushing(&mut query, expression);
+    }
+    query.push(" ORDER BY id;");
+    Ok(query)
+}
+
+// 🧮 แปลง ItemsExpression เป็น SQL: ชื่อคอลัมน์มาจาก whitelist ของ ItemsField / ค่าทุกตัวเป็น bind parameter
+fn expression_pushing(query: &mut QueryBuilder<'static, Postgres>, expression: ItemsExpression){
+    let (expressions, separator) = match expression {
+        ItemsExpression::And(expressions) => (expressions, " AND "),
+        ItemsExpression::Or(expressions) => (expressions, " OR "),
+        ItemsExpression::Comparison(field, operator, values) => {
+            let comparison = match operator {
+                ItemsOperator::Equal => " = ",
+                ItemsOperator::NotEqual => " <> ",
+                ItemsOperator::Less => " < ",
+                ItemsOperator::LessOrEqual => " <= ",
+                ItemsOperator::Greater => " > ",
+                ItemsOperator::GreaterOrEqual => " >= ",
+                ItemsOperator::In => " IN (",
+                ItemsOperator::NotIn => " NOT IN (",
+                ItemsOperator::Like => " LIKE ",
+                ItemsOperator::NotLike => " NOT LIKE ",
+            };
+            query.push("(").push(field.column()).push(comparison);
+            for (index, value) in values.into_iter().enumerate() {
+                if index > 0 {
+                    query.push(", ");
+                }
+                match (operator, value) {
+                    // ✳️ * = % (like_pattern)
+                    (ItemsOperator::Like | ItemsOperator::NotLike, ItemsValue::Text(text)) => query.push_bind(like_pattern(&text)),
+                    (_, ItemsValue::Text(text)) => query.push_bind(text),
+                    (_, ItemsValue::Integer(integer)) => query.push_bind(integer),
+                    (_, ItemsValue::Timestamp(timestamp)) => query.push_bind(timestamp),
+                };
+            }
+            if matches!(operator, ItemsOperator::In | ItemsOperator::NotIn) {
+                query.push(")");
+            }
+            query.push(")");
+            return;
+        }
+    };
+    query.push("(");
+    for (index, expression) in expressions.into_iter().enumerate() {
+        if index > 0 {
+            query.push(separator);
+        }
+        expression_pushing(query, expression);
+    }
+    query.push(")");
+}
+
+// ✳️ ค่าของ LIKE: * = % / ตัวอักษรพิเศษของ LIKE (\\ % _) ในค่าเป็นตัวอักษรธรรมดา
+pub(crate) fn like_pattern(text: &str) -> String{
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_").replace('*', "%")
+}
+
+// ➕ เพิ่ม Item หนึ่งแถวพร้อม revision "create" ใน transaction ที่ส่งมา ส่งกลับแถวที่เพิ่ม
+async fn item_inserting(tx: &mut Transaction<'_, Postgres>, item: Items, auditing: &Auditing) -> Result<Items, sqlx::Error>{
+    let item = match sqlx::query_as::<_,Items>(
+        "INSERT INTO items (name, category, owner_id, weight, rarity, required_level, stats, attributes, attributes_schema_version, definition_id, durability, created_at, updated_at, name_key) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) RETURNING *;",
+    )
+    .bind(&item.name)                     // 🔗 Bind parameter: ป้องกัน SQL injection
+    .bind(item.category)                  // 🔗 Bind parameter: ประเภท
+    .bind(item.owner_id)                  // 🔗 Bind parameter: เจ้าของ
+    .bind(item.weight)                    // 🔗 Bind parameter: น้ำหนัก
+    .bind(item.rarity)                    // 🔗 Bind parameter: ความหายาก
+    .bind(item.required_level)            // 🔗 Bind parameter: เลเวลขั้นต่ำ
+    .bind(item.stats)                     // 🔗 Bind parameter: ค่าสถานะ (JSONB)
+    .bind(item.attributes)                // 🔗 Bind parameter: attributes (JSONB)
+    .bind(item.attributes_schema_version) // 🔗 Bind parameter: เวอร์ชัน schema
+    .bind(item.definition_id)             // 🔗 Bind parameter: แบบของ instance
+    .bind(item.durability)                // 🔗 Bind parameter: ความทนทาน
+    .bind(item.created_at)                // 🔗 Bind parameter: วันที่สร้าง
+    .bind(item.updated_at)                // 🔗 Bind parameter: วันที่อัปเดต
+    .bind(name_key(&item.name))           // 🔗 Bind parameter: key ของชื่อ (ใช้ตรวจชื่อซ้ำ)
+    .fetch_one(&mut **tx)                 // 🎯 ดึงข้อมูลแถวเดียวที่เพิ่งเพิ่ม
+    .await
+    {
+        Ok(item) => item,                 // ✅ สำเร็จ
+        Err(e) => {
+            error!("Failed to insert item: {:?}",e); // 📝 Log error
+            return Err(e);                // ❌ ส่ง error กลับ
+        }
+    };
+    // 🔍 ตรวจสอบว่าได้ ID กลับมาหรือไม่
+    let id = match item.id {
+        Some(id) => id,                   // ✅ ได้ ID แล้ว
+        None => {
+            error!("Failed to insert item: id is missing"); // 📝 Log error
+            return Err(sqlx::Error::RowNotFound); // ❌ ไม่ได้ ID (ไม่น่าเกิดขึ้น)
+        }
+    };
+
+    revision_inserting(tx, auditing.recording(id, None, Some(&item))).await?;
+    Ok(item)
+}
+
+// 🔤 คำสั่ง admin (src/bin/name_keys_rebuilding.rs): คำนวณ name_key ของทุกแถวใหม่ด้วย models::item::name_key แล้วสร้าง unique index ของชื่อใหม่
+// 📜 แถวที่ต้องเปลี่ยนชื่อ (normalize / ชื่อซ้ำได้ " #id") ขยับ version + updated_at และบันทึก revision ด้วย auditing
+// 🔒 ล็อกทั้งตารางจน commit - ผู้ดูแลสั่งเองครั้งเดียวหลังอัปเกรด ไม่รันตอนเริ่มแอป
+//    ส่งกลับจำนวนแถวที่เปลี่ยนชื่อ
+pub async fn name_keys_rebuilding(db_pool: &PgPool, auditing: Auditing) -> Result<u64, sqlx::Error>{
+    let mut tx = db_pool.begin().await?; // 🔒 เปลี่ยนชื่อ / revision / index พร้อมกัน
+    sqlx::query("LOCK TABLE items IN ACCESS EXCLUSIVE MODE;").execute(&mut *tx).await?;
+    sqlx::query("DROP INDEX items_owner_category_name_key;").execute(&mut *tx).await?; // 🔑 key เดิมอาจซ้ำกันระหว่างคำนวณใหม่
+
+    let items = match sqlx::query_as::<_,Items>(
+        "SELECT * FROM items ORDER BY id;", // 📊 SQL query - id น้อยสุดได้ชื่อเดิมเมื่อชื่อซ้ำ
+    )
+    .fetch_all(&mut *tx)              // 🎯 ทุกแถว (รวมที่ถูกลบ)
+    .await
+    {
+        Ok(items) => items,           // ✅ สำเร็จ
+        Err(e) => {
+            error!("Failed to fetch items for name key rebuilding: {:?}",e); // 📝 Log error
+            return Err(e);            // ❌ ส่ง error กลับ
+        }
+    };
+
+    let mut seen = HashSet::new();    // 🔑 (เจ้าของ, ประเภท, name_key) ที่ใช้ไปแล้ว - ตรงกับ unique index
+    let mut renamed = 0;
+    for before in items {
+        let id = before.id.unwrap_or_default();
+        let mut name = name_normalizing(&before.name);
+        // 🔁 ชื่อซ้ำหลัง normalize: ชิ้นแรกใช้ชื่อเดิม ชิ้นอื่นต่อท้ายด้วย " #id"
+        if before.definition_id.is_none() && before.deleted_at.is_none()
+            && !seen.insert((before.owner_id.unwrap_or(0), before.category.clone(), name_key(&name)))
+        {
+            name = format!("{} #{}", name, id);
+            seen.insert((before.owner_id.unwrap_or(0), before.category.clone(), name_key(&name)));
+        }
+
+        if name == before.name {
+            sqlx::query("UPDATE items SET name_key = $2 WHERE id = $1 AND name_key IS DISTINCT FROM $2;")
+                .bind(id)                 // 🔗 Bind parameter: ID ของ item
+                .bind(name_key(&name))    // 🔗 Bind parameter: key ของชื่อ
+                .execute(&mut *tx)
+                .await?;
+            continue;
+        }
+
+        // ✏️ ชื่อเปลี่ยน = การแก้ไขจริง: ขยับ version + updated_at และบันทึก revision "update"
+        let item = match sqlx::query_as::<_,Items>(
+            "UPDATE items SET name = $2, name_key = $3, updated_at = $4, version = version + 1 \
+             WHERE id = $1 RETURNING *;", // 📊 SQL query
+        )
+        .bind(id)                     // 🔗 Bind parameter: ID ของ item
+        .bind(&name)                  // 🔗 Bind parameter: ชื่อที่ normalize แล้ว
+        .bind(name_key(&name))        // 🔗 Bind parameter: key ของชื่อ
+        .bind(auditing.created_at)    // 🔗 Bind parameter: วันที่อัปเดต
+        .fetch_one(&mut *tx)          // 🎯 ดึงข้อมูลแถวที่อัปเดต
+        .await
+        {
+            Ok(item) => item,         // ✅ สำเร็จ
+            Err(e) => {
+                error!("Failed to rename item for name key rebuilding: {:?}",e); // 📝 Log error
+                return Err(e);        // ❌ ส่ง error กลับ
+            }
+        };
+        revision_inserting(&mut tx, auditing.recording(id, Some(&before), Some(&item))).await?;
+        renamed += 1;
+    }
+
+    sqlx::query(
+        "CREATE UNIQUE INDEX items_owner_category_name_key ON items (COALESCE(owner_id, 0), category, name_key) \
+         WHERE definition_id IS NULL AND deleted_at IS NULL;",
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;               // ✅ ยืนยันทั้งชื่อ / revision / index
+    Ok(renamed)
+}
+
+// 📜 เพิ่ม revision ถัดไปของ item ใน transaction ของการแก้ไข
+async fn revision_inserting(tx: &mut Transaction<'_, Postgres>, revision: ItemRevisions) -> Result<(), sqlx::Error>{
+    match sqlx::query(
+        "INSERT INTO item_revisions (item_id, revision, action, actor, request_id, before, after, diff, created_at) \
+         SELECT $1, COALESCE(MAX(revision), 0) + 1, $2, $3, $4, $5, $6, $7, $8 FROM item_revisions WHERE item_id = $1;", // 📊 SQL query
+    )
+    .bind(revision.item_id)               // 🔗 Bind parameter: ID ของ item
+    .bind(revision.action)                // 🔗 Bind parameter: ชนิดของการแก้ไข
+    .bind(revision.actor)                 // 🔗 Bind parameter: ผู้แก้ไข
+    .bind(revision.request_id)            // 🔗 Bind parameter: request id
+    .bind(revision.before)                // 🔗 Bind parameter: ภาพก่อน (JSONB)
+    .bind(revision.after)                 // 🔗 Bind parameter: ภาพหลัง (JSONB)
+    .bind(revision.diff)                  // 🔗 Bind parameter: field ที่เปลี่ยน (JSONB)
+    .bind(revision.created_at)            // 🔗 Bind parameter: เวลาที่แก้ไข
+    .execute(&mut **tx)                   // 🎯 รันคำสั่ง INSERT
+    .await
+    {
+        Ok(_) => Ok(()),                  // ✅ สำเร็จ
+        Err(e) => {
+            error!("Failed to insert item revision: {:?}",e); // 📝 Log error
+            Err(e)                        // ❌ ส่ง error กลับ
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// === 🧪 Test Module: Items Postgres Query Tests ===
+// 📖 ทดสอบ SQL ที่ filtered_query สร้างจาก ?filter= (ไม่ต่อฐานข้อมูล) และการ escape ค่าของ LIKE
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entities::items::ItemsFilter,
+        models::filter::filter_parsing,
+        repositories::items_postgres::{filtered_query, like_pattern},
+    };
+
+    // 🔎 SQL ส่วนที่ต่อท้าย FILTERED_ITEMS_SQL สำหรับ filter นี้ (bind ของ filter เริ่มที่ $10)
+    fn expression_sql(filter: &str) -> String {
+        let expression = match filter_parsing(filter) {
+            Ok(expression) => expression,
+            Err(_) => panic!("filter error: {}", filter),
+        };
+        let filter = ItemsFilter {
+            expression: Some(expression),
+            ..ItemsFilter::default()
+        };
+        let query = match filtered_query(filter, None) {
+            Ok(query) => query,
+            Err(e) => panic!("query error: {}", e),
+        };
+        let sql = query.sql();
+        match sql.find(" AND ((") {
+            Some(index) => sql[index..].to_string(),
+            None => panic!("expression missing: {}", sql),
+        }
+    }
+
+    // 🧪 ; = AND / , = OR / วงเล็บ / =in= / * = LIKE - ชื่อคอลัมน์จาก whitelist และค่าทุกตัวเป็น bind parameter
+    #[test]
+    fn filtered_query_test() {
+        assert_eq!(
+            expression_sql("category==Staff;(name==Fire*,required_level=ge=10)"),
+            " AND ((category = $10) AND ((name LIKE $11) OR (required_level >= $12))) ORDER BY id;"
+        );
+        assert_eq!(
+            expression_sql("rarity=out=(Common,Rare);name!=*Ice*"),
+            " AND ((rarity NOT IN ($10, $11)) AND (name NOT LIKE $12)) ORDER BY id;"
+        );
+    }
+
+    // 🧪 ค่าของ LIKE: * เป็น % / \ % _ ที่ผู้ใช้พิมพ์เป็นตัวอักษรธรรมดา
+    #[test]
+    fn like_pattern_test() {
+        assert_eq!(like_pattern("Fire*"), "Fire%");
+        assert_eq!(like_pattern("*100%_off*"), "%100\\%\\_off%");
+        assert_eq!(like_pattern("C:\\Staff*"), "C:\\\\Staff%");
+    }
+}
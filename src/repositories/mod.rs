@@ -1,2 +1,12 @@
+pub mod categories;
+pub mod categories_postgres;
+pub mod item_definitions;
+pub mod item_definitions_postgres;
 pub mod items;
-pub mod staff;
\ No newline at end of file
+pub mod items_postgres;
+pub mod items_postgres_test;
+pub mod players;
+pub mod players_postgres;
+pub mod staff;
+pub mod unit_of_work;
+pub mod unit_of_work_postgres;
@@ -0,0 +1,40 @@
+// === 📋 Repository Interface: Players ===
+// 📖 กำหนด Interface สำหรับการเข้าถึงข้อมูลผู้เล่น และสร้าง Mock สำหรับการทดสอบ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 4️⃣ Interface Segregation Principle (ISP):
+//    แยกจาก ItemsRepository - usecase ที่ไม่ยุ่งกับผู้เล่นไม่ต้องรู้จัก trait นี้
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    PlayersUsecase ขึ้นอยู่กับ trait นี้แทน concrete implementation
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use mockall::automock;
+
+use crate::entities::players::Players;
+
+// 🔗 Type alias สำหรับ shared Repository
+pub type SharedPlayersRepository = Arc<dyn PlayersRepository + Send + Sync>;
+
+#[async_trait]
+#[automock]
+pub trait PlayersRepository {
+    // 🔍 ค้นหาผู้เล่นตามชื่อ
+    async fn find_by_name(&self, name: String) -> Result<Players, sqlx::Error>;
+
+    // ➕ เพิ่มผู้เล่นใหม่ ส่งกลับข้อมูลที่เพิ่ม
+    async fn insert(&self, player: Players) -> Result<Players, sqlx::Error>;
+
+    // 🔍 ค้นหาผู้เล่นตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<Players, sqlx::Error>;
+
+    // 🔒 ค้นหาผู้เล่นตาม ID และล็อกแถวไว้จนจบ transaction (SELECT ... FOR UPDATE)
+    // 🎒 ใช้กับ repository ของ UnitOfWork ก่อนอ่านกระเป๋า - request ที่เพิ่ม item ให้ผู้เล่นคนเดียวกันต้องรอกัน
+    async fn find_for_update(&self, id: i32) -> Result<Players, sqlx::Error>;
+
+    // 🏅 เปลี่ยน tier ของผู้เล่น ส่งกลับข้อมูลหลังแก้ (ไม่พบ = RowNotFound)
+    async fn update_tier(&self, id: i32, tier: String, updated_at: NaiveDateTime) -> Result<Players, sqlx::Error>;
+}
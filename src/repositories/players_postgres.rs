@@ -0,0 +1,132 @@
+// === 💾 Infrastructure Layer: Players Repository (PostgreSQL) ===
+// 📖 จัดการการเข้าถึงข้อมูลผู้เล่นในฐานข้อมูล - เป็น concrete implementation ของ PlayersRepository
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 3️⃣ Liskov Substitution Principle (LSP):
+//    PlayersPostgresRepository ใช้แทน PlayersRepository interface ได้อย่างสมบูรณ์
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ abstraction (PlayersRepository trait) ไม่ใช่ concrete classes
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::entities::players::Players;
+
+use super::{
+    players::{PlayersRepository, SharedPlayersRepository},
+    unit_of_work_postgres::{PgConnectionGuard, SharedTransaction, connection_acquiring},
+};
+
+// 📦 PlayersPostgresRepository struct: จัดการข้อมูลผู้เล่นในฐานข้อมูล
+pub struct PlayersPostgresRepository {
+    db_pool: PgPool,                  // 🗄️ Database connection pool สำหรับ PostgreSQL
+    transaction: Option<SharedTransaction>, // 🔒 transaction ของ UnitOfWork (None = ใช้ pool)
+}
+
+impl PlayersPostgresRepository {
+    // 🏗️ Factory method: สร้าง Repository และ wrap ด้วย Arc สำหรับ shared ownership
+    pub fn creation(db_pool: PgPool) -> SharedPlayersRepository {
+        Arc::new(Self { db_pool, transaction: None })
+    }
+
+    // 🏗️ Factory method: Repository ที่อ่าน/เขียนผ่าน transaction ของ UnitOfWork
+    pub fn bound(db_pool: PgPool, transaction: SharedTransaction) -> SharedPlayersRepository {
+        Arc::new(Self { db_pool, transaction: Some(transaction) })
+    }
+
+    // 🔌 connection สำหรับหนึ่งคำสั่ง (transaction ของ UnitOfWork หรือจาก pool)
+    async fn connection(&self) -> Result<PgConnectionGuard, sqlx::Error> {
+        connection_acquiring(&self.db_pool, self.transaction.as_ref()).await
+    }
+}
+
+#[async_trait]
+impl PlayersRepository for PlayersPostgresRepository {
+    // 🔍 ค้นหาผู้เล่นตามชื่อ
+    async fn find_by_name(&self, name: String) -> Result<Players, sqlx::Error> {
+        match sqlx::query_as::<_, Players>("SELECT * FROM players WHERE name = $1;")
+            .bind(name)
+            .fetch_one(&mut *self.connection().await?)
+            .await
+        {
+            Ok(player) => Ok(player),
+            Err(e) => {
+                error!("Failed to find player by name: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // ➕ เพิ่มผู้เล่นใหม่ และส่งกลับแถวที่เพิ่ม
+    async fn insert(&self, player: Players) -> Result<Players, sqlx::Error> {
+        match sqlx::query_as::<_, Players>(
+            "INSERT INTO players (name, tier, created_at, updated_at) VALUES ($1, $2, $3, $4) RETURNING *;",
+        )
+        .bind(player.name)
+        .bind(player.tier)
+        .bind(player.created_at)
+        .bind(player.updated_at)
+        .fetch_one(&mut *self.connection().await?)
+        .await
+        {
+            Ok(player) => Ok(player),
+            Err(e) => {
+                error!("Failed to insert player: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🔍 ค้นหาผู้เล่นตาม ID
+    async fn find_by_id(&self, id: i32) -> Result<Players, sqlx::Error> {
+        match sqlx::query_as::<_, Players>("SELECT * FROM players WHERE id = $1;")
+            .bind(id)
+            .fetch_one(&mut *self.connection().await?)
+            .await
+        {
+            Ok(player) => Ok(player),
+            Err(e) => {
+                error!("Failed to find player by id: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🔒 ค้นหาผู้เล่นตาม ID และล็อกแถว (มีผลเมื่ออยู่ใน transaction ของ UnitOfWork)
+    async fn find_for_update(&self, id: i32) -> Result<Players, sqlx::Error> {
+        match sqlx::query_as::<_, Players>("SELECT * FROM players WHERE id = $1 FOR UPDATE;")
+            .bind(id)
+            .fetch_one(&mut *self.connection().await?)
+            .await
+        {
+            Ok(player) => Ok(player),
+            Err(e) => {
+                error!("Failed to lock player: {}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+
+    // 🏅 เปลี่ยน tier ของผู้เล่น และส่งกลับแถวหลังแก้
+    async fn update_tier(&self, id: i32, tier: String, updated_at: NaiveDateTime) -> Result<Players, sqlx::Error> {
+        match sqlx::query_as::<_, Players>("UPDATE players SET tier = $2, updated_at = $3 WHERE id = $1 RETURNING *;")
+            .bind(id)
+            .bind(tier)
+            .bind(updated_at)
+            .fetch_one(&mut *self.connection().await?)
+            .await
+        {
+            Ok(player) => Ok(player),
+            Err(e) => {
+                error!("Failed to update player tier: {:?}", e); // 📝 Log error
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// === 📋 Repository Interface: Unit of Work ===
+// 📖 เปิด transaction เดียวให้ usecase ที่ต้องทำหลายขั้นตอน (อ่าน -> ตรวจ -> เขียน) แบบสำเร็จหรือไม่สำเร็จพร้อมกัน
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    UnitOfWork ดูแลแค่ขอบเขตของ transaction - การอ่าน/เขียนข้อมูลยังอยู่ที่ repository เดิม
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    usecase ขึ้นอยู่กับ trait นี้ ไม่รู้ว่าเบื้องหลังเป็น PostgreSQL transaction หรือ Mock
+//    ตอนนี้มีเฉพาะ implementation ของ PostgreSQL (ยังไม่มี backend in-memory) - test ใช้ Mock
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use mockall::automock;
+
+use super::{items::SharedItemsRepository, players::SharedPlayersRepository};
+
+// 🔗 Type alias สำหรับ shared UnitOfWork / transaction ที่เปิดอยู่
+pub type SharedUnitOfWork = Arc<dyn UnitOfWork + Send + Sync>;
+pub type BoxedTransactionScope = Box<dyn TransactionScope + Send + Sync>;
+
+// 🏭 UnitOfWork: เปิด transaction ใหม่
+#[async_trait]
+#[automock]
+pub trait UnitOfWork {
+    // 🔒 เริ่ม transaction - ต้อง commit เอง ไม่เช่นนั้นจะ rollback เมื่อ scope ถูก drop
+    async fn begin(&self) -> Result<BoxedTransactionScope, sqlx::Error>;
+}
+
+// 🔒 TransactionScope: repository ที่ผูกกับ transaction เดียวกัน
+// 📜 การแก้ไขของ repository ข้างในยังบันทึก revision ตามปกติ (เป็น savepoint ภายใน transaction นี้)
+#[async_trait]
+#[automock]
+pub trait TransactionScope {
+    // 💾 ItemsRepository ที่อ่าน/เขียนผ่าน transaction นี้
+    fn items(&self) -> SharedItemsRepository;
+
+    // 💾 PlayersRepository ที่อ่าน/เขียนผ่าน transaction นี้
+    fn players(&self) -> SharedPlayersRepository;
+
+    // ✅ ยืนยันทุกการเปลี่ยนแปลง
+    async fn commit(&self) -> Result<(), sqlx::Error>;
+
+    // ↩️ ยกเลิกทุกการเปลี่ยนแปลง
+    async fn rollback(&self) -> Result<(), sqlx::Error>;
+}
@@ -0,0 +1,137 @@
+// === 💾 Infrastructure Layer: Unit of Work (PostgreSQL) ===
+// 📖 transaction ของ PostgreSQL ที่แชร์ให้ ItemsPostgresRepository / PlayersPostgresRepository ใช้ร่วมกัน
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 3️⃣ Liskov Substitution Principle (LSP):
+//    repository ที่ผูกกับ transaction ใช้แทน repository ปกติได้ทุก method
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    usecase เห็นแค่ UnitOfWork / TransactionScope trait
+
+use std::{ops::Deref, sync::Arc};
+
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction, pool::PoolConnection};
+use tokio::sync::{Mutex, OwnedMappedMutexGuard, OwnedMutexGuard};
+use tracing::error;
+
+use super::{
+    items::SharedItemsRepository,
+    items_postgres::ItemsPostgresRepository,
+    players::SharedPlayersRepository,
+    players_postgres::PlayersPostgresRepository,
+    unit_of_work::{BoxedTransactionScope, SharedUnitOfWork, TransactionScope, UnitOfWork},
+};
+
+// 🔗 transaction ที่ repository หลายตัวใช้ร่วมกัน (None = commit / rollback ไปแล้ว)
+pub type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+// 🔌 connection ที่ repository ใช้รันคำสั่งหนึ่งครั้ง: จาก pool หรือจาก transaction ของ UnitOfWork
+pub enum PgConnectionGuard {
+    Pooled(PoolConnection<Postgres>),
+    Bound(OwnedMappedMutexGuard<Option<Transaction<'static, Postgres>>, Transaction<'static, Postgres>>),
+}
+
+impl Deref for PgConnectionGuard {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Bound(transaction) => transaction,
+        }
+    }
+}
+
+impl std::ops::DerefMut for PgConnectionGuard {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Bound(transaction) => transaction,
+        }
+    }
+}
+
+// 🔌 ขอ connection: มี transaction = ใช้ connection ของ transaction นั้น (ถือไว้จนจบคำสั่ง)
+// 🔒 begin() บน connection ที่อยู่ใน transaction แล้วจะเป็น SAVEPOINT ของ transaction เดิม
+pub async fn connection_acquiring(
+    db_pool: &PgPool,
+    transaction: Option<&SharedTransaction>,
+) -> Result<PgConnectionGuard, sqlx::Error> {
+    let transaction = match transaction {
+        Some(transaction) => Arc::clone(transaction),
+        None => return Ok(PgConnectionGuard::Pooled(db_pool.acquire().await?)),
+    };
+
+    match OwnedMutexGuard::try_map(transaction.lock_owned().await, |transaction| transaction.as_mut()) {
+        Ok(transaction) => Ok(PgConnectionGuard::Bound(transaction)),
+        Err(_) => Err(finished_error()),
+    }
+}
+
+// 📦 PostgresUnitOfWork: เปิด transaction จาก pool
+pub struct PostgresUnitOfWork {
+    db_pool: PgPool,
+}
+
+impl PostgresUnitOfWork {
+    // 🏗️ Factory method: สร้าง UnitOfWork และ wrap ด้วย Arc สำหรับ shared ownership
+    pub fn creation(db_pool: PgPool) -> SharedUnitOfWork {
+        Arc::new(Self { db_pool })
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn begin(&self) -> Result<BoxedTransactionScope, sqlx::Error> {
+        let transaction = match self.db_pool.begin().await {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                error!("Failed to begin unit of work: {}", e); // 📝 Log error
+                return Err(e);
+            }
+        };
+
+        Ok(Box::new(PostgresTransactionScope {
+            db_pool: self.db_pool.clone(),
+            transaction: Arc::new(Mutex::new(Some(transaction))),
+        }))
+    }
+}
+
+// 🔒 PostgresTransactionScope: transaction ที่เปิดอยู่ - drop โดยไม่ commit = rollback
+struct PostgresTransactionScope {
+    db_pool: PgPool,
+    transaction: SharedTransaction,
+}
+
+#[async_trait]
+impl TransactionScope for PostgresTransactionScope {
+    fn items(&self) -> SharedItemsRepository {
+        ItemsPostgresRepository::bound(self.db_pool.clone(), Arc::clone(&self.transaction))
+    }
+
+    fn players(&self) -> SharedPlayersRepository {
+        PlayersPostgresRepository::bound(self.db_pool.clone(), Arc::clone(&self.transaction))
+    }
+
+    async fn commit(&self) -> Result<(), sqlx::Error> {
+        let transaction = self.transaction.lock().await.take().ok_or_else(finished_error)?;
+        if let Err(e) = transaction.commit().await {
+            error!("Failed to commit unit of work: {}", e); // 📝 Log error
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    async fn rollback(&self) -> Result<(), sqlx::Error> {
+        let transaction = self.transaction.lock().await.take().ok_or_else(finished_error)?;
+        transaction.rollback().await
+    }
+}
+
+// ❌ ใช้ transaction ที่ commit / rollback ไปแล้ว (bug ของ usecase)
+fn finished_error() -> sqlx::Error {
+    sqlx::Error::Protocol("unit of work is already committed or rolled back".to_string())
+}
@@ -0,0 +1,43 @@
+// === 🎲 Roll Helper ===
+// 📖 สุ่มค่าสถานะของ instance จากค่าตั้งต้นของแบบ (definition)
+//    แยกเป็น trait เหมือน time_helper เพื่อให้ test กำหนดผลการสุ่มได้ (Mock = ค่าตั้งต้นเสมอ)
+
+use std::sync::Arc;
+
+use mockall::automock;
+use rand::Rng;
+
+pub type IntoRollHelperShared = Arc<dyn IntoRollHelper + Send + Sync>;
+
+#[automock]
+pub trait IntoRollHelper {
+    // 🎲 สุ่มค่าในช่วง base ± variance% (variance 0 = base)
+    fn roll(&self, base: i32, variance: i32) -> i32;
+}
+
+pub enum RollHelper {
+    Random,
+    Mock,
+}
+
+impl RollHelper {
+    pub fn creation(&self) -> IntoRollHelperShared {
+        match self {
+            Self::Random => Arc::new(Self::Random),
+            Self::Mock => Arc::new(Self::Mock),
+        }
+    }
+}
+
+impl IntoRollHelper for RollHelper {
+    fn roll(&self, base: i32, variance: i32) -> i32 {
+        match self {
+            Self::Random => {
+                let spread = i64::from(base).abs() * i64::from(variance.clamp(0, 100)) / 100;
+                let rolled = rand::thread_rng().gen_range(i64::from(base) - spread..=i64::from(base) + spread);
+                rolled.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+            }
+            Self::Mock => base,
+        }
+    }
+}
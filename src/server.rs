@@ -0,0 +1,125 @@
+// === 🚀 Server Listener ===
+// 📖 เลือกช่องทางที่ server รับ connection: socket activation (systemd/listenfd), Unix domain socket หรือ TCP (+TLS)
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ไฟล์นี้ดูแลเฉพาะการ bind และ serve - router ถูกประกอบมาจาก main.rs แล้ว
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เปลี่ยนวิธี listen ได้จาก Setting.toml โดยไม่แก้ router หรือ handler
+
+use std::{io, net::SocketAddr, os::unix::fs::FileTypeExt};
+
+use axum::Router;
+use listenfd::ListenFd;
+use tokio::net::{TcpListener, UnixListener};
+use tracing::{error, info};
+
+use crate::{
+    setting::{Server, Tls},
+    tls,
+};
+
+// 🎧 listener ที่เลือกแล้ว (ยังเป็น std listener - แปลงเป็น tokio ตอน serve)
+#[derive(Debug)]
+pub enum Listener {
+    Tcp(std::net::TcpListener),
+    Unix(std::os::unix::net::UnixListener),
+}
+
+// 🚀 เริ่ม server ด้วย listener ที่ listener_selecting เลือก
+pub async fn serving(setting: &Server, app: Router) -> io::Result<()> {
+    match listener_selecting(activated_taking(&mut ListenFd::from_env())?, setting)? {
+        Listener::Tcp(listener) => tcp_serving(setting, listener, app).await,
+        Listener::Unix(listener) => {
+            listener.set_nonblocking(true)?;
+            unix_serving(setting, UnixListener::from_std(listener)?, app).await
+        }
+    }
+}
+
+// 🔌 socket ที่ systemd/listenfd ส่งมาให้ (LISTEN_FDS) - TCP ก่อน Unix socket
+fn activated_taking(listenfd: &mut ListenFd) -> io::Result<Option<Listener>> {
+    if let Some(listener) = listenfd.take_tcp_listener(0)? {
+        info!("Using TCP socket from socket activation");
+        return Ok(Some(Listener::Tcp(listener)));
+    }
+    if let Some(listener) = listenfd.take_unix_listener(0)? {
+        info!("Using Unix socket from socket activation");
+        return Ok(Some(Listener::Unix(listener)));
+    }
+    Ok(None)
+}
+
+// 🧭 เลือก listener ตามลำดับความสำคัญ:
+// 1️⃣ socket ที่ systemd/listenfd ส่งมาให้ (activated)
+// 2️⃣ Unix domain socket จาก server.unix_socket
+// 3️⃣ TCP ที่ server.host:server.port
+pub fn listener_selecting(activated: Option<Listener>, setting: &Server) -> io::Result<Listener> {
+    if let Some(listener) = activated {
+        return Ok(listener);
+    }
+
+    if let Some(path) = &setting.unix_socket {
+        // 🧹 ลบ socket file ที่ค้างจากรอบก่อน ไม่เช่นนั้น bind จะ error
+        // ⚠️ ลบเฉพาะ socket จริง (ไม่ตาม symlink) - ไฟล์อื่นที่ path เดียวกันไม่แตะและหยุดทำงาน
+        match std::fs::symlink_metadata(path) {
+            Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)?,
+            Ok(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("server.unix_socket {} exists and is not a socket", path),
+                ));
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        let listener = std::os::unix::net::UnixListener::bind(path)?;
+        info!("Server running on unix socket {}", path);
+        return Ok(Listener::Unix(listener));
+    }
+
+    Ok(Listener::Tcp(std::net::TcpListener::bind(SocketAddr::new(setting.host, setting.port))?))
+}
+
+// 🌐 Serve บน TCP - เปิด HTTPS ถ้ามี [server.tls]
+async fn tcp_serving(setting: &Server, listener: std::net::TcpListener, app: Router) -> io::Result<()> {
+    listener.set_nonblocking(true)?;
+    let addr = listener.local_addr()?;
+    let service = app.into_make_service_with_connect_info::<SocketAddr>(); // 🔑 ให้ middleware รู้ IP ของ client
+
+    // 🔐 มี [server.tls] -> เปิด HTTPS ด้วย rustls แทน TCP ธรรมดา
+    if let Some(tls_setting) = &setting.tls {
+        let rustls_config = tls::config_creation(tls_setting)?;
+        tls::reloading(rustls_config.clone(), tls_setting.clone()); // 🔄 reload cert เมื่อไฟล์เปลี่ยน
+        redirect_spawning(setting, tls_setting, addr.port());
+
+        info!("Server running on {} (TLS)", addr);
+        return axum_server::from_tcp_rustls(listener, rustls_config).serve(service).await;
+    }
+
+    info!("Server running on {}", addr);
+    axum::serve(TcpListener::from_std(listener)?, service).await
+}
+
+// 🧦 Serve บน Unix domain socket - ไม่มี peer IP ให้ rate limit จึงใช้ header จาก reverse proxy แทน
+async fn unix_serving(setting: &Server, listener: UnixListener, app: Router) -> io::Result<()> {
+    if setting.tls.is_some() {
+        // ⚠️ TLS ควรจบที่ reverse proxy เมื่อใช้ Unix socket
+        error!("[server.tls] is ignored when listening on a Unix socket");
+    }
+    axum::serve(listener, app.into_make_service()).await
+}
+
+// ↪️ เปิด HTTP port แยกสำหรับ redirect ไป HTTPS (ถ้ากำหนด redirect_http_port)
+fn redirect_spawning(setting: &Server, tls_setting: &Tls, https_port: u16) {
+    if let Some(http_port) = tls_setting.redirect_http_port {
+        let http_addr = SocketAddr::new(setting.host, http_port);
+        tokio::spawn(async move {
+            if let Err(e) = tls::redirecting(http_addr, https_port).await {
+                error!("HTTP redirect server stopped: {}", e);
+            }
+        });
+    }
+}
@@ -0,0 +1,82 @@
+// === 🧪 Test Module: Server Listener Tests ===
+// 📖 ทดสอบลำดับการเลือก listener: socket activation -> Unix socket -> TCP
+
+#[cfg(test)]
+mod tests {
+    use std::{net::IpAddr, os::unix::fs::FileTypeExt, path::PathBuf};
+
+    use crate::{
+        server::{Listener, listener_selecting},
+        setting::Server,
+    };
+
+    fn server(unix_socket: Option<&PathBuf>) -> Server {
+        Server {
+            host: IpAddr::from([127, 0, 0, 1]),
+            port: 0, // 🔢 ให้ OS เลือก port ว่าง
+            unix_socket: unix_socket.map(|path| path.to_string_lossy().to_string()),
+            request_timeout_secs: 30,
+            route_timeouts: Vec::new(),
+            max_body_bytes: 1024,
+            tls: None,
+        }
+    }
+
+    fn socket_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("server-test-{}-{}.sock", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    // 🧪 socket activation มาก่อน server.unix_socket (ไม่ bind path นั้นเลย)
+    #[test]
+    fn activated_first_test() {
+        let path = socket_path("activated");
+        let activated = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = activated.local_addr().unwrap();
+
+        match listener_selecting(Some(Listener::Tcp(activated)), &server(Some(&path))).unwrap() {
+            Listener::Tcp(listener) => assert_eq!(listener.local_addr().unwrap(), addr),
+            listener => panic!("expected activated TCP listener, got {:?}", listener),
+        }
+        assert!(!path.exists());
+    }
+
+    // 🧪 ไม่มี socket activation: server.unix_socket มาก่อน TCP และแทนที่ socket ที่ค้างจากรอบก่อน
+    #[test]
+    fn unix_socket_test() {
+        let path = socket_path("unix");
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap()); // 🧹 socket file ที่ค้างอยู่
+
+        match listener_selecting(None, &server(Some(&path))).unwrap() {
+            Listener::Unix(listener) => {
+                let addr = listener.local_addr().unwrap();
+                assert_eq!(addr.as_pathname(), Some(path.as_path()));
+            }
+            listener => panic!("expected Unix listener, got {:?}", listener),
+        }
+        assert!(std::fs::symlink_metadata(&path).unwrap().file_type().is_socket());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // 🧪 ไฟล์อื่นที่ path ของ server.unix_socket ไม่ถูกลบ และได้ error
+    #[test]
+    fn unix_socket_not_socket_test() {
+        let path = socket_path("regular");
+        std::fs::write(&path, "keep").unwrap();
+
+        let error = listener_selecting(None, &server(Some(&path))).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // 🧪 ไม่มีทั้งสองอย่าง: bind TCP ที่ server.host:server.port
+    #[test]
+    fn tcp_fallback_test() {
+        match listener_selecting(None, &server(None)).unwrap() {
+            Listener::Tcp(listener) => assert_eq!(listener.local_addr().unwrap().ip(), IpAddr::from([127, 0, 0, 1])),
+            listener => panic!("expected TCP listener, got {:?}", listener),
+        }
+    }
+}
@@ -1,9 +1,34 @@
-use config::Config;
-use std::sync::Arc;
+use config::{Config, ConfigError};
+use serde::Deserialize;
+use std::{collections::HashMap, net::IpAddr, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub struct Server {
-    pub port: i64,
+    pub host: IpAddr,                    // 🌐 address ที่ bind (IPv4 หรือ IPv6)
+    pub port: u16,                       // 🔢 port ที่ผ่านการตรวจสอบแล้ว (1-65535)
+    pub unix_socket: Option<String>,     // 🧦 ถ้ากำหนด จะ listen บน Unix domain socket แทน TCP
+    pub request_timeout_secs: u64,       // ⏱️ timeout เริ่มต้นของทุก route
+    pub route_timeouts: Vec<RouteTimeout>, // 🛣️ timeout เฉพาะ route (แทนที่ค่าเริ่มต้น)
+    pub max_body_bytes: usize,           // 📦 ขนาด request body สูงสุด
+    pub tls: Option<Tls>,                // 🔐 เปิด HTTPS เมื่อมี [server.tls]
+}
+
+// 🔐 ตั้งค่า TLS (rustls) สำหรับ deployment ที่ไม่มี ingress
+#[derive(Debug, Clone)]
+pub struct Tls {
+    pub cert_path: String,                // 📜 certificate chain (PEM)
+    pub key_path: String,                 // 🔑 private key (PEM)
+    pub client_ca_path: Option<String>,   // 🪪 CA สำหรับตรวจ client certificate (mTLS)
+    pub redirect_http_port: Option<u16>,  // ↪️ port HTTP ที่ redirect ไป HTTPS
+    pub reload_interval_secs: u64,        // 🔄 ความถี่ในการตรวจว่าไฟล์ cert เปลี่ยนหรือไม่
+}
+
+// ⏱️ Timeout ของ route ใด route หนึ่ง
+#[derive(Debug, Clone, Deserialize)]
+pub struct RouteTimeout {
+    pub method: String,
+    pub path: String,
+    pub timeout_secs: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -16,10 +41,96 @@ pub struct Database {
     pub schema: String,
 }
 
+// 🚦 ตั้งค่าการจำกัดจำนวน request (rate limit) และ concurrency
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub max_concurrent_requests: usize, // 🔢 จำนวน request ที่ทำงานพร้อมกันได้สูงสุด (ป้องกัน sqlx pool)
+    pub routes: Vec<RouteRateLimit>,    // 🛣️ token bucket ราย route
+    pub api_keys: Vec<ApiKey>,          // 🔑 API key ที่รู้จัก - client ที่ส่ง X-API-Key ตรงได้ bucket ตามชื่อแทน IP
+    pub jwt_secret: Option<String>,     // 🎫 secret ของ JWT (HS256) - token ที่ตรวจผ่านได้ bucket ตาม sub แทน IP
+}
+
+// 🔑 API key หนึ่งตัว: name ใช้เป็น key ของ bucket (ไม่ใช้ตัว key ใน log / bucket)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKey {
+    pub name: String,
+    pub key: String,
+}
+
+// 🪣 Token bucket ของแต่ละ route: เติม refill_per_second token ต่อวินาที เก็บได้สูงสุด capacity
+#[derive(Debug, Clone, Deserialize)]
+pub struct RouteRateLimit {
+    pub method: String,
+    pub path: String,
+    pub capacity: u32,
+    pub refill_per_second: f64,
+}
+
+// 🎒 ตั้งค่า limit ของกระเป๋าผู้เล่นตามระดับ (tier)
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    pub default_tier: String,                      // 🏅 tier ของผู้เล่นใหม่ / ใช้เมื่อ tier ของผู้เล่นไม่มีในรายการ
+    pub tiers: HashMap<String, InventoryTier>,     // 🏅 tier -> limit
+}
+
+// 📏 limit ของกระเป๋าหนึ่ง tier
+#[derive(Debug, Clone, Deserialize)]
+pub struct InventoryTier {
+    pub max_slots: usize,  // 🔢 จำนวน item สูงสุด
+    pub max_weight: i64,   // ⚖️ น้ำหนักรวมสูงสุด
+}
+
+// 🗑️ ตั้งค่าการเก็บ item ที่ถูกลบ (soft delete) ก่อน purge job ลบจริง
+#[derive(Debug, Clone)]
+pub struct Trash {
+    pub retention_days: i64,        // 📅 เก็บ item ที่ถูกลบไว้กู้คืนได้กี่วัน
+    pub purge_interval_secs: u64,   // 🧹 ความถี่ในการรัน purge job
+}
+
+// 📥 ตั้งค่าการนำเข้า item ทีละมาก ๆ (POST /items/import)
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub batch_size: usize,          // 📦 จำนวนแถวต่อ transaction
+    pub max_body_bytes: usize,      // 📦 ขนาดไฟล์นำเข้าสูงสุด (แทน server.max_body_bytes เฉพาะ route นี้)
+}
+
+// 🔑 ตั้งค่า Idempotency-Key ของ POST: เก็บ response แรกไว้ตอบซ้ำเมื่อ client retry
+#[derive(Debug, Clone)]
+pub struct Idempotency {
+    pub ttl_secs: u64,              // ⏰ เก็บ response ไว้นานเท่าไร (หลังจากนั้นใช้ key เดิมได้ใหม่)
+    pub max_response_bytes: usize,  // 📦 response ที่ใหญ่กว่านี้ไม่เก็บ (retry จะทำงานซ้ำ)
+    pub max_entries: usize,         // 🧹 key ทั้งหมดที่จำได้ - เกินแล้วลืม key ที่ไม่ได้ใช้นานที่สุด (LRU)
+    pub max_total_bytes: usize,     // 📦 ขนาด response ที่เก็บรวมกันทุก client - เกินแล้วลืม key ที่ไม่ได้ใช้นานที่สุด
+    pub max_keys_per_client: usize, // 👤 key ที่ client หนึ่งรายจำได้ - เกินแล้วลืม key ที่เสร็จแล้วเก่าสุดของ client นั้น
+}
+
+// 📦 ตั้งค่า batch endpoint: จำนวน id / operation สูงสุดต่อ request
+#[derive(Debug, Clone)]
+pub struct Batch {
+    pub max_ids: usize,             // 🔍 POST /items/batch-get
+    pub max_operations: usize,      // ✏️ POST /items/batch
+}
+
+// 🔎 ตั้งค่าการค้นหาชื่อ item (GET /items/search)
+#[derive(Debug, Clone)]
+pub struct Search {
+    pub default_limit: usize,       // 🔢 จำนวนผลลัพธ์เมื่อไม่ส่ง limit
+    pub max_limit: usize,           // 🔢 limit สูงสุดที่ขอได้
+    pub max_query_chars: usize,     // 🔤 ความยาวคำค้นสูงสุด (ตัวอักษร)
+    pub min_similarity: f32,        // 📏 ความใกล้เคียงขั้นต่ำของชื่อที่สะกดผิด (0-1)
+}
+
 #[derive(Debug, Clone)]
 pub struct Setting {
     pub server: Server,
     pub database: Database,
+    pub rate_limit: RateLimit,
+    pub inventory: Inventory,
+    pub trash: Trash,
+    pub import: Import,
+    pub idempotency: Idempotency,
+    pub batch: Batch,
+    pub search: Search,
 }
 
 impl Setting {
@@ -31,7 +142,32 @@ impl Setting {
 
         Ok(Arc::new(Self {
             server: Server {
-                port: settings.get_int("server.port").unwrap(),
+                host: match settings.get_string("server.host") {
+                    Ok(host) => host.parse().map_err(|_| {
+                        ConfigError::Message(format!("server.host is not an IP address: {}", host))
+                    })?,
+                    Err(_) => IpAddr::from([0, 0, 0, 0]),
+                },
+                port: port_validating("server.port", settings.get_int("server.port")?)?,
+                unix_socket: settings.get_string("server.unix_socket").ok(),
+                request_timeout_secs: count_getting(&settings, "server.request_timeout_secs", 30, 0)?,
+                route_timeouts: settings
+                    .get::<Vec<RouteTimeout>>("server.route_timeouts")
+                    .unwrap_or_default(),
+                max_body_bytes: count_getting(&settings, "server.max_body_bytes", 64 * 1024, 0)?,
+                tls: match settings.get_string("server.tls.cert_path") {
+                    Ok(cert_path) => Some(Tls {
+                        cert_path,
+                        key_path: settings.get_string("server.tls.key_path")?,
+                        client_ca_path: settings.get_string("server.tls.client_ca_path").ok(),
+                        redirect_http_port: match settings.get_int("server.tls.redirect_http_port") {
+                            Ok(port) => Some(port_validating("server.tls.redirect_http_port", port)?),
+                            Err(_) => None,
+                        },
+                        reload_interval_secs: count_getting(&settings, "server.tls.reload_interval_secs", 30, 0)?,
+                    }),
+                    Err(_) => None,
+                },
             },
             database: Database {
                 host: settings.get_string("database.host").unwrap(),
@@ -41,10 +177,78 @@ impl Setting {
                 dbname: settings.get_string("database.dbname").unwrap(),
                 schema: settings.get_string("database.schema").unwrap(),
             },
+            rate_limit: RateLimit {
+                max_concurrent_requests: count_getting(&settings, "rate_limit.max_concurrent_requests", 64, 0)?,
+                routes: settings
+                    .get::<Vec<RouteRateLimit>>("rate_limit.routes")
+                    .unwrap_or_default(),
+                api_keys: settings.get::<Vec<ApiKey>>("rate_limit.api_keys").unwrap_or_default(),
+                jwt_secret: settings.get_string("rate_limit.jwt_secret").ok(),
+            },
+            inventory: inventory_validating(Inventory {
+                default_tier: settings
+                    .get_string("inventory.default_tier")
+                    .unwrap_or_else(|_| "basic".to_string()),
+                tiers: settings.get::<HashMap<String, InventoryTier>>("inventory.tiers")?,
+            })?,
+            trash: Trash {
+                retention_days: settings.get_int("trash.retention_days").unwrap_or(30),
+                purge_interval_secs: count_getting(&settings, "trash.purge_interval_secs", 3600, 0)?,
+            },
+            import: Import {
+                batch_size: count_getting(&settings, "import.batch_size", 500, 1)?,
+                max_body_bytes: count_getting(&settings, "import.max_body_bytes", 10 * 1024 * 1024, 0)?,
+            },
+            idempotency: Idempotency {
+                ttl_secs: count_getting(&settings, "idempotency.ttl_secs", 86400, 0)?,
+                max_response_bytes: count_getting(&settings, "idempotency.max_response_bytes", 1024 * 1024, 0)?,
+                max_entries: count_getting(&settings, "idempotency.max_entries", 10_000, 1)?,
+                max_total_bytes: count_getting(&settings, "idempotency.max_total_bytes", 64 * 1024 * 1024, 0)?,
+                max_keys_per_client: count_getting(&settings, "idempotency.max_keys_per_client", 100, 1)?,
+            },
+            batch: Batch {
+                max_ids: count_getting(&settings, "batch.max_ids", 100, 1)?,
+                max_operations: count_getting(&settings, "batch.max_operations", 100, 1)?,
+            },
+            search: Search {
+                default_limit: count_getting(&settings, "search.default_limit", 20, 1)?,
+                max_limit: count_getting(&settings, "search.max_limit", 100, 1)?,
+                max_query_chars: count_getting(&settings, "search.max_query_chars", 100, 1)?,
+                min_similarity: settings.get_float("search.min_similarity").unwrap_or(0.4).clamp(0.0, 1.0) as f32,
+            },
         }))
     }
 }
 
+// 🔢 ตรวจว่า port อยู่ในช่วง 1-65535 แทนการ cast ด้วย as u16 ที่จะวนค่าโดยไม่แจ้งเตือน
+pub fn port_validating(key: &str, port: i64) -> Result<u16, ConfigError> {
+    match u16::try_from(port) {
+        Ok(port) if port != 0 => Ok(port),
+        _ => Err(ConfigError::Message(format!("{} is out of range (1-65535): {}", key, port))),
+    }
+}
+
+// 🔢 อ่านจำนวนจาก config เป็น u64 / usize (ไม่กำหนด = default, น้อยกว่า min ปัดขึ้นเป็น min)
+// ค่าติดลบเป็น error แทนการ cast ด้วย as ที่จะวนเป็นค่ามหาศาลโดยไม่แจ้งเตือน
+pub fn count_getting<T: TryFrom<i64>>(settings: &Config, key: &str, default: i64, min: i64) -> Result<T, ConfigError> {
+    let value = settings.get_int(key).unwrap_or(default);
+    if value < 0 {
+        return Err(ConfigError::Message(format!("{} must not be negative: {}", key, value)));
+    }
+    T::try_from(value.max(min)).map_err(|_| ConfigError::Message(format!("{} is too large: {}", key, value)))
+}
+
+// 🏅 ผู้เล่นใหม่ได้ default_tier เสมอ จึงต้องเป็น tier ที่มีอยู่ใน inventory.tiers
+fn inventory_validating(inventory: Inventory) -> Result<Inventory, ConfigError> {
+    match inventory.tiers.contains_key(&inventory.default_tier) {
+        true => Ok(inventory),
+        false => Err(ConfigError::Message(format!(
+            "inventory.default_tier is not in inventory.tiers: {}",
+            inventory.default_tier
+        ))),
+    }
+}
+
 impl Database {
     pub fn url_getting(&self) -> String {
         format!(
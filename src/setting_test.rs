@@ -0,0 +1,40 @@
+// === 🧪 Test Module: Setting Tests ===
+// 📖 ทดสอบการตรวจค่าตัวเลขจาก Setting.toml ก่อนใช้งาน
+
+#[cfg(test)]
+mod tests {
+    use config::Config;
+
+    use crate::setting::{count_getting, port_validating};
+
+    // 🧪 ค่าติดลบเป็น error (ไม่วนเป็นค่ามหาศาล) / ไม่กำหนดใช้ default / น้อยกว่า min ปัดขึ้น
+    #[test]
+    fn count_getting_test() {
+        let settings = Config::builder()
+            .set_override("server.request_timeout_secs", -1)
+            .unwrap()
+            .set_override("import.batch_size", 0)
+            .unwrap()
+            .set_override("server.max_body_bytes", 2048)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let error = count_getting::<u64>(&settings, "server.request_timeout_secs", 30, 0).unwrap_err();
+        assert_eq!(error.to_string(), "server.request_timeout_secs must not be negative: -1");
+        assert_eq!(count_getting::<usize>(&settings, "server.max_body_bytes", 64, 0).unwrap(), 2048);
+        assert_eq!(count_getting::<u64>(&settings, "trash.purge_interval_secs", 3600, 0).unwrap(), 3600);
+        assert_eq!(count_getting::<usize>(&settings, "import.batch_size", 500, 1).unwrap(), 1);
+    }
+
+    // 🧪 port ต้องอยู่ในช่วง 1-65535 (ไม่ cast ด้วย as u16 ที่วนค่า 65536 เป็น 0)
+    #[test]
+    fn port_validating_test() {
+        assert_eq!(port_validating("server.port", 1).unwrap(), 1);
+        assert_eq!(port_validating("server.port", 65535).unwrap(), 65535);
+        for port in [0, -1, 65536, 70000] {
+            let error = port_validating("server.port", port).unwrap_err();
+            assert_eq!(error.to_string(), format!("server.port is out of range (1-65535): {}", port));
+        }
+    }
+}
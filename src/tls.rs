@@ -0,0 +1,149 @@
+// === 🔐 TLS (rustls) ===
+// 📖 โหลด certificate สำหรับ HTTPS, reload อัตโนมัติเมื่อไฟล์เปลี่ยน และ redirect HTTP -> HTTPS
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ไฟล์นี้ดูแลเฉพาะเรื่อง TLS - router และ handler ไม่ต้องรู้ว่าทำงานบน HTTPS หรือไม่
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    รับค่าทั้งหมดจาก setting::Tls ไม่ hardcode path
+
+use std::{
+    io,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use axum::{
+    Router,
+    extract::Request,
+    http::{Uri, header},
+    response::Redirect,
+};
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::{
+    RootCertStore, ServerConfig,
+    crypto::ring,
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    server::WebPkiClientVerifier,
+};
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+use crate::setting::Tls;
+
+// 🏗️ สร้าง RustlsConfig ครั้งแรกตอนเริ่มระบบ
+pub fn config_creation(tls: &Tls) -> io::Result<RustlsConfig> {
+    Ok(RustlsConfig::from_config(Arc::new(server_config_building(tls)?)))
+}
+
+// 🔧 อ่านไฟล์ PEM แล้วประกอบเป็น rustls ServerConfig
+// ถ้ามี client_ca_path จะบังคับให้ client ส่ง certificate ที่ออกโดย CA นั้น (mTLS)
+fn server_config_building(tls: &Tls) -> io::Result<ServerConfig> {
+    let certs = CertificateDer::pem_file_iter(&tls.cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| invalid_data(&tls.cert_path, e))?;
+    let key = PrivateKeyDer::from_pem_file(&tls.key_path).map_err(|e| invalid_data(&tls.key_path, e))?;
+
+    let provider = Arc::new(ring::default_provider());
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| invalid_data(&tls.cert_path, e))?;
+
+    let builder = match &tls.client_ca_path {
+        Some(client_ca_path) => {
+            // 🪪 mTLS: ตรวจ client certificate กับ CA ที่กำหนด
+            let mut roots = RootCertStore::empty();
+            for cert in CertificateDer::pem_file_iter(client_ca_path).map_err(|e| invalid_data(client_ca_path, e))? {
+                roots
+                    .add(cert.map_err(|e| invalid_data(client_ca_path, e))?)
+                    .map_err(|e| invalid_data(client_ca_path, e))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| invalid_data(client_ca_path, e))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut config = builder
+        .with_single_cert(certs, key)
+        .map_err(|e| invalid_data(&tls.key_path, e))?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()]; // 🌐 รองรับทั้ง HTTP/2 และ HTTP/1.1
+    Ok(config)
+}
+
+// ❌ แปลง error ของ rustls ให้เป็น io::Error พร้อมบอกว่าไฟล์ไหนมีปัญหา
+fn invalid_data(path: &str, e: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, e))
+}
+
+// 🕰️ เวลาแก้ไขล่าสุดของไฟล์ที่เกี่ยวกับ TLS ทั้งหมด
+fn modified_times(tls: &Tls) -> Vec<Option<SystemTime>> {
+    [Some(&tls.cert_path), Some(&tls.key_path), tls.client_ca_path.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+// 🔄 ตรวจไฟล์ cert เป็นระยะ ถ้าเปลี่ยนจะ reload โดยไม่ต้อง restart server
+// ถ้าไฟล์ใหม่เสียจะ log error และใช้ cert เดิมต่อไป
+pub fn reloading(config: RustlsConfig, tls: Tls) {
+    tokio::spawn(async move {
+        let mut last_modified = modified_times(&tls);
+        let mut interval = tokio::time::interval(Duration::from_secs(tls.reload_interval_secs.max(1)));
+        loop {
+            interval.tick().await;
+            let modified = modified_times(&tls);
+            if modified == last_modified {
+                continue;
+            }
+
+            match server_config_building(&tls) {
+                Ok(server_config) => {
+                    config.reload_from_config(Arc::new(server_config));
+                    last_modified = modified;
+                    info!("TLS certificate has been reloaded");
+                }
+                Err(e) => error!("Failed to reload TLS certificate: {}", e),
+            }
+        }
+    });
+}
+
+// ↪️ เปิด HTTP port แยกที่ redirect ทุก request ไปยัง HTTPS port
+pub async fn redirecting(http_addr: SocketAddr, https_port: u16) -> io::Result<()> {
+    let app = Router::new().fallback(move |request: Request| async move {
+        Redirect::permanent(&https_uri(&request, https_port))
+    });
+
+    let listener = TcpListener::bind(http_addr).await?;
+    info!("HTTP redirect running on {}", http_addr);
+    axum::serve(listener, app).await
+}
+
+// 🔧 สร้าง URL ปลายทาง https://{host}:{https_port}{path?query}
+pub fn https_uri(request: &Request, https_port: u16) -> String {
+    let host = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|host| host.parse::<Uri>().ok())
+        .and_then(|uri| uri.host().map(str::to_string))
+        .unwrap_or_else(|| "localhost".to_string());
+    let path = request
+        .uri()
+        .path_and_query()
+        .map(|p| p.as_str())
+        .unwrap_or("/");
+
+    if https_port == 443 {
+        format!("https://{}{}", host, path)
+    } else {
+        format!("https://{}:{}{}", host, https_port, path)
+    }
+}
@@ -0,0 +1,32 @@
+// === 🧪 Test Module: HTTPS Redirect Tests ===
+// 📖 ทดสอบการสร้าง URL ปลายทางของ HTTP -> HTTPS redirect
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+
+    use crate::tls::https_uri;
+
+    fn request(uri: &str, host: Option<&str>) -> Request<Body> {
+        let mut builder = Request::get(uri);
+        if let Some(host) = host {
+            builder = builder.header(header::HOST, host);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    // 🧪 ตัด port เดิมของ Host ทิ้ง / เก็บ path + query / 443 ไม่ต้องใส่ port / ไม่มี Host ใช้ localhost
+    #[test]
+    fn https_uri_test() {
+        let request_with_query = request("/items?category=Staff", Some("game.example.com:8080"));
+        assert_eq!(https_uri(&request_with_query, 8443), "https://game.example.com:8443/items?category=Staff");
+        assert_eq!(https_uri(&request_with_query, 443), "https://game.example.com/items?category=Staff");
+
+        assert_eq!(https_uri(&request("/", Some("[::1]:8080")), 8443), "https://[::1]:8443/");
+        assert_eq!(https_uri(&request("/health", None), 8443), "https://localhost:8443/health");
+        assert_eq!(https_uri(&request("/health", Some("bad host")), 443), "https://localhost/health");
+    }
+}
@@ -0,0 +1,361 @@
+// === 🧠 Application Layer: Batch Use Case ===
+// 📖 อ่านหลาย item ด้วย query เดียว และเพิ่ม/แก้ไข/ลบหลายรายการใน transaction เดียว
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    BatchUsecase ดูแลการแบ่งผลราย operation และโหมดของ transaction - กติกาของ item ใช้ของ StaffUsecase / CategoriesUsecase
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository และ SharedUnitOfWork (Mock ได้ใน test)
+
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    entities::{item_revisions::Auditing, items::Items as ItemsEntity},
+    models::{
+        audit::{Actor, ItemAction},
+        batch::{
+            BatchGetStatus, BatchGot, BatchItems, BatchMode, BatchMutating, BatchOperation,
+            BatchOperationResult, BatchOperationStatus, BatchReport,
+        },
+        error::{APIError, IntoErrorResponse},
+        etag::Precondition,
+        item::{Item, StaffAdding},
+    },
+    repositories::{items::SharedItemsRepository, unit_of_work::SharedUnitOfWork},
+    setting::Batch as BatchSetting,
+    time_helper::IntoTimerHelperShared,
+    usecases::{
+        categories::CategoriesUsecase,
+        items::missing_item,
+        staff::{adding_error, attributes_validating, name_validating, owner_rejecting, updating_error},
+    },
+};
+
+// 📦 BatchUsecase struct: batch-get และ batch mutation
+pub struct BatchUsecase {
+    items_repository: SharedItemsRepository,    // 💾 Repository ของ item (batch-get)
+    unit_of_work: SharedUnitOfWork,             // 🔒 transaction ของ batch mutation
+    timer_helper: IntoTimerHelperShared,        // ⏰ เวลาที่แก้ไข
+    categories_usecase: Arc<CategoriesUsecase>, // 📚 ตรวจประเภท / กติกา / attributes
+    setting: BatchSetting,                      // 📦 จำนวนรายการสูงสุดต่อ request
+}
+
+// ✅ operation ที่ผ่านการตรวจแล้ว รอทำใน transaction
+enum Prepared {
+    Create {
+        staff: StaffAdding,
+        schema_version: Option<i32>,
+    },
+    Update {
+        id: i32,
+        staff: StaffAdding,
+        schema_version: Option<i32>,
+        precondition: Precondition,
+    },
+    Delete {
+        id: i32,
+        precondition: Precondition,
+    },
+}
+
+impl BatchUsecase {
+    // 🏗️ Factory method: สร้าง BatchUsecase พร้อม dependencies
+    pub fn creation(
+        items_repository: SharedItemsRepository,
+        unit_of_work: SharedUnitOfWork,
+        timer_helper: IntoTimerHelperShared,
+        categories_usecase: Arc<CategoriesUsecase>,
+        setting: BatchSetting,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            unit_of_work,
+            timer_helper,
+            categories_usecase,
+            setting,
+        })
+    }
+
+    // 🔍 อ่านทุก id ด้วย query เดียว - ผลเรียงตามลำดับที่ขอ id ที่ไม่พบได้ status not_found
+    pub async fn getting(&self, ids: Vec<i32>) -> Result<BatchItems, Box<dyn IntoErrorResponse>> {
+        size_validating(ids.len(), self.setting.max_ids, "ids")?;
+
+        let mut unique = ids.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        let entities = match self.items_repository.find_by_ids(unique).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        let mut found = HashMap::new();
+        for entity in entities {
+            let item = entity.to_model()?;
+            found.insert(item.id, item);
+        }
+
+        let items: Vec<BatchGot> = ids
+            .into_iter()
+            .map(|id| match found.get(&id) {
+                Some(item) => BatchGot {
+                    id,
+                    status: BatchGetStatus::Found,
+                    item: Some(item.clone()),
+                },
+                None => BatchGot {
+                    id,
+                    status: BatchGetStatus::NotFound,
+                    item: None,
+                },
+            })
+            .collect();
+        let found = items.iter().filter(|got| got.status == BatchGetStatus::Found).count();
+        Ok(BatchItems {
+            found,
+            not_found: items.len() - found,
+            items,
+        })
+    }
+
+    // ✏️ ทำทุก operation ตามลำดับใน transaction เดียว
+    // 🔒 all_or_nothing: ล้มรายการแรก = ยกเลิกทั้ง batch / best_effort: แต่ละรายการเป็น savepoint ของตัวเอง รายการที่ล้มไม่กระทบรายการอื่น
+    pub async fn mutating(&self, batch: BatchMutating, actor: &Actor) -> Result<BatchReport, Box<dyn IntoErrorResponse>> {
+        size_validating(batch.operations.len(), self.setting.max_operations, "operations")?;
+        let mode = batch.mode;
+        let mut results: Vec<BatchOperationResult> = batch
+            .operations
+            .iter()
+            .enumerate()
+            .map(|(index, operation)| BatchOperationResult {
+                index,
+                op: operation.name().to_string(),
+                status: BatchOperationStatus::Skipped,
+                id: operation.target(),
+                item: None,
+                error: None,
+                status_code: None,
+                current_version: None,
+            })
+            .collect();
+
+        // 📊 ขั้นตอนที่ 1: ตรวจทุกรายการก่อนเปิด transaction
+        let mut prepared = Vec::with_capacity(results.len());
+        for (index, operation) in batch.operations.into_iter().enumerate() {
+            match self.preparing(operation).await {
+                Ok(operation) => prepared.push(Some(operation)),
+                Err(e) => {
+                    failing(&mut results[index], e);
+                    prepared.push(None);
+                }
+            }
+        }
+        if mode == BatchMode::AllOrNothing && prepared.iter().any(Option::is_none) {
+            return Ok(reporting(mode, false, results));
+        }
+
+        // 🔒 ขั้นตอนที่ 2: อ่าน item ที่ถูกอ้างถึงทั้งหมดด้วย query เดียว แล้วทำทีละรายการ
+        let scope = match self.unit_of_work.begin().await {
+            Ok(scope) => scope,
+            Err(e) => return Err(Box::new(APIError::UpdatingItemError(e))),
+        };
+        let items_repository = scope.items();
+
+        let mut targets: Vec<i32> = prepared.iter().flatten().filter_map(Prepared::target).collect();
+        targets.sort_unstable();
+        targets.dedup();
+        let mut current: HashMap<i32, ItemsEntity> = match items_repository.find_by_ids(targets).await {
+            Ok(r) => r.into_iter().filter_map(|entity| Some((entity.id?, entity))).collect(),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+
+        for (index, operation) in prepared.into_iter().enumerate() {
+            let Some(operation) = operation else { continue };
+            match self.applying(&items_repository, &mut current, operation, actor).await {
+                Ok(item) => {
+                    let result = &mut results[index];
+                    result.status = BatchOperationStatus::Succeeded;
+                    if let Some(item) = item {
+                        result.id = Some(item.id);
+                        result.item = Some(item);
+                    }
+                }
+                Err(e) => {
+                    failing(&mut results[index], e);
+                    if mode == BatchMode::AllOrNothing {
+                        return Ok(reporting(mode, false, results)); // ↩️ drop scope = rollback
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = scope.commit().await {
+            return Err(Box::new(APIError::UpdatingItemError(e)));
+        }
+        Ok(reporting(mode, true, results))
+    }
+
+    // 📊 ตรวจชื่อ / ค่า / ประเภทของ create และ update (เหมือน POST /items/staff)
+    async fn preparing(&self, operation: BatchOperation) -> Result<Prepared, Box<dyn IntoErrorResponse>> {
+        let precondition = |version: Option<i32>| version.map(Precondition::Version).unwrap_or_default();
+        match operation {
+            BatchOperation::Create { item } => {
+                owner_rejecting(item.owner_id)?; // 👤 item ของผู้เล่นต้องผ่านกติกากระเป๋า
+                let schema_version = self.staff_validating(&item).await?;
+                Ok(Prepared::Create {
+                    staff: item,
+                    schema_version,
+                })
+            }
+            BatchOperation::Update { id, item, version } => {
+                let schema_version = self.staff_validating(&item).await?;
+                Ok(Prepared::Update {
+                    id,
+                    staff: item,
+                    schema_version,
+                    precondition: precondition(version),
+                })
+            }
+            BatchOperation::Delete { id, version } => Ok(Prepared::Delete {
+                id,
+                precondition: precondition(version),
+            }),
+        }
+    }
+
+    async fn staff_validating(&self, staff: &StaffAdding) -> Result<Option<i32>, Box<dyn IntoErrorResponse>> {
+        name_validating(&staff.name)?;
+        attributes_validating(staff)?;
+        self.categories_usecase.item_validating(staff).await
+    }
+
+    // ✏️ ทำ operation หนึ่งรายการผ่าน repository ของ transaction - ส่งกลับ item หลังบันทึก (delete ไม่มี)
+    // 📋 current คือภาพล่าสุดของ item ที่ถูกอ้างถึง (operation ก่อนหน้าใน batch เดียวกันแก้ไขได้)
+    async fn applying(
+        &self,
+        items_repository: &SharedItemsRepository,
+        current: &mut HashMap<i32, ItemsEntity>,
+        operation: Prepared,
+        actor: &Actor,
+    ) -> Result<Option<Item>, Box<dyn IntoErrorResponse>> {
+        match operation {
+            Prepared::Create { staff, schema_version } => {
+                let entity = ItemsEntity {
+                    attributes_schema_version: schema_version,
+                    ..staff.to_entity(Arc::clone(&self.timer_helper))
+                };
+                let auditing = Auditing::new(ItemAction::Create, actor, Arc::clone(&self.timer_helper));
+                match items_repository.insert(entity, auditing).await {
+                    Ok(r) => Ok(Some(r.to_model()?)),
+                    Err(e) => Err(adding_error(e, &staff)),
+                }
+            }
+            Prepared::Update {
+                id,
+                staff,
+                schema_version,
+                precondition,
+            } => {
+                let existing = match current.get(&id) {
+                    Some(existing) => existing.clone(),
+                    None => return Err(Box::new(APIError::ItemNotFound(id))),
+                };
+                precondition.checking(existing.version)?;
+                // 👤 ย้ายเจ้าของต้องผ่าน POST /items/{id}/transfer (ตรวจกระเป๋า + บันทึกเป็นการโอน) - ไม่ส่ง owner_id = คงเจ้าของเดิม
+                if staff.owner_id.is_some() && staff.owner_id != existing.owner_id {
+                    return Err(Box::new(APIError::ItemOwnerNotWritable("POST /items/{id}/transfer".to_string())));
+                }
+
+                // 🔄 field ที่ไม่ได้มาจาก request (เจ้าของ / แบบ / ความทนทาน / เวลาสร้าง) คงค่าเดิม
+                let entity = ItemsEntity {
+                    id: Some(id),
+                    owner_id: existing.owner_id,
+                    attributes_schema_version: schema_version,
+                    definition_id: existing.definition_id,
+                    durability: existing.durability,
+                    version: existing.version,
+                    created_at: existing.created_at,
+                    updated_at: self.timer_helper.now(),
+                    ..staff.to_entity(Arc::clone(&self.timer_helper))
+                };
+                let auditing = Auditing::new(ItemAction::Update, actor, Arc::clone(&self.timer_helper));
+                match items_repository.update(entity, auditing).await {
+                    Ok(r) => {
+                        current.insert(id, r.clone());
+                        Ok(Some(r.to_model()?))
+                    }
+                    Err(sqlx::Error::RowNotFound) => Err(missing_item(items_repository, id, precondition).await),
+                    Err(e) => Err(updating_error(e, &staff)),
+                }
+            }
+            Prepared::Delete { id, precondition } => {
+                let existing = match current.get(&id) {
+                    Some(existing) => existing,
+                    None => return Err(Box::new(APIError::ItemNotFound(id))),
+                };
+                precondition.checking(existing.version)?;
+
+                let auditing = Auditing::new(ItemAction::Delete, actor, Arc::clone(&self.timer_helper));
+                let deleted_at = self.timer_helper.now();
+                match items_repository.soft_delete(id, deleted_at, Some(existing.version), auditing).await {
+                    Ok(_) => {
+                        current.remove(&id);
+                        Ok(None)
+                    }
+                    Err(sqlx::Error::RowNotFound) => Err(missing_item(items_repository, id, precondition).await),
+                    Err(e) => Err(Box::new(APIError::RemovingItemError(e))),
+                }
+            }
+        }
+    }
+}
+
+impl Prepared {
+    fn target(&self) -> Option<i32> {
+        match self {
+            Self::Create { .. } => None,
+            Self::Update { id, .. } | Self::Delete { id, .. } => Some(*id),
+        }
+    }
+}
+
+// 📏 batch ต้องมีอย่างน้อยหนึ่งรายการและไม่เกินที่ตั้งค่าไว้
+fn size_validating(len: usize, max: usize, what: &str) -> Result<(), Box<dyn IntoErrorResponse>> {
+    if len == 0 {
+        return Err(Box::new(APIError::InvalidBatch(format!("{} must not be empty", what))));
+    }
+    if len > max {
+        return Err(Box::new(APIError::InvalidBatch(format!("at most {} {} per request", max, what))));
+    }
+    Ok(())
+}
+
+// ❌ บันทึกเหตุผลที่ล้มลงในรายงานของรายการนั้น
+fn failing(result: &mut BatchOperationResult, e: Box<dyn IntoErrorResponse>) {
+    let error = e.error();
+    result.status = BatchOperationStatus::Failed;
+    result.error = Some(error.error);
+    result.status_code = Some(error.status_code.as_u16());
+    result.current_version = error.current_version;
+}
+
+// 📊 สรุปผล - ไม่ได้ commit: รายการที่สำเร็จไปแล้วถูกยกเลิกทั้งหมด
+fn reporting(mode: BatchMode, committed: bool, mut results: Vec<BatchOperationResult>) -> BatchReport {
+    if !committed {
+        for result in results.iter_mut().filter(|result| result.status == BatchOperationStatus::Succeeded) {
+            result.status = BatchOperationStatus::RolledBack;
+            result.item = None;
+            if result.op == "create" {
+                result.id = None;                 // 🆔 id ที่ได้ถูกยกเลิกไปพร้อม transaction
+            }
+        }
+    }
+    let count = |status| results.iter().filter(|result| result.status == status).count();
+    BatchReport {
+        mode,
+        committed,
+        succeeded: count(BatchOperationStatus::Succeeded),
+        failed: count(BatchOperationStatus::Failed),
+        results,
+    }
+}
@@ -0,0 +1,355 @@
+// === 🧪 Test Module: Batch Use Case Tests ===
+// 📖 ทดสอบ batch-get และโหมด all_or_nothing / best_effort ของ batch mutation โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::http::StatusCode;
+    use mockall::predicate::{always, eq};
+
+    use crate::{
+        entities::{categories::Categories as CategoriesEntity, items::Items as ItemsEntity},
+        models::{
+            audit::Actor,
+            batch::{BatchGetStatus, BatchMode, BatchMutating, BatchOperation, BatchOperationStatus},
+            item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding},
+        },
+        repositories::{
+            categories::MockCategoriesRepository,
+            items::{MockItemsRepository, SharedItemsRepository},
+            unit_of_work::{BoxedTransactionScope, MockTransactionScope, MockUnitOfWork, SharedUnitOfWork},
+        },
+        setting::Batch as BatchSetting,
+        time_helper::TimerHelper,
+        usecases::{batch::BatchUsecase, categories::CategoriesUsecase},
+    };
+
+    // 👤 GM ที่เป็นผู้แก้ไข (บันทึกลงประวัติ)
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: None,
+        }
+    }
+
+    fn batch_setting() -> BatchSetting {
+        BatchSetting {
+            max_ids: 10,
+            max_operations: 10,
+        }
+    }
+
+    fn item_entity(id: i32) -> ItemsEntity {
+        ItemsEntity {
+            id: Some(id),
+            ..ItemsEntity::new(format!("staff {}", id), Category::Staff.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    fn staff(name: &str) -> StaffAdding {
+        StaffAdding {
+            name: name.to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats {
+                magic_power: Some(5),
+                ..ItemStats::default()
+            },
+        }
+    }
+
+    // 📚 catalog ที่มี Staff
+    fn categories_usecase() -> Arc<CategoriesUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation())
+    }
+
+    // 🔒 Mock UnitOfWork: scope ใช้ Mock Repository ชุดเดียวกับ usecase / commit ได้ตามที่กำหนด (0 = ต้องไม่ commit)
+    fn unit_of_work(items_repository: SharedItemsRepository, commits: usize) -> SharedUnitOfWork {
+        let mut unit_of_work_mock = MockUnitOfWork::new();
+        unit_of_work_mock.expect_begin().returning(move || {
+            let mut scope_mock = MockTransactionScope::new();
+            scope_mock
+                .expect_items()
+                .return_const(Arc::clone(&items_repository));
+            scope_mock
+                .expect_commit()
+                .times(commits)
+                .returning(|| Box::pin(async { Ok(()) }));
+            let scope: BoxedTransactionScope = Box::new(scope_mock);
+            Box::pin(async move { Ok(scope) })
+        });
+        Arc::new(unit_of_work_mock)
+    }
+
+    // ➕ create / ✏️ update id ที่ไม่มี / 🗑️ delete id 1
+    fn operations() -> Vec<BatchOperation> {
+        vec![
+            BatchOperation::Create {
+                item: staff("new staff"),
+            },
+            BatchOperation::Update {
+                id: 99,
+                item: staff("ghost staff"),
+                version: None,
+            },
+            BatchOperation::Delete { id: 1, version: Some(1) },
+        ]
+    }
+
+    // 📦 Mock ของ transaction: id 1 มีอยู่ / id 99 ไม่มี - insert ได้ id 10
+    fn items_repository_mock(deletes: usize) -> SharedItemsRepository {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_ids()
+            .with(eq(vec![1, 99]))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![item_entity(1)]) }));
+        items_repository_mock.expect_insert().times(1).returning(|item, _| {
+            Box::pin(async move {
+                Ok(ItemsEntity {
+                    id: Some(10),
+                    ..item
+                })
+            })
+        });
+        items_repository_mock
+            .expect_update()
+            .never();
+        items_repository_mock
+            .expect_soft_delete()
+            .with(eq(1), always(), eq(Some(1)), always())
+            .times(deletes)
+            .returning(|id, _, _, _| Box::pin(async move { Ok(item_entity(id)) }));
+        Arc::new(items_repository_mock)
+    }
+
+    #[tokio::test]
+    async fn getting_test() {
+        // 📦 ขอ id ซ้ำได้ แต่ query ครั้งเดียวด้วย id ที่ไม่ซ้ำ
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_ids()
+            .with(eq(vec![1, 3, 99]))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![item_entity(1), item_entity(3)]) }));
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+
+        let batch_usecase = BatchUsecase::creation(
+            Arc::clone(&items_repository),
+            unit_of_work(items_repository, 0),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        let result = match batch_usecase.getting(vec![3, 99, 1, 3]).await {
+            Ok(r) => r,
+            Err(_) => panic!("getting error"),
+        };
+
+        // ✅ ผลเรียงตามลำดับที่ขอ พร้อม marker ของ id ที่ไม่พบ
+        assert_eq!(result.found, 3);
+        assert_eq!(result.not_found, 1);
+        let ids: Vec<(i32, BatchGetStatus)> = result.items.iter().map(|got| (got.id, got.status)).collect();
+        assert_eq!(
+            ids,
+            vec![
+                (3, BatchGetStatus::Found),
+                (99, BatchGetStatus::NotFound),
+                (1, BatchGetStatus::Found),
+                (3, BatchGetStatus::Found),
+            ]
+        );
+        assert!(result.items[1].item.is_none());
+        assert_eq!(result.items[2].item.as_ref().unwrap().name, "staff 1");
+    }
+
+    #[tokio::test]
+    async fn getting_too_many_test() {
+        let items_repository: SharedItemsRepository = Arc::new(MockItemsRepository::new());
+        let batch_usecase = BatchUsecase::creation(
+            Arc::clone(&items_repository),
+            unit_of_work(items_repository, 0),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        // ❌ เกิน max_ids - ไม่ query เลย
+        let error = match batch_usecase.getting((1..=11).collect()).await {
+            Ok(_) => panic!("getting should fail"),
+            Err(e) => e.error(),
+        };
+        assert_eq!(error.status_code, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn mutating_all_or_nothing_test() {
+        // 🔒 update ล้ม = ยกเลิก create ที่ทำไปแล้ว และไม่ทำ delete ที่เหลือ
+        let items_repository = items_repository_mock(0);
+        let batch_usecase = BatchUsecase::creation(
+            Arc::clone(&items_repository),
+            unit_of_work(items_repository, 0),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        let batch = BatchMutating {
+            mode: BatchMode::AllOrNothing,
+            operations: operations(),
+        };
+        let report = match batch_usecase.mutating(batch, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("mutating error"),
+        };
+
+        assert!(!report.committed);
+        assert_eq!(report.succeeded, 0);
+        assert_eq!(report.failed, 1);
+        let statuses: Vec<BatchOperationStatus> = report.results.iter().map(|result| result.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                BatchOperationStatus::RolledBack,
+                BatchOperationStatus::Failed,
+                BatchOperationStatus::Skipped,
+            ]
+        );
+        assert_eq!(report.results[0].id, None);
+        assert_eq!(report.results[1].status_code, Some(404));
+    }
+
+    #[tokio::test]
+    async fn mutating_best_effort_test() {
+        // 📋 update ล้มรายการเดียว - create และ delete ถูกบันทึก
+        let items_repository = items_repository_mock(1);
+        let batch_usecase = BatchUsecase::creation(
+            Arc::clone(&items_repository),
+            unit_of_work(items_repository, 1),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        let batch = BatchMutating {
+            mode: BatchMode::BestEffort,
+            operations: operations(),
+        };
+        let report = match batch_usecase.mutating(batch, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("mutating error"),
+        };
+
+        assert!(report.committed);
+        assert_eq!(report.succeeded, 2);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.results[0].status, BatchOperationStatus::Succeeded);
+        assert_eq!(report.results[0].id, Some(10));
+        assert_eq!(report.results[0].item.as_ref().unwrap().name, "new staff");
+        assert_eq!(report.results[1].status, BatchOperationStatus::Failed);
+        assert_eq!(report.results[1].error.as_deref(), Some("Item not found: 99"));
+        assert_eq!(report.results[2].status, BatchOperationStatus::Succeeded);
+    }
+
+    #[tokio::test]
+    async fn mutating_invalid_operation_test() {
+        // ❌ all_or_nothing: รายการที่ตรวจไม่ผ่าน = ไม่เปิด transaction เลย
+        let items_repository: SharedItemsRepository = Arc::new(MockItemsRepository::new());
+        let mut unit_of_work_mock = MockUnitOfWork::new();
+        unit_of_work_mock.expect_begin().never();
+        let batch_usecase = BatchUsecase::creation(
+            items_repository,
+            Arc::new(unit_of_work_mock),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        let batch = BatchMutating {
+            mode: BatchMode::AllOrNothing,
+            operations: vec![
+                BatchOperation::Create { item: staff("  ") },
+                BatchOperation::Delete { id: 1, version: None },
+            ],
+        };
+        let report = match batch_usecase.mutating(batch, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("mutating error"),
+        };
+
+        assert!(!report.committed);
+        assert_eq!(report.results[0].status, BatchOperationStatus::Failed);
+        assert_eq!(report.results[0].status_code, Some(422));
+        assert_eq!(report.results[1].status, BatchOperationStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn mutating_owner_test() {
+        // 👤 create ที่มี owner_id / update ที่ย้ายเจ้าของ ไม่ผ่าน - update ที่ไม่ส่ง owner_id คงเจ้าของเดิม
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_ids()
+            .with(eq(vec![1, 2]))
+            .returning(|ids| {
+                Box::pin(async move {
+                    Ok(ids
+                        .into_iter()
+                        .map(|id| ItemsEntity { owner_id: Some(7), ..item_entity(id) })
+                        .collect())
+                })
+            });
+        items_repository_mock.expect_insert().never();
+        items_repository_mock
+            .expect_update()
+            .withf(|item, _| item.id == Some(2) && item.owner_id == Some(7))
+            .times(1)
+            .returning(|item, _| Box::pin(async move { Ok(item) }));
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+        let batch_usecase = BatchUsecase::creation(
+            Arc::clone(&items_repository),
+            unit_of_work(items_repository, 1),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            batch_setting(),
+        );
+
+        let owned = |name: &str, owner_id| StaffAdding { owner_id, ..staff(name) };
+        let batch = BatchMutating {
+            mode: BatchMode::BestEffort,
+            operations: vec![
+                BatchOperation::Create { item: owned("new staff", Some(7)) },
+                BatchOperation::Update { id: 1, item: owned("staff 1", Some(8)), version: None },
+                BatchOperation::Update { id: 2, item: owned("staff 2", None), version: None },
+            ],
+        };
+        let report = match batch_usecase.mutating(batch, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("mutating error"),
+        };
+
+        let statuses: Vec<(BatchOperationStatus, Option<u16>)> =
+            report.results.iter().map(|result| (result.status, result.status_code)).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (BatchOperationStatus::Failed, Some(422)),
+                (BatchOperationStatus::Failed, Some(422)),
+                (BatchOperationStatus::Succeeded, None),
+            ]
+        );
+        assert_eq!(report.results[2].item.as_ref().unwrap().owner_id, Some(7));
+    }
+}
@@ -0,0 +1,456 @@
+// === 🧠 Application Layer: Categories Use Case ===
+// 📖 catalog ประเภท item: admin เพิ่ม/แก้/ลบได้ และใช้ตรวจ category ของ item ใหม่
+//    ประเภทเป็น tree (Weapon > Magic > Staff) - กติกาสืบทอดจากแม่ลงไปหาลูก
+//    และการกรองด้วยประเภทแม่รวม item ของประเภทลูกทั้งหมด
+//    attributes ของ item ตรวจกับ JSON Schema เวอร์ชันล่าสุดของประเภท (schema เก่าเก็บไว้ทุกเวอร์ชัน)
+//    catalog cache ไว้ในหน่วยความจำ หมดอายุทุก CACHE_TTL หรือทันทีที่ catalog ถูกแก้ในเครื่องนี้
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    CategoriesUsecase ดูแลเฉพาะ catalog, tree และกติกาของประเภท
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เพิ่มประเภทหรือกติกาใหม่ได้จาก catalog โดยไม่แก้ enum หรือเขียน repository ใหม่
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedCategoriesRepository (abstraction)
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use jsonschema::Validator;
+use serde_json::Value;
+use sqlx::types::Json;
+
+use crate::{
+    entities::{categories::Categories as CategoriesEntity, category_schemas::CategorySchemas as CategorySchemasEntity},
+    models::{
+        category::{CategoryAdding, CategoryEntry, CategoryNode, CategoryRules, CategorySchemaEntry, CategoryUpdating},
+        error::{APIError, FieldError, IntoErrorResponse},
+        item::{Category, ItemAttributes, StaffAdding},
+    },
+    repositories::categories::SharedCategoriesRepository,
+    time_helper::IntoTimerHelperShared,
+};
+
+// ⏳ อายุของ cache - instance อื่นแก้ catalog แล้วเครื่องนี้เห็นภายในเวลานี้
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+// 🗂️ catalog: ประเภททั้งหมดและ schema เวอร์ชันล่าสุดของแต่ละประเภท
+struct Catalog {
+    categories: Vec<CategoriesEntity>,   // 🏷️ ประเภททั้งหมด
+    schemas: Vec<CategorySchemasEntity>, // 🧾 schema ล่าสุด (ประเภทที่ไม่มี schema ไม่อยู่ในนี้)
+}
+
+// 🗂️ catalog ที่โหลดมาล่าสุด
+struct CatalogCache {
+    catalog: Arc<Catalog>, // 🗂️ catalog
+    loaded_at: Instant,    // ⏱️ เวลาที่โหลด
+}
+
+// 📦 CategoriesUsecase struct: จัดการ catalog ประเภท item
+pub struct CategoriesUsecase {
+    categories_repository: SharedCategoriesRepository, // 💾 Repository ของ catalog
+    timer_helper: IntoTimerHelperShared,               // ⏰ เครื่องมือจัดการเวลา
+    cache: RwLock<Option<CatalogCache>>,               // 🗂️ None = ต้องโหลดใหม่
+    validators: RwLock<HashMap<(i32, i32), Arc<Validator>>>, // 🧾 schema ที่ compile แล้ว ตาม (ประเภท, เวอร์ชัน) - เวอร์ชันไม่ถูกแก้จึงไม่ต้องล้าง
+}
+
+impl CategoriesUsecase {
+    // 🏗️ Factory method: สร้าง CategoriesUsecase พร้อม dependencies
+    pub fn creation(
+        categories_repository: SharedCategoriesRepository,
+        timer_helper: IntoTimerHelperShared,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            categories_repository,
+            timer_helper,
+            cache: RwLock::new(None),
+            validators: RwLock::new(HashMap::new()),
+        })
+    }
+
+    // 📏 กติกาที่ใช้จริงของประเภท (รวมที่สืบทอดจากประเภทแม่) - ไม่มีใน catalog -> InvalidCategory
+    pub async fn rules_getting(&self, category: &Category) -> Result<CategoryRules, Box<dyn IntoErrorResponse>> {
+        let catalog = self.catalog_getting().await?;
+        let entity = named(&catalog.categories, &category.to_string())?;
+        Ok(effective_rules(&catalog.categories, entity))
+    }
+
+    // ✅ ตรวจ item ใหม่กับกติกาและ schema ของประเภท (ประเภทต้องอยู่ใน catalog)
+    // 🧾 ส่งกลับเวอร์ชัน schema ที่ใช้ตรวจ attributes ให้บันทึกไว้กับ item
+    pub async fn item_validating(&self, staff: &StaffAdding) -> Result<Option<i32>, Box<dyn IntoErrorResponse>> {
+        self.rules_checking(staff).await?;
+        self.attributes_validating(&staff.category, &staff.attributes).await
+    }
+
+    // 📏 ตรวจ stats / level / น้ำหนักกับกติกาที่สืบทอดตาม tree (ไม่รวม attributes)
+    pub async fn rules_checking(&self, staff: &StaffAdding) -> Result<(), Box<dyn IntoErrorResponse>> {
+        let rules = self.rules_getting(&staff.category).await?;
+        match rules.checking(&staff.category.to_string(), staff) {
+            Ok(()) => Ok(()),
+            Err(reason) => Err(Box::new(APIError::InvalidItemAttributes(reason))),
+        }
+    }
+
+    // 🧩 ตรวจ attributes กับ schema เวอร์ชันล่าสุดของประเภท ส่งกลับเวอร์ชันที่ใช้
+    // 📭 ประเภทที่ยังไม่มี schema รับได้เฉพาะ attributes ว่าง (None)
+    pub async fn attributes_validating(
+        &self,
+        category: &Category,
+        attributes: &ItemAttributes,
+    ) -> Result<Option<i32>, Box<dyn IntoErrorResponse>> {
+        let catalog = self.catalog_getting().await?;
+        let entity = named(&catalog.categories, &category.to_string())?;
+
+        let schema = match catalog.schemas.iter().find(|schema| Some(schema.category_id) == entity.id) {
+            Some(schema) => schema,
+            None if attributes.is_empty() => return Ok(None),
+            None => {
+                return Err(Box::new(APIError::InvalidAttributes(vec![FieldError {
+                    field: String::new(),
+                    message: format!("{} has no attributes schema", category),
+                }])));
+            }
+        };
+
+        let validator = self.validator_getting(schema)?;
+        let instance = Value::Object(attributes.clone());
+        let fields: Vec<FieldError> = validator
+            .iter_errors(&instance)
+            .map(|e| FieldError {
+                field: e.instance_path.to_string(),
+                message: e.to_string(),
+            })
+            .collect();
+
+        match fields.is_empty() {
+            true => Ok(schema.version),
+            false => Err(Box::new(APIError::InvalidAttributes(fields))),
+        }
+    }
+
+    // 🧾 ดู schema ทุกเวอร์ชันของประเภท
+    pub async fn schemas_listing(&self, id: i32) -> Result<Vec<CategorySchemaEntry>, Box<dyn IntoErrorResponse>> {
+        self.found_getting(id).await?;
+
+        let entities = match self.categories_repository.find_schemas(id).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingCategoryError(e))),
+        };
+
+        let mut schemas = Vec::with_capacity(entities.len());
+        for entity in entities {
+            schemas.push(entity.to_model()?);
+        }
+        Ok(schemas)
+    }
+
+    // ➕ เพิ่ม schema เป็นเวอร์ชันใหม่ของประเภท - item ใหม่ตรวจกับเวอร์ชันนี้ item เดิมยังอ้างถึงเวอร์ชันเดิม
+    pub async fn schema_adding(&self, id: i32, schema: Value) -> Result<CategorySchemaEntry, Box<dyn IntoErrorResponse>> {
+        if let Err(e) = jsonschema::validator_for(&schema) {
+            return Err(Box::new(APIError::InvalidCategorySchema(e.to_string())));
+        }
+        self.found_getting(id).await?;
+
+        let entity = CategorySchemasEntity::new(id, schema, Arc::clone(&self.timer_helper));
+        let entity = match self.categories_repository.insert_schema(entity).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::SavingCategoryError(e))),
+        };
+
+        self.invalidating();
+        entity.to_model()
+    }
+
+    // 🌿 ชื่อประเภทนี้และประเภทลูกหลานทั้งหมด (ใช้กรอง "Weapon ทั้งหมด")
+    pub async fn descendants_getting(&self, name: &str) -> Result<Vec<String>, Box<dyn IntoErrorResponse>> {
+        let catalog = self.catalog_getting().await?;
+        let entity = named(&catalog.categories, name)?;
+        Ok(descendants(&catalog.categories, entity).into_iter().map(|entity| entity.name.clone()).collect())
+    }
+
+    // 🌳 catalog ในรูป tree พร้อมกติกาที่สืบทอดแล้ว
+    pub async fn tree_getting(&self) -> Result<Vec<CategoryNode>, Box<dyn IntoErrorResponse>> {
+        let catalog = self.catalog_getting().await?;
+        let categories = &catalog.categories;
+
+        let mut nodes = Vec::new();
+        for root in categories.iter().filter(|entity| parent(categories, entity).is_none()) {
+            nodes.push(node_building(categories, root, &CategoryRules::default(), &mut HashSet::new())?);
+        }
+        Ok(nodes)
+    }
+
+    // 📋 ดูประเภททั้งหมด (อ่านจากฐานข้อมูลเสมอ)
+    pub async fn listing(&self) -> Result<Vec<CategoryEntry>, Box<dyn IntoErrorResponse>> {
+        let entities = self.all_getting().await?;
+
+        let mut categories = Vec::with_capacity(entities.len());
+        for entity in entities {
+            categories.push(entity.to_model()?);
+        }
+        Ok(categories)
+    }
+
+    // ➕ เพิ่มประเภทใหม่
+    pub async fn adding(&self, category: CategoryAdding) -> Result<CategoryEntry, Box<dyn IntoErrorResponse>> {
+        let entity = category.to_entity(Arc::clone(&self.timer_helper));
+        name_validating(&entity.name)?;
+        rules_validating(&entity.rules)?;
+        if let Some(parent_id) = entity.parent_id {
+            parent_validating(&self.all_getting().await?, None, parent_id)?;
+        }
+
+        let name = entity.name.clone();
+        let entity = match self.categories_repository.insert(entity).await {
+            Ok(r) => r,
+            Err(e) => return Err(saving_error(e, &name)),
+        };
+
+        self.invalidating();
+        entity.to_model()
+    }
+
+    // ✏️ แก้ชื่อ/ประเภทแม่/กติกา - built-in เปลี่ยนชื่อไม่ได้
+    pub async fn updating(&self, id: i32, category: CategoryUpdating) -> Result<CategoryEntry, Box<dyn IntoErrorResponse>> {
+        let name = category.name.trim().to_string();
+        name_validating(&name)?;
+        rules_validating(&category.rules)?;
+
+        let entity = self.found_getting(id).await?;
+        if Category::from(entity.name.clone()).is_built_in() && entity.name != name {
+            return Err(Box::new(APIError::BuiltInCategory(entity.name)));
+        }
+        if let Some(parent_id) = category.parent_id {
+            parent_validating(&self.all_getting().await?, Some(id), parent_id)?;
+        }
+
+        let entity = CategoriesEntity {
+            name: name.clone(),
+            parent_id: category.parent_id,
+            rules: Json(category.rules),
+            updated_at: self.timer_helper.now(),
+            ..entity
+        };
+        let entity = match self.categories_repository.update(entity).await {
+            Ok(r) => r,
+            Err(e) => return Err(saving_error(e, &name)),
+        };
+
+        self.invalidating();
+        entity.to_model()
+    }
+
+    // 🗑️ ลบประเภท - built-in หรือยังมี item/ประเภทลูกใช้อยู่ลบไม่ได้
+    pub async fn removing(&self, id: i32) -> Result<(), Box<dyn IntoErrorResponse>> {
+        let entity = self.found_getting(id).await?;
+        if Category::from(entity.name.clone()).is_built_in() {
+            return Err(Box::new(APIError::BuiltInCategory(entity.name)));
+        }
+
+        match self.categories_repository.delete(id).await {
+            Ok(()) => {}
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::CategoryNotFound(id))),
+            Err(e) => return Err(saving_error(e, &entity.name)),
+        }
+
+        self.invalidating();
+        Ok(())
+    }
+
+    // 🔍 ดึงประเภทตาม ID
+    async fn found_getting(&self, id: i32) -> Result<CategoriesEntity, Box<dyn IntoErrorResponse>> {
+        match self.categories_repository.find_by_id(id).await {
+            Ok(r) => Ok(r),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::CategoryNotFound(id))),
+            Err(e) => Err(Box::new(APIError::FetchingCategoryError(e))),
+        }
+    }
+
+    // 📋 ประเภททั้งหมดจากฐานข้อมูล
+    async fn all_getting(&self) -> Result<Vec<CategoriesEntity>, Box<dyn IntoErrorResponse>> {
+        match self.categories_repository.find_all().await {
+            Ok(r) => Ok(r),
+            Err(e) => Err(Box::new(APIError::FetchingCategoryError(e))),
+        }
+    }
+
+    // 🗂️ catalog จาก cache ถ้ายังไม่หมดอายุ ไม่เช่นนั้นโหลดใหม่
+    async fn catalog_getting(&self) -> Result<Arc<Catalog>, Box<dyn IntoErrorResponse>> {
+        if let Some(catalog) = self.cached() {
+            return Ok(catalog);
+        }
+
+        let categories = self.all_getting().await?;
+        let schemas = match self.categories_repository.find_latest_schemas().await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingCategoryError(e))),
+        };
+
+        let catalog = Arc::new(Catalog { categories, schemas });
+        let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+        *cache = Some(CatalogCache {
+            catalog: Arc::clone(&catalog),
+            loaded_at: Instant::now(),
+        });
+        Ok(catalog)
+    }
+
+    fn cached(&self) -> Option<Arc<Catalog>> {
+        let cache = self.cache.read().unwrap_or_else(|e| e.into_inner());
+        cache
+            .as_ref()
+            .filter(|cache| cache.loaded_at.elapsed() < CACHE_TTL)
+            .map(|cache| Arc::clone(&cache.catalog))
+    }
+
+    // 🧾 schema ที่ compile แล้ว (compile ครั้งแรกที่ใช้)
+    fn validator_getting(&self, schema: &CategorySchemasEntity) -> Result<Arc<Validator>, Box<dyn IntoErrorResponse>> {
+        let key = (schema.category_id, schema.version.unwrap_or_default());
+        if let Some(validator) = self.validators.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            return Ok(Arc::clone(validator));
+        }
+
+        let validator = match jsonschema::validator_for(&schema.schema) {
+            Ok(validator) => Arc::new(validator),
+            Err(e) => return Err(Box::new(APIError::InvalidCategorySchema(e.to_string()))),
+        };
+        let mut validators = self.validators.write().unwrap_or_else(|e| e.into_inner());
+        validators.insert(key, Arc::clone(&validator));
+        Ok(validator)
+    }
+
+    fn invalidating(&self) {
+        let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+        *cache = None;
+    }
+}
+
+// 🔍 หาประเภทตามชื่อ - ไม่มี -> InvalidCategory
+fn named<'a>(catalog: &'a [CategoriesEntity], name: &str) -> Result<&'a CategoriesEntity, Box<dyn IntoErrorResponse>> {
+    match catalog.iter().find(|entity| entity.name == name) {
+        Some(entity) => Ok(entity),
+        None => Err(Box::new(APIError::InvalidCategory(name.to_string()))),
+    }
+}
+
+// 🌳 ประเภทแม่ (None = ราก หรือแม่ไม่อยู่ใน catalog)
+fn parent<'a>(catalog: &'a [CategoriesEntity], entity: &CategoriesEntity) -> Option<&'a CategoriesEntity> {
+    let parent_id = entity.parent_id?;
+    catalog.iter().find(|parent| parent.id == Some(parent_id))
+}
+
+// 🌳 ประเภทนี้และบรรพบุรุษ ไล่จากตัวเองขึ้นไปถึงราก (จำกัดรอบกัน tree วนซ้ำ)
+fn ancestry<'a>(catalog: &'a [CategoriesEntity], entity: &'a CategoriesEntity) -> Vec<&'a CategoriesEntity> {
+    let mut chain = vec![entity];
+    while let Some(parent) = parent(catalog, chain[chain.len() - 1])
+        && chain.len() <= catalog.len()
+    {
+        chain.push(parent);
+    }
+    chain
+}
+
+// 📏 กติกาที่ใช้จริง: รวมจากรากลงมาหาประเภทนี้
+fn effective_rules(catalog: &[CategoriesEntity], entity: &CategoriesEntity) -> CategoryRules {
+    ancestry(catalog, entity)
+        .iter()
+        .rev()
+        .fold(CategoryRules::default(), |inherited, entity| entity.rules.0.inheriting(&inherited))
+}
+
+// 🌿 ประเภทนี้และลูกหลานทั้งหมด
+fn descendants<'a>(catalog: &'a [CategoriesEntity], root: &'a CategoriesEntity) -> Vec<&'a CategoriesEntity> {
+    let mut found = vec![root];
+    let mut index = 0;
+    while index < found.len() {
+        let id = found[index].id;
+        for child in catalog.iter().filter(|child| child.parent_id.is_some() && child.parent_id == id) {
+            if !found.iter().any(|entity| entity.id == child.id) {
+                found.push(child);
+            }
+        }
+        index += 1;
+    }
+    found
+}
+
+// 🌳 สร้าง node พร้อมลูกหลาน
+fn node_building(
+    catalog: &[CategoriesEntity],
+    entity: &CategoriesEntity,
+    inherited: &CategoryRules,
+    visited: &mut HashSet<i32>,
+) -> Result<CategoryNode, Box<dyn IntoErrorResponse>> {
+    let model = entity.to_model()?;
+    visited.insert(model.id);
+    let effective_rules = model.rules.inheriting(inherited);
+
+    let mut children = Vec::new();
+    for child in catalog.iter().filter(|child| child.parent_id == Some(model.id)) {
+        if child.id.is_some_and(|id| !visited.contains(&id)) {
+            children.push(node_building(catalog, child, &effective_rules, visited)?);
+        }
+    }
+
+    Ok(CategoryNode {
+        id: model.id,
+        name: model.name,
+        rules: model.rules,
+        effective_rules,
+        children,
+    })
+}
+
+// 🏷️ ชื่อประเภทต้องไม่ว่าง
+fn name_validating(name: &str) -> Result<(), Box<dyn IntoErrorResponse>> {
+    match name.is_empty() {
+        true => Err(Box::new(APIError::InvalidCategory(name.to_string()))),
+        false => Ok(()),
+    }
+}
+
+// 📏 กติกาต้องอ้างถึง stats ที่มีจริง
+fn rules_validating(rules: &CategoryRules) -> Result<(), Box<dyn IntoErrorResponse>> {
+    match rules.unknown_stat() {
+        Some(name) => Err(Box::new(APIError::InvalidCategoryRules(format!("unknown stat {}", name)))),
+        None => Ok(()),
+    }
+}
+
+// 🌳 ประเภทแม่ต้องมีอยู่ และต้องไม่ใช่ตัวเองหรือลูกหลานของตัวเอง (tree วนซ้ำ)
+fn parent_validating(catalog: &[CategoriesEntity], id: Option<i32>, parent_id: i32) -> Result<(), Box<dyn IntoErrorResponse>> {
+    let parent = match catalog.iter().find(|entity| entity.id == Some(parent_id)) {
+        Some(parent) => parent,
+        None => return Err(Box::new(APIError::InvalidCategoryParent(format!("category {} not found", parent_id)))),
+    };
+
+    if id.is_some() && ancestry(catalog, parent).iter().any(|entity| entity.id == id) {
+        return Err(Box::new(APIError::InvalidCategoryParent(format!(
+            "{} is the category itself or one of its descendants",
+            parent.name
+        ))));
+    }
+    Ok(())
+}
+
+// 🔄 แปลง error ตอนบันทึก: constraint ของฐานข้อมูลบอกเหตุผลที่ admin แก้ไขได้
+fn saving_error(e: sqlx::Error, name: &str) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match code.as_deref() {
+        // 🔄 unique violation: ชื่อนี้มีอยู่แล้ว
+        Some("23505") => Box::new(APIError::CategoryAlreadyExists(name.to_string())),
+        // 🔗 foreign key violation: ยังมี item หรือประเภทลูกใช้ประเภทนี้อยู่
+        Some("23503") => Box::new(APIError::CategoryInUse(name.to_string())),
+        _ => Box::new(APIError::SavingCategoryError(e)),
+    }
+}
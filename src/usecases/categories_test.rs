@@ -0,0 +1,311 @@
+// === 🧪 Test Module: Categories Use Case Tests ===
+// 📖 ทดสอบ catalog ประเภท item และ cache โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::http::StatusCode;
+    use mockall::predicate::eq;
+    use serde_json::json;
+    use sqlx::types::Json;
+
+    use crate::{
+        entities::{categories::Categories as CategoriesEntity, category_schemas::CategorySchemas as CategorySchemasEntity},
+        models::{
+            category::{CategoryAdding, CategoryRules},
+            item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding},
+        },
+        repositories::categories::MockCategoriesRepository,
+        time_helper::TimerHelper,
+        usecases::categories::CategoriesUsecase,
+    };
+
+    fn category_entity(id: i32, name: &str) -> CategoriesEntity {
+        CategoriesEntity {
+            id: Some(id),
+            ..CategoriesEntity::new(name.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    fn child_entity(id: i32, name: &str, parent_id: i32, rules: CategoryRules) -> CategoriesEntity {
+        CategoriesEntity {
+            parent_id: Some(parent_id),
+            rules: Json(rules),
+            ..category_entity(id, name)
+        }
+    }
+
+    // 🧾 catalog ที่ยังไม่มี JSON Schema ของ attributes
+    fn without_schemas(categories_repository_mock: &mut MockCategoriesRepository) {
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+    }
+
+    fn item_adding(category: Category, stats: ItemStats) -> StaffAdding {
+        StaffAdding {
+            name: "test".to_string(),
+            category,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats,
+        }
+    }
+
+    // 🧪 ตรวจประเภทซ้ำหลายครั้ง query catalog ครั้งเดียว / ประเภทที่ไม่มีได้ 400
+    #[tokio::test]
+    async fn validating_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock
+            .expect_find_all()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec![category_entity(1, "Staff"), category_entity(3, "Bow")]) }));
+
+        without_schemas(&mut categories_repository_mock);
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+
+        assert!(categories_usecase.rules_getting(&Category::Staff).await.is_ok());
+        assert!(categories_usecase.rules_getting(&Category::from("Bow".to_string())).await.is_ok());
+        match categories_usecase.rules_getting(&Category::Sword).await {
+            Ok(_) => panic!("Sword is not in the catalog"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::BAD_REQUEST),
+        }
+    }
+
+    // 🧪 เพิ่มประเภทแล้ว cache ต้องถูกล้าง ประเภทใหม่ใช้ได้ทันที
+    #[tokio::test]
+    async fn adding_invalidates_cache_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        let mut calls = 0;
+        categories_repository_mock
+            .expect_find_all()
+            .times(2)
+            .returning(move || {
+                calls += 1;
+                let mut categories = vec![category_entity(1, "Staff")];
+                if calls > 1 {
+                    categories.push(category_entity(3, "Bow"));
+                }
+                Box::pin(async move { Ok(categories) })
+            });
+        categories_repository_mock
+            .expect_insert()
+            .with(eq(CategoriesEntity::new("Bow".to_string(), TimerHelper::Mock.creation())))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(category_entity(3, "Bow")) }));
+
+        without_schemas(&mut categories_repository_mock);
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+        let bow = Category::from("Bow".to_string());
+
+        assert!(categories_usecase.rules_getting(&bow).await.is_err());
+        let category = match categories_usecase
+            .adding(CategoryAdding {
+                name: " Bow ".to_string(),
+                parent_id: None,
+                rules: CategoryRules::default(),
+            })
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => panic!("adding error"),
+        };
+        assert_eq!(category.name, "Bow");
+        assert!(categories_usecase.rules_getting(&bow).await.is_ok());
+    }
+
+    // 🧪 กติกาสืบทอดจาก Weapon > Magic > Staff และการกรอง Weapon รวมลูกหลาน
+    #[tokio::test]
+    async fn inherited_rules_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().times(1).returning(|| {
+            Box::pin(async {
+                Ok(vec![
+                    CategoriesEntity {
+                        rules: Json(CategoryRules {
+                            allowed_stats: Some(vec!["magic_power".to_string(), "attack".to_string()]),
+                            ..CategoryRules::default()
+                        }),
+                        ..category_entity(1, "Weapon")
+                    },
+                    child_entity(
+                        2,
+                        "Magic",
+                        1,
+                        CategoryRules {
+                            required_stats: vec!["magic_power".to_string()],
+                            ..CategoryRules::default()
+                        },
+                    ),
+                    child_entity(
+                        3,
+                        "Staff",
+                        2,
+                        CategoryRules {
+                            min_required_level: Some(2),
+                            ..CategoryRules::default()
+                        },
+                    ),
+                    child_entity(4, "Sword", 1, CategoryRules::default()),
+                ])
+            })
+        });
+
+        without_schemas(&mut categories_repository_mock);
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+
+        let rules = match categories_usecase.rules_getting(&Category::Staff).await {
+            Ok(r) => r,
+            Err(_) => panic!("rules error"),
+        };
+        assert_eq!(rules.required_stats, vec!["magic_power".to_string()]);     // 🌳 จาก Magic
+        assert_eq!(rules.allowed_stats.as_ref().map(Vec::len), Some(2)); // 🌳 จาก Weapon
+        assert_eq!(rules.min_required_level, Some(2));                         // 📏 ของ Staff เอง
+
+        let magic = ItemStats {
+            magic_power: Some(5),
+            ..ItemStats::default()
+        };
+        let durable = ItemStats {
+            durability: Some(5),
+            ..magic.clone()
+        };
+        let staff = StaffAdding {
+            required_level: 2,
+            ..item_adding(Category::Staff, magic)
+        };
+        assert!(categories_usecase.item_validating(&staff).await.is_ok());
+        match categories_usecase
+            .item_validating(&StaffAdding {
+                required_level: 1,
+                ..staff.clone()
+            })
+            .await
+        {
+            Ok(_) => panic!("required_level should be too low"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+        }
+        assert!(categories_usecase.item_validating(&item_adding(Category::Sword, durable)).await.is_err());
+
+        let weapons = match categories_usecase.descendants_getting("Weapon").await {
+            Ok(r) => r,
+            Err(_) => panic!("descendants error"),
+        };
+        assert_eq!(weapons, vec!["Weapon", "Magic", "Sword", "Staff"]);
+
+        let tree = match categories_usecase.tree_getting().await {
+            Ok(r) => r,
+            Err(_) => panic!("tree error"),
+        };
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].children.len(), 2);
+        assert_eq!(tree[0].children[0].children[0].effective_rules, rules);
+    }
+
+    // 🧪 attributes ตรวจกับ schema เวอร์ชันล่าสุด: ผ่านได้เวอร์ชันนั้น / ไม่ผ่านได้ 422 พร้อม error ทุก field
+    #[tokio::test]
+    async fn attributes_validating_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock
+            .expect_find_all()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec![category_entity(1, "Staff"), category_entity(3, "Bow")]) }));
+        categories_repository_mock.expect_find_latest_schemas().times(1).returning(|| {
+            Box::pin(async {
+                Ok(vec![CategorySchemasEntity {
+                    version: Some(2),
+                    ..CategorySchemasEntity::new(
+                        1,
+                        json!({
+                            "type": "object",
+                            "properties": {
+                                "element": { "enum": ["fire", "ice"] },
+                                "charges": { "type": "integer", "minimum": 1 }
+                            },
+                            "required": ["element"]
+                        }),
+                        TimerHelper::Mock.creation(),
+                    )
+                }])
+            })
+        });
+
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+        let attributes = |value: serde_json::Value| -> ItemAttributes {
+            match value {
+                serde_json::Value::Object(map) => map,
+                _ => panic!("attributes must be an object"),
+            }
+        };
+
+        match categories_usecase
+            .attributes_validating(&Category::Staff, &attributes(json!({ "element": "fire", "charges": 3 })))
+            .await
+        {
+            Ok(version) => assert_eq!(version, Some(2)),                // 🧾 บันทึกเวอร์ชันที่ใช้ตรวจ
+            Err(_) => panic!("attributes should be valid"),
+        }
+
+        match categories_usecase
+            .attributes_validating(&Category::Staff, &attributes(json!({ "element": "mud", "charges": 0 })))
+            .await
+        {
+            Ok(_) => panic!("attributes should be invalid"),
+            Err(e) => {
+                let error = e.error();
+                assert_eq!(error.status_code, StatusCode::UNPROCESSABLE_ENTITY);
+                let mut fields: Vec<String> = error.fields.into_iter().map(|field| field.field).collect();
+                fields.sort();
+                assert_eq!(fields, vec!["/charges", "/element"]);
+            }
+        }
+
+        // 📭 ประเภทที่ไม่มี schema: attributes ว่างผ่าน (ไม่มีเวอร์ชัน) / มี attributes ไม่ผ่าน
+        let bow = Category::from("Bow".to_string());
+        assert!(matches!(categories_usecase.attributes_validating(&bow, &ItemAttributes::new()).await, Ok(None)));
+        assert!(categories_usecase.attributes_validating(&bow, &attributes(json!({ "draw": 40 }))).await.is_err());
+    }
+
+    // 🧪 schema ที่ไม่ใช่ JSON Schema ที่ถูกต้องต้องได้ 422 และไม่บันทึก
+    #[tokio::test]
+    async fn schema_adding_invalid_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_insert_schema().never();
+
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+
+        match categories_usecase.schema_adding(1, json!({ "type": "not-a-type" })).await {
+            Ok(_) => panic!("schema should be invalid"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+
+    // 🧪 ลบประเภท built-in ไม่ได้ (409) และไม่เรียก delete
+    #[tokio::test]
+    async fn removing_built_in_test() {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Box::pin(async move { Ok(category_entity(id, "Staff")) }));
+        categories_repository_mock.expect_delete().never();
+
+        without_schemas(&mut categories_repository_mock);
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation());
+
+        match categories_usecase.removing(1).await {
+            Ok(_) => panic!("built-in category should not be removed"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::CONFLICT),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// === 🧠 Application Layer: History Use Case ===
+// 📖 ประวัติการแก้ไข item (ใครแก้อะไร เมื่อไร) และการย้อน item กลับไปเป็นค่าของ revision ก่อนหน้า
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    HistoryUsecase ดูแลเฉพาะการอ่าน/ย้อนประวัติ - การบันทึกประวัติอยู่ใน repository พร้อมการแก้ไข
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository และ IntoTimerHelperShared
+
+use std::sync::Arc;
+
+use crate::{
+    entities::{item_revisions::Auditing, items::Items as ItemsEntity},
+    models::{
+        audit::{Actor, ItemAction, ItemRevision},
+        error::{APIError, IntoErrorResponse},
+        etag::Precondition,
+        item::Item,
+    },
+    repositories::items::SharedItemsRepository,
+    time_helper::IntoTimerHelperShared,
+    usecases::items::missing_item,
+};
+
+// 📦 HistoryUsecase struct: ประวัติการแก้ไขของ item
+pub struct HistoryUsecase {
+    items_repository: SharedItemsRepository, // 💾 Repository ของ item และประวัติ
+    timer_helper: IntoTimerHelperShared,     // ⏰ เวลาที่ย้อนกลับ
+}
+
+impl HistoryUsecase {
+    // 🏗️ Factory method: สร้าง HistoryUsecase พร้อม dependencies
+    pub fn creation(items_repository: SharedItemsRepository, timer_helper: IntoTimerHelperShared) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            timer_helper,
+        })
+    }
+
+    // 📜 ประวัติทั้งหมดของ item เรียงจากเก่าไปใหม่ (item ที่ถูกลบแล้วก็ยังดูได้)
+    pub async fn listing(&self, item_id: i32) -> Result<Vec<ItemRevision>, Box<dyn IntoErrorResponse>> {
+        let entities = match self.items_repository.find_revisions(item_id).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+
+        // 📭 ไม่มีประวัติ: item ที่สร้างก่อนมีประวัติได้รายการว่าง / ไม่มี item เลยได้ 404
+        if entities.is_empty() {
+            return match self.items_repository.find_by_id(item_id).await {
+                Ok(_) => Ok(Vec::new()),
+                Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::ItemNotFound(item_id))),
+                Err(e) => Err(Box::new(APIError::FetchingItemError(e))),
+            };
+        }
+
+        let mut revisions = Vec::with_capacity(entities.len());
+        for entity in entities {
+            revisions.push(entity.to_model()?);
+        }
+        Ok(revisions)
+    }
+
+    // ⏪ ย้อน item กลับไปเป็นค่าหลังการแก้ไขของ revision นั้น - บันทึกเป็น revision ใหม่ ("revert")
+    // 🗑️ item ที่ถูกลบต้องกู้คืนก่อน / id, created_at ไม่เปลี่ยน / 🔒 ตรวจ version เหมือนการแก้ไขอื่น
+    pub async fn reverting(
+        &self,
+        item_id: i32,
+        revision: i32,
+        precondition: Precondition,
+        actor: &Actor,
+    ) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let target = match self.items_repository.find_revision(item_id, revision).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemRevisionNotFound(item_id, revision))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        let current = match self.items_repository.find_by_id(item_id).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemNotFound(item_id))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        precondition.checking(current.version)?;
+
+        let snapshot = match target.after.as_ref().and_then(|after| ItemsEntity::from_snapshot(&after.0)) {
+            Some(snapshot) => snapshot,
+            None => {
+                return Err(Box::new(APIError::ItemRevisionNotRevertible(
+                    revision,
+                    "revision has no item snapshot".to_string(),
+                )));
+            }
+        };
+
+        let entity = ItemsEntity {
+            id: current.id,
+            created_at: current.created_at,
+            deleted_at: current.deleted_at,
+            version: current.version,
+            updated_at: self.timer_helper.now(),
+            ..snapshot
+        };
+        let auditing = Auditing::new(ItemAction::Revert, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.update(entity, auditing).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(missing_item(&self.items_repository, item_id, precondition).await),
+            Err(e) => Err(reverting_error(e, revision)),
+        }
+    }
+}
+
+// 🔄 แปลง error ตอนย้อนกลับ: ค่าเดิมขัดกับข้อมูลปัจจุบันบอก client ได้
+fn reverting_error(e: sqlx::Error, revision: i32) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match code.as_deref() {
+        // 🔄 unique violation: ชื่อเดิมถูก item อื่นใช้ไปแล้ว
+        Some("23505") => Box::new(APIError::ItemRevisionNotRevertible(
+            revision,
+            "another item already uses that name".to_string(),
+        )),
+        // 🔗 foreign key violation: ประเภท / เจ้าของ / แบบเดิมไม่มีแล้ว
+        Some("23503") => Box::new(APIError::ItemRevisionNotRevertible(
+            revision,
+            "a referenced category, player or definition no longer exists".to_string(),
+        )),
+        _ => Box::new(APIError::UpdatingItemError(e)),
+    }
+}
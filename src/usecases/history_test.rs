@@ -0,0 +1,95 @@
+// === 🧪 Test Module: History Use Case Tests ===
+// 📖 ทดสอบการอ่านประวัติและการย้อน item กลับไปเป็นค่าของ revision ก่อนหน้าโดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mockall::predicate::eq;
+    use std::sync::Arc;
+
+    use crate::{
+        entities::{
+            item_revisions::{Auditing, ItemRevisions},
+            items::Items as ItemsEntity,
+        },
+        models::{
+            audit::{Actor, ItemAction},
+            etag::Precondition,
+            item::Category,
+        },
+        repositories::items::MockItemsRepository,
+        time_helper::TimerHelper,
+        usecases::history::HistoryUsecase,
+    };
+
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: None,
+        }
+    }
+
+    fn item_entity(id: i32, name: &str) -> ItemsEntity {
+        ItemsEntity {
+            id: Some(id),
+            ..ItemsEntity::new(name.to_string(), Category::Staff.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    // 🧪 ย้อนกลับ: ใช้ค่าหลังแก้ไขของ revision นั้น และบันทึกเป็น revision "revert"
+    #[tokio::test]
+    async fn reverting_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        items_repository_mock
+            .expect_find_revision()
+            .with(eq(1), eq(1))
+            .returning(|item_id, revision| {
+                Box::pin(async move {
+                    let auditing = Auditing::new(ItemAction::Create, &actor(), TimerHelper::Mock.creation());
+                    Ok(ItemRevisions {
+                        revision: Some(revision),
+                        ..auditing.recording(item_id, None, Some(&item_entity(item_id, "wooden staff")))
+                    })
+                })
+            });
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Box::pin(async move { Ok(item_entity(id, "fire staff")) }));
+        items_repository_mock
+            .expect_update()
+            .with(
+                eq(item_entity(1, "wooden staff")),
+                eq(Auditing::new(ItemAction::Revert, &actor(), Arc::clone(&timer_helper))),
+            )
+            .times(1)
+            .returning(|item, _| Box::pin(async move { Ok(item) }));
+
+        let history_usecase = HistoryUsecase::creation(Arc::new(items_repository_mock), timer_helper);
+
+        match history_usecase.reverting(1, 1, Precondition::Any, &actor()).await {
+            Ok(item) => assert_eq!(item.name, "wooden staff"),
+            Err(_) => panic!("reverting error"),
+        }
+    }
+
+    // 🧪 revision ที่ไม่มีต้องได้ 404 และไม่แก้ไข item
+    #[tokio::test]
+    async fn reverting_revision_not_found_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        items_repository_mock
+            .expect_find_revision()
+            .returning(|_, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock.expect_update().never();
+
+        let history_usecase = HistoryUsecase::creation(Arc::new(items_repository_mock), TimerHelper::Mock.creation());
+
+        match history_usecase.reverting(1, 9, Precondition::Any, &actor()).await {
+            Ok(_) => panic!("revision should not exist"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// === 🧠 Application Layer: Import Use Case ===
+// 📖 นำเข้า item ทีละมาก ๆ (เช่นตอนเริ่ม season ใหม่) ผ่านการตรวจเดียวกับ StaffUsecase::adding
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ImportUsecase ดูแลการตรวจรายแถวและแบ่ง batch - การอ่านไฟล์อยู่ที่ models::import
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository, IntoTimerHelperShared และ CategoriesUsecase
+
+use std::{collections::HashSet, sync::Arc};
+
+use axum::http::StatusCode;
+use tracing::warn;
+
+use crate::{
+    entities::{item_revisions::Auditing, items::Items as ItemsEntity},
+    models::{
+        audit::{Actor, ItemAction},
+        error::{APIError, ErrorResponse, IntoErrorResponse},
+        import::{ImportLine, ImportReport, ImportRow, ImportStatus},
+        item::{StaffAdding, name_key},
+    },
+    repositories::items::SharedItemsRepository,
+    setting::Import as ImportSetting,
+    time_helper::IntoTimerHelperShared,
+    usecases::{
+        categories::CategoriesUsecase,
+        staff::{attributes_validating, name_validating, owner_rejecting},
+    },
+};
+
+// 📦 ImportUsecase struct: นำเข้า item ทีละมาก ๆ
+pub struct ImportUsecase {
+    items_repository: SharedItemsRepository,    // 💾 Repository ของ item
+    timer_helper: IntoTimerHelperShared,        // ⏰ เวลาที่สร้าง
+    categories_usecase: Arc<CategoriesUsecase>, // 📚 ตรวจประเภท / กติกา / attributes
+    setting: ImportSetting,                     // 📦 จำนวนแถวต่อ transaction
+}
+
+// ✅ แถวที่ผ่านการตรวจ รอบันทึก (index ของแถวในรายงาน)
+struct Pending {
+    index: usize,
+    entity: ItemsEntity,
+}
+
+impl ImportUsecase {
+    // 🏗️ Factory method: สร้าง ImportUsecase พร้อม dependencies
+    pub fn creation(
+        items_repository: SharedItemsRepository,
+        timer_helper: IntoTimerHelperShared,
+        categories_usecase: Arc<CategoriesUsecase>,
+        setting: ImportSetting,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            timer_helper,
+            categories_usecase,
+            setting,
+        })
+    }
+
+    // 📥 ตรวจทุกแถวแล้วบันทึกทีละ batch_size แถวต่อ transaction - dry_run ตรวจอย่างเดียว
+    // 📋 แถวที่ไม่ผ่านไม่ทำให้แถวอื่นล้ม ผลรายแถวอยู่ในรายงาน
+    pub async fn importing(
+        &self,
+        lines: Vec<ImportLine>,
+        dry_run: bool,
+        actor: &Actor,
+    ) -> Result<ImportReport, Box<dyn IntoErrorResponse>> {
+        let mut rows = Vec::with_capacity(lines.len());
+        let mut pending = Vec::new();
+        let mut seen = HashSet::new(); // 🔄 (เจ้าของ, ประเภท, ชื่อ) ของแถวก่อนหน้าในไฟล์
+
+        for (row, parsed) in lines {
+            let staff = match parsed {
+                Ok(staff) => staff,
+                Err(reason) => {
+                    rows.push(rejected_row(row, None, ImportStatus::Invalid, reason));
+                    continue;
+                }
+            };
+
+            match self.row_validating(&staff, &mut seen).await? {
+                Ok(entity) => {
+                    pending.push(Pending { index: rows.len(), entity });
+                    rows.push(ImportRow {
+                        row,
+                        name: Some(staff.name),
+                        status: ImportStatus::Created,
+                        id: None,
+                        error: None,
+                    });
+                }
+                Err((status, reason)) => rows.push(rejected_row(row, Some(staff.name), status, reason)),
+            }
+        }
+
+        if !dry_run {
+            let mut pending = pending.into_iter().peekable();
+            while pending.peek().is_some() {
+                let batch: Vec<Pending> = pending.by_ref().take(self.setting.batch_size).collect();
+                self.batch_inserting(batch, actor, &mut rows).await;
+            }
+        }
+
+        let created = rows.iter().filter(|row| row.status == ImportStatus::Created).count();
+        Ok(ImportReport {
+            dry_run,
+            total: rows.len(),
+            created,
+            rejected: rows.len() - created,
+            rows,
+        })
+    }
+
+    // ✅ ตรวจแถวเดียวตามลำดับเดียวกับ StaffUsecase::adding
+    // ❌ Err ชั้นนอก = ตรวจต่อไม่ได้ทั้งไฟล์ (เช่นอ่าน catalog ไม่ได้) / Err ชั้นในคือผลของแถวนี้
+    async fn row_validating(
+        &self,
+        staff: &StaffAdding,
+        seen: &mut HashSet<(Option<i32>, String, String)>,
+    ) -> Result<Result<ItemsEntity, (ImportStatus, String)>, Box<dyn IntoErrorResponse>> {
+        // 👤 item ของผู้เล่นต้องผ่านกติกากระเป๋า - นำเข้าได้เฉพาะ item กลางของระบบ
+        if let Err(e) = owner_rejecting(staff.owner_id) {
+            return Ok(Err((ImportStatus::Invalid, e.error().error)));
+        }
+        if let Err(e) = name_validating(&staff.name) {
+            return Ok(Err((ImportStatus::InvalidName, e.error().error)));
+        }
+        // 🏷️ ประเภทที่ไม่มีใน catalog ได้ 400 / อย่างอื่นคือ catalog ใช้ไม่ได้
+        if let Err(e) = self.categories_usecase.rules_getting(&staff.category).await {
+            let error = e.error();
+            return match error.status_code {
+                StatusCode::BAD_REQUEST => Ok(Err((ImportStatus::InvalidCategory, error.error))),
+                _ => Err(e),
+            };
+        }
+        if let Err(e) = attributes_validating(staff) {
+            return Ok(Err((ImportStatus::Invalid, rejection_reason(e.error()))));
+        }
+        let schema_version = match self.categories_usecase.item_validating(staff).await {
+            Ok(schema_version) => schema_version,
+            Err(e) => return Ok(Err((ImportStatus::Invalid, rejection_reason(e.error())))),
+        };
+
+        // 🔄 ชื่อห้ามซ้ำภายในเจ้าของและประเภทเดียวกัน (ไม่สนตัวพิมพ์ - ตรงกับ unique index) ทั้งกับแถวก่อนหน้าในไฟล์และกับที่มีอยู่แล้ว
+        // 💾 อ่านฐานข้อมูลไม่ได้ = ตรวจต่อไม่ได้ทั้งไฟล์ (ไม่ถือว่าไม่ซ้ำ)
+        let category = staff.category.to_string();
+        let duplicate = match seen.insert((staff.owner_id, category.clone(), name_key(&staff.name))) {
+            false => true,
+            true => match self.items_repository.find_by_key(staff.owner_id, category, staff.name.clone()).await {
+                Ok(_) => true,
+                Err(sqlx::Error::RowNotFound) => false,
+                Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+            },
+        };
+        if duplicate {
+            return Ok(Err((ImportStatus::Duplicate, format!("Item is already exists: {}", staff.name))));
+        }
+
+        Ok(Ok(ItemsEntity {
+            attributes_schema_version: schema_version,
+            ..staff.to_entity(Arc::clone(&self.timer_helper))
+        }))
+    }
+
+    // 📦 บันทึกหนึ่ง batch ใน transaction เดียว
+    // 🔁 batch ล้ม (เช่นมีคนเพิ่มชื่อเดียวกันไประหว่างนำเข้า) -> บันทึกทีละแถวเพื่อหาแถวที่ผิด
+    async fn batch_inserting(&self, batch: Vec<Pending>, actor: &Actor, rows: &mut [ImportRow]) {
+        let auditing = Auditing::new(ItemAction::Create, actor, Arc::clone(&self.timer_helper));
+        let entities = batch.iter().map(|pending| pending.entity.clone()).collect();
+
+        match self.items_repository.insert_batch(entities, auditing.clone()).await {
+            Ok(inserted) => {
+                for (pending, item) in batch.iter().zip(inserted) {
+                    rows[pending.index].id = item.id;
+                }
+                return;
+            }
+            Err(e) => warn!("Import batch failed, retrying row by row: {}", e),
+        }
+
+        for pending in batch {
+            let row = &mut rows[pending.index];
+            match self.items_repository.insert(pending.entity, auditing.clone()).await {
+                Ok(item) => row.id = item.id,
+                Err(e) => {
+                    let (status, reason) = inserting_rejection(e, row.name.as_deref().unwrap_or_default());
+                    row.status = status;
+                    row.error = Some(reason);
+                }
+            }
+        }
+    }
+}
+
+// ❌ รายงานของแถวที่ไม่ผ่าน
+fn rejected_row(row: usize, name: Option<String>, status: ImportStatus, reason: String) -> ImportRow {
+    ImportRow {
+        row,
+        name,
+        status,
+        id: None,
+        error: Some(reason),
+    }
+}
+
+// 💬 เหตุผลของแถวที่ไม่ผ่าน รวม error ราย field ของ attributes
+fn rejection_reason(error: ErrorResponse) -> String {
+    let fields: Vec<String> = error
+        .fields
+        .iter()
+        .map(|field| format!("{}: {}", field.field, field.message))
+        .collect();
+    match fields.is_empty() {
+        true => error.error,
+        false => format!("{} ({})", error.error, fields.join("; ")),
+    }
+}
+
+// 🔄 แปลง error ตอนบันทึกทีละแถว: constraint ของฐานข้อมูลบอกเหตุผลรายแถวได้
+fn inserting_rejection(e: sqlx::Error, name: &str) -> (ImportStatus, String) {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match code.as_deref() {
+        // 🔄 unique violation: มีคนเพิ่มชื่อเดียวกันไปก่อนหน้าเล็กน้อย
+        Some("23505") => (ImportStatus::Duplicate, format!("Item is already exists: {}", name)),
+        // 🔗 foreign key violation: ไม่มีผู้เล่นหรือประเภทที่อ้างถึง
+        Some("23503") => (ImportStatus::Invalid, "owner or category does not exist".to_string()),
+        _ => (ImportStatus::Failed, "Failed to add item".to_string()),
+    }
+}
@@ -0,0 +1,255 @@
+// === 🧪 Test Module: Import Use Case Tests ===
+// 📖 ทดสอบการนำเข้า item ทีละมาก ๆ: รายงานรายแถวของ dry_run และการบันทึกเป็น batch โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate::always;
+
+    use crate::{
+        entities::{categories::Categories as CategoriesEntity, items::Items as ItemsEntity},
+        models::{
+            audit::Actor,
+            import::{ImportFormat, ImportLine, ImportStatus},
+            item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding},
+        },
+        repositories::{categories::MockCategoriesRepository, items::MockItemsRepository},
+        setting::Import as ImportSetting,
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, import::ImportUsecase},
+    };
+
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: None,
+        }
+    }
+
+    fn staff(name: &str, category: Category) -> StaffAdding {
+        StaffAdding {
+            name: name.to_string(),
+            category,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats::default(),
+        }
+    }
+
+    // 📚 Mock catalog: มีเฉพาะประเภท Staff
+    fn categories_usecase() -> Arc<CategoriesUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation())
+    }
+
+    fn import_usecase(items_repository_mock: MockItemsRepository, batch_size: usize) -> Arc<ImportUsecase> {
+        ImportUsecase::creation(
+            Arc::new(items_repository_mock),
+            TimerHelper::Mock.creation(),
+            categories_usecase(),
+            ImportSetting {
+                batch_size,
+                max_body_bytes: 1024,
+            },
+        )
+    }
+
+    // 🧪 dry_run: รายงานรายแถว (created / duplicate / invalid_category / invalid_name) โดยไม่บันทึก - ชื่อซ้ำไม่สนตัวพิมพ์และช่องว่าง / แถวที่มี owner_id ไม่ผ่าน
+    #[tokio::test]
+    async fn dry_run_report_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_key()
+            .returning(|_, _, name| {
+                Box::pin(async move {
+                    match name.as_str() {
+                        "old staff" => Ok(ItemsEntity::new(name, Category::Staff.to_string(), TimerHelper::Mock.creation())),
+                        _ => Err(sqlx::Error::RowNotFound),
+                    }
+                })
+            });
+        items_repository_mock.expect_insert_batch().never();
+        items_repository_mock.expect_insert().never();
+
+        let lines: Vec<ImportLine> = vec![
+            (1, Ok(staff("wooden staff", Category::Staff))),
+            (2, Ok(staff("old staff", Category::Staff))),
+            (3, Ok(staff("iron sword", Category::Sword))),
+            (4, Ok(staff("   ", Category::Staff))),
+            (5, Ok(staff("wooden staff", Category::Staff))),
+            (6, Err("missing field `name`".to_string())),
+            (7, Ok(staff("  Wooden  STAFF ", Category::Staff))),
+            (8, Ok(StaffAdding { owner_id: Some(7), ..staff("player staff", Category::Staff) })),
+        ];
+
+        let report = match import_usecase(items_repository_mock, 500).importing(lines, true, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("importing error"),
+        };
+
+        let statuses: Vec<ImportStatus> = report.rows.iter().map(|row| row.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ImportStatus::Created,
+                ImportStatus::Duplicate,
+                ImportStatus::InvalidCategory,
+                ImportStatus::InvalidName,
+                ImportStatus::Duplicate,
+                ImportStatus::Invalid,
+                ImportStatus::Duplicate,
+                ImportStatus::Invalid,
+            ]
+        );
+        assert!(report.dry_run);
+        assert_eq!((report.total, report.created, report.rejected), (8, 1, 7));
+        assert_eq!(report.rows[0].id, None);
+    }
+
+    // 🧪 ชื่อเดียวกันต่างประเภทไม่ซ้ำกัน (unique index คือ เจ้าของ + ประเภท + name_key) ทั้งในไฟล์และกับที่มีอยู่แล้ว
+    #[tokio::test]
+    async fn dry_run_category_scope_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_find_by_name().never();
+        items_repository_mock
+            .expect_find_by_key()
+            .returning(|_, category, name| {
+                Box::pin(async move {
+                    match (category.as_str(), name.as_str()) {
+                        ("Wand", "old rod") => Ok(ItemsEntity::new(name, category, TimerHelper::Mock.creation())),
+                        _ => Err(sqlx::Error::RowNotFound),
+                    }
+                })
+            });
+        items_repository_mock.expect_insert_batch().never();
+
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async {
+                Ok(vec![
+                    CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation()),
+                    CategoriesEntity::new("Wand".to_string(), TimerHelper::Mock.creation()),
+                ])
+            })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        let import_usecase = ImportUsecase::creation(
+            Arc::new(items_repository_mock),
+            TimerHelper::Mock.creation(),
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation()),
+            ImportSetting {
+                batch_size: 500,
+                max_body_bytes: 1024,
+            },
+        );
+
+        let wand = || Category::Catalog("Wand".to_string());
+        let lines: Vec<ImportLine> = vec![
+            (1, Ok(staff("old rod", Category::Staff))),
+            (2, Ok(staff("old rod", wand()))),
+            (3, Ok(staff("twin rod", Category::Staff))),
+            (4, Ok(staff("Twin Rod", wand()))),
+            (5, Ok(staff("twin rod", wand()))),
+        ];
+        let report = match import_usecase.importing(lines, true, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("importing error"),
+        };
+
+        let statuses: Vec<ImportStatus> = report.rows.iter().map(|row| row.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ImportStatus::Created,
+                ImportStatus::Duplicate,
+                ImportStatus::Created,
+                ImportStatus::Created,
+                ImportStatus::Duplicate,
+            ]
+        );
+    }
+
+    // 🧪 บันทึกจริง: แบ่งเป็น batch ละ batch_size แถว แต่ละ batch คือ transaction เดียว
+    #[tokio::test]
+    async fn importing_batches_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_key()
+            .returning(|_, _, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_insert_batch()
+            .with(always(), always())
+            .times(2)
+            .returning(|items, _| {
+                Box::pin(async move {
+                    Ok(items
+                        .into_iter()
+                        .zip(10..)
+                        .map(|(item, id)| ItemsEntity { id: Some(id), ..item })
+                        .collect())
+                })
+            });
+        items_repository_mock.expect_insert().never();
+
+        let lines: Vec<ImportLine> = (1..=3)
+            .map(|row| (row, Ok(staff(&format!("staff {}", row), Category::Staff))))
+            .collect();
+
+        let report = match import_usecase(items_repository_mock, 2).importing(lines, false, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("importing error"),
+        };
+
+        assert_eq!(report.created, 3);
+        let ids: Vec<Option<i32>> = report.rows.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![Some(10), Some(11), Some(10)]);
+    }
+
+    // 🧪 อ่านฐานข้อมูลไม่ได้ตอนตรวจชื่อซ้ำ: ทั้งไฟล์ล้ม (ไม่ถือว่าแถวนั้นไม่ซ้ำ)
+    #[tokio::test]
+    async fn importing_fetch_error_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_key()
+            .returning(|_, _, _| Box::pin(async { Err(sqlx::Error::PoolTimedOut) }));
+        items_repository_mock.expect_insert_batch().never();
+
+        let lines: Vec<ImportLine> = vec![(1, Ok(staff("wooden staff", Category::Staff)))];
+        match import_usecase(items_repository_mock, 500).importing(lines, false, &actor()).await {
+            Ok(_) => panic!("importing should fail"),
+            Err(e) => assert_eq!(e.error().status_code, axum::http::StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+
+    // 🧪 CSV: cell ว่างใช้ค่า default และ stats อ่านเป็น JSON
+    #[test]
+    fn csv_parsing_test() {
+        let body = "name,category,weight,stats\nwooden staff,Staff,3,\"{\"\"magic_power\"\":5}\"\nbad staff,Staff,heavy,\n";
+        let lines = match ImportFormat::Csv.parsing(body.as_bytes()) {
+            Ok(r) => r,
+            Err(_) => panic!("csv parsing error"),
+        };
+
+        assert_eq!(lines.len(), 2);
+        match &lines[0] {
+            (2, Ok(staff)) => {
+                assert_eq!(staff.weight, 3);
+                assert_eq!(staff.stats.magic_power, Some(5));
+            }
+            _ => panic!("row 2 should parse"),
+        }
+        assert!(matches!(&lines[1], (3, Err(_))));
+    }
+}
@@ -0,0 +1,107 @@
+// === 🧠 Application Layer: Instances Use Case ===
+// 📖 สร้าง instance (แถวใน items) จากแบบของ item: สุ่มค่าสถานะจาก base_stats ± stat_variance
+//    และเริ่มความทนทานที่ max_durability - instance ที่มีเจ้าของต้องผ่านกติกาของกระเป๋า
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    InstancesUsecase ดูแลเฉพาะการสร้างชิ้นจริงจากแบบ
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ Repository traits และ IntoRollHelperShared (สุ่มได้ / Mock ได้)
+
+use std::sync::Arc;
+
+use crate::{
+    entities::{item_revisions::Auditing, items::Items as ItemsEntity},
+    models::{
+        audit::{Actor, ItemAction},
+        error::{APIError, IntoErrorResponse},
+        item::{Item, ItemStats, StaffAdding},
+        item_definition::InstanceSpawning,
+    },
+    repositories::{items::SharedItemsRepository, item_definitions::SharedItemDefinitionsRepository},
+    roll_helper::IntoRollHelperShared,
+    time_helper::IntoTimerHelperShared,
+    usecases::{categories::CategoriesUsecase, inventory::InventoryUsecase},
+};
+
+// 📦 InstancesUsecase struct: สร้าง instance จากแบบ
+pub struct InstancesUsecase {
+    item_definitions_repository: SharedItemDefinitionsRepository, // 💾 Repository ของแบบ
+    items_repository: SharedItemsRepository,                      // 💾 Repository ของ instance
+    timer_helper: IntoTimerHelperShared,                          // ⏰ เครื่องมือจัดการเวลา
+    roll_helper: IntoRollHelperShared,                            // 🎲 สุ่มค่าสถานะ
+    categories_usecase: Arc<CategoriesUsecase>,                   // 📚 ตรวจกติกาและ schema ของประเภท
+    inventory_usecase: Arc<InventoryUsecase>,                     // 🎒 ตรวจช่อง/น้ำหนักเมื่อมีเจ้าของ
+}
+
+impl InstancesUsecase {
+    // 🏗️ Factory method: สร้าง InstancesUsecase พร้อม dependencies
+    pub fn creation(
+        item_definitions_repository: SharedItemDefinitionsRepository,
+        items_repository: SharedItemsRepository,
+        timer_helper: IntoTimerHelperShared,
+        roll_helper: IntoRollHelperShared,
+        categories_usecase: Arc<CategoriesUsecase>,
+        inventory_usecase: Arc<InventoryUsecase>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            item_definitions_repository,
+            items_repository,
+            timer_helper,
+            roll_helper,
+            categories_usecase,
+            inventory_usecase,
+        })
+    }
+
+    // 🎲 สร้าง instance ใหม่จากแบบ
+    pub async fn spawning(&self, definition_id: i32, body: InstanceSpawning, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let definition = match self.item_definitions_repository.find_by_id(definition_id).await {
+            Ok(r) => r.to_model()?,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemDefinitionNotFound(definition_id))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemDefinitionError(e))),
+        };
+
+        let staff = StaffAdding {
+            name: definition.name.clone(),
+            category: definition.category.clone(),
+            owner_id: body.owner_id,
+            weight: definition.weight,
+            rarity: definition.rarity.clone(),
+            required_level: definition.required_level,
+            stats: self.stats_rolling(&definition.base_stats, definition.stat_variance),
+            attributes: body.attributes,
+        };
+        let schema_version = self.categories_usecase.item_validating(&staff).await?;
+
+        let entity = ItemsEntity {
+            attributes_schema_version: schema_version,
+            definition_id: Some(definition.id),
+            durability: definition.max_durability,
+            ..staff.to_entity(Arc::clone(&self.timer_helper))
+        };
+
+        // 🎒 มีเจ้าของ -> เข้ากระเป๋าผ่านกติกาของ tier / ไม่มี -> item กลางของระบบ
+        if let Some(player_id) = body.owner_id {
+            return self.inventory_usecase.entity_storing(player_id, entity, actor).await;
+        }
+
+        let auditing = Auditing::new(ItemAction::Create, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.insert(entity, auditing).await {
+            Ok(r) => r.to_model(),
+            Err(e) => Err(Box::new(APIError::AddingItemError(e))),
+        }
+    }
+
+    // 🎲 สุ่มค่าสถานะทุกตัวที่แบบมี (ตัวที่แบบไม่มีก็ไม่มีใน instance)
+    fn stats_rolling(&self, base: &ItemStats, variance: i32) -> ItemStats {
+        let rolled = |value: Option<i32>| value.map(|value| self.roll_helper.roll(value, variance));
+        ItemStats {
+            magic_power: rolled(base.magic_power),
+            attack: rolled(base.attack),
+            durability: rolled(base.durability),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// === 🧪 Test Module: Instances Use Case Tests ===
+// 📖 ทดสอบการสร้าง instance จากแบบโดยใช้ Mock Repository และ Mock การสุ่ม
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::http::StatusCode;
+    use mockall::predicate::{always, eq};
+    use sqlx::types::Json;
+
+    use crate::{
+        entities::{
+            categories::Categories as CategoriesEntity, item_definitions::ItemDefinitions as ItemDefinitionsEntity,
+            items::Items as ItemsEntity, players::Players as PlayersEntity,
+        },
+        models::{audit::Actor, item::ItemStats, item_definition::InstanceSpawning},
+        repositories::{
+            categories::MockCategoriesRepository, item_definitions::MockItemDefinitionsRepository,
+            items::{MockItemsRepository, SharedItemsRepository},
+            players::{MockPlayersRepository, SharedPlayersRepository},
+            unit_of_work::{BoxedTransactionScope, MockTransactionScope, MockUnitOfWork, SharedUnitOfWork},
+        },
+        roll_helper::MockIntoRollHelper,
+        setting::{Inventory as InventorySetting, InventoryTier},
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, instances::InstancesUsecase, inventory::InventoryUsecase},
+    };
+
+    // 👤 GM ที่สร้าง instance
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: None,
+        }
+    }
+
+    // 📜 แบบ Fire Staff: magic_power 10 ± 20% / ความทนทาน 50
+    fn definition_entity(id: i32) -> ItemDefinitionsEntity {
+        ItemDefinitionsEntity {
+            id: Some(id),
+            weight: 2,
+            base_stats: Json(ItemStats {
+                magic_power: Some(10),
+                ..ItemStats::default()
+            }),
+            stat_variance: 20,
+            max_durability: Some(50),
+            ..ItemDefinitionsEntity::new("Fire Staff".to_string(), "Staff".to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    fn categories_usecase() -> Arc<CategoriesUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation())
+    }
+
+    // 🔒 Mock UnitOfWork: scope ใช้ Mock Repository ชุดเดียวกับ usecase
+    fn unit_of_work(items_repository: SharedItemsRepository, players_repository: SharedPlayersRepository) -> SharedUnitOfWork {
+        let mut unit_of_work_mock = MockUnitOfWork::new();
+        unit_of_work_mock.expect_begin().returning(move || {
+            let mut scope_mock = MockTransactionScope::new();
+            scope_mock
+                .expect_items()
+                .return_const(Arc::clone(&items_repository));
+            scope_mock
+                .expect_players()
+                .return_const(Arc::clone(&players_repository));
+            scope_mock
+                .expect_commit()
+                .returning(|| Box::pin(async { Ok(()) }));
+            let scope: BoxedTransactionScope = Box::new(scope_mock);
+            Box::pin(async move { Ok(scope) })
+        });
+        Arc::new(unit_of_work_mock)
+    }
+
+    fn inventory_usecase(items_repository: Arc<MockItemsRepository>) -> Arc<InventoryUsecase> {
+        let player = |id| PlayersEntity {
+            id: Some(id),
+            ..PlayersEntity::new("alice".to_string(), "basic".to_string(), TimerHelper::Mock.creation())
+        };
+        let mut players_repository_mock = MockPlayersRepository::new();
+        players_repository_mock
+            .expect_find_by_id()
+            .returning(move |id| Box::pin(async move { Ok(player(id)) }));
+        players_repository_mock
+            .expect_find_for_update()
+            .returning(move |id| Box::pin(async move { Ok(player(id)) }));
+
+        let items_repository: SharedItemsRepository = items_repository;
+        let players_repository: SharedPlayersRepository = Arc::new(players_repository_mock);
+        InventoryUsecase::creation(
+            Arc::clone(&items_repository),
+            Arc::clone(&players_repository),
+            unit_of_work(items_repository, players_repository),
+            TimerHelper::Mock.creation(),
+            InventorySetting {
+                default_tier: "basic".to_string(),
+                tiers: HashMap::from([(
+                    "basic".to_string(),
+                    InventoryTier {
+                        max_slots: 10,
+                        max_weight: 100,
+                    },
+                )]),
+            },
+            categories_usecase(),
+        )
+    }
+
+    // 🧪 สร้าง instance ให้ผู้เล่น: ค่าสถานะมาจากการสุ่ม / อ้างถึงแบบ / ความทนทานเต็ม
+    //    แม้ผู้เล่นมี Fire Staff อยู่แล้วก็สร้างชิ้นใหม่ได้
+    #[tokio::test]
+    async fn spawning_test() {
+        let mut item_definitions_repository_mock = MockItemDefinitionsRepository::new();
+        let mut items_repository_mock = MockItemsRepository::new();
+        let mut roll_helper_mock = MockIntoRollHelper::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        item_definitions_repository_mock
+            .expect_find_by_id()
+            .with(eq(3))
+            .returning(|id| Box::pin(async move { Ok(definition_entity(id)) }));
+        roll_helper_mock.expect_roll().with(eq(10), eq(20)).times(1).return_const(12);
+
+        let spawned = ItemsEntity {
+            owner_id: Some(7),
+            weight: 2,
+            stats: Json(ItemStats {
+                magic_power: Some(12),
+                ..ItemStats::default()
+            }),
+            definition_id: Some(3),
+            durability: Some(50),
+            ..ItemsEntity::new("Fire Staff".to_string(), "Staff".to_string(), Arc::clone(&timer_helper))
+        };
+        let owned = ItemsEntity {
+            id: Some(1),
+            ..spawned.clone()
+        };
+        items_repository_mock
+            .expect_find_by_owner()
+            .with(eq(7))
+            .returning(move |_| {
+                let owned = owned.clone();
+                Box::pin(async move { Ok(vec![owned]) })
+            });
+        items_repository_mock.expect_find_by_name().never();
+        items_repository_mock
+            .expect_insert()
+            .with(eq(spawned.clone()), always())
+            .times(1)
+            .returning(|item, _| Box::pin(async move { Ok(ItemsEntity { id: Some(2), ..item }) }));
+        items_repository_mock.expect_find_by_id().never();
+
+        let items_repository = Arc::new(items_repository_mock);
+        let instances_usecase = InstancesUsecase::creation(
+            Arc::new(item_definitions_repository_mock),
+            Arc::clone(&items_repository) as _,
+            timer_helper,
+            Arc::new(roll_helper_mock),
+            categories_usecase(),
+            inventory_usecase(items_repository),
+        );
+
+        let item = match instances_usecase
+            .spawning(
+                3,
+                InstanceSpawning {
+                    owner_id: Some(7),
+                    ..InstanceSpawning::default()
+                },
+                &actor(),
+            )
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => panic!("spawning error"),
+        };
+
+        assert_eq!(item.stats.magic_power, Some(12));
+        assert_eq!(item.definition_id, Some(3));
+        assert_eq!(item.durability, Some(50));
+        assert_eq!(item.owner_id, Some(7));
+    }
+
+    // 🧪 แบบที่ไม่มีอยู่ต้องได้ 404 และไม่ insert
+    #[tokio::test]
+    async fn spawning_definition_not_found_test() {
+        let mut item_definitions_repository_mock = MockItemDefinitionsRepository::new();
+        let mut items_repository_mock = MockItemsRepository::new();
+        let mut roll_helper_mock = MockIntoRollHelper::new();
+
+        item_definitions_repository_mock
+            .expect_find_by_id()
+            .with(eq(99))
+            .returning(|_| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock.expect_insert().with(always(), always()).never();
+        roll_helper_mock.expect_roll().never();
+
+        let items_repository = Arc::new(items_repository_mock);
+        let instances_usecase = InstancesUsecase::creation(
+            Arc::new(item_definitions_repository_mock),
+            Arc::clone(&items_repository) as _,
+            TimerHelper::Mock.creation(),
+            Arc::new(roll_helper_mock),
+            categories_usecase(),
+            inventory_usecase(items_repository),
+        );
+
+        match instances_usecase.spawning(99, InstanceSpawning::default(), &actor()).await {
+            Ok(_) => panic!("definition should not exist"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+}
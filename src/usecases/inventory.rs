@@ -0,0 +1,267 @@
+// === 🧠 Application Layer: Inventory Use Case ===
+// 📖 กติกาของกระเป๋าผู้เล่น: จำนวนช่อง (slot) และน้ำหนักรวมต้องไม่เกิน limit ของ tier
+//    ตรวจทุกครั้งที่มี item เข้ากระเป๋า ทั้งการเพิ่มใหม่และการรับโอนจากผู้เล่นอื่น
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    InventoryUsecase ดูแลเฉพาะกติกาความจุของกระเป๋า
+//
+// 2️⃣ Open/Closed Principle (OCP):
+//    เพิ่ม tier ใหม่ได้จาก Setting.toml โดยไม่แก้โค้ด
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository / SharedPlayersRepository (abstractions)
+//
+// 👤 item ได้เจ้าของผ่าน use case นี้เท่านั้น - POST / PUT /items/staff ไม่รับ owner_id (staff::owner_rejecting)
+
+use std::sync::Arc;
+
+use crate::{
+    entities::{item_revisions::Auditing, items::Items as ItemsEntity, players::Players as PlayersEntity},
+    models::{
+        audit::{Actor, ItemAction},
+        error::{APIError, IntoErrorResponse},
+        etag::Precondition,
+        inventory::Inventory,
+        item::{Item, StaffAdding},
+    },
+    repositories::{items::SharedItemsRepository, players::SharedPlayersRepository, unit_of_work::SharedUnitOfWork},
+    setting::{Inventory as InventorySetting, InventoryTier},
+    time_helper::IntoTimerHelperShared,
+    usecases::{categories::CategoriesUsecase, items::missing_item, staff::attributes_validating},
+};
+
+// 📦 InventoryUsecase struct: จัดการกติกาของกระเป๋าผู้เล่น
+pub struct InventoryUsecase {
+    items_repository: SharedItemsRepository,     // 💾 Repository ของ item
+    players_repository: SharedPlayersRepository, // 💾 Repository ของผู้เล่น
+    unit_of_work: SharedUnitOfWork,              // 🔒 ตรวจความจุแล้วเขียนใน transaction เดียว
+    timer_helper: IntoTimerHelperShared,         // ⏰ เครื่องมือจัดการเวลา
+    setting: InventorySetting,                   // 📏 limit ของแต่ละ tier
+    categories_usecase: Arc<CategoriesUsecase>,  // 📚 ตรวจประเภทกับ catalog
+}
+
+impl InventoryUsecase {
+    // 🏗️ Factory method: สร้าง InventoryUsecase พร้อม dependencies
+    pub fn creation(
+        items_repository: SharedItemsRepository,
+        players_repository: SharedPlayersRepository,
+        unit_of_work: SharedUnitOfWork,
+        timer_helper: IntoTimerHelperShared,
+        setting: InventorySetting,
+        categories_usecase: Arc<CategoriesUsecase>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            players_repository,
+            unit_of_work,
+            timer_helper,
+            setting,
+            categories_usecase,
+        })
+    }
+
+    // 🎒 ดูสรุปการใช้งานกระเป๋าของผู้เล่น
+    pub async fn getting(&self, player_id: i32) -> Result<Inventory, Box<dyn IntoErrorResponse>> {
+        let player = player_getting(&self.players_repository, player_id).await?;
+        let owned = owned_getting(&self.items_repository, player_id).await?;
+        let tier = self.tier_getting(&player);
+
+        Ok(Inventory {
+            player_id,
+            tier: player.tier.clone(),
+            used_slots: owned.len(),
+            max_slots: tier.max_slots,
+            used_weight: total_weight(&owned),
+            max_weight: tier.max_weight,
+        })
+    }
+
+    // ➕ เพิ่ม item ใหม่เข้ากระเป๋าผู้เล่น
+    pub async fn storing(&self, player_id: i32, staff: StaffAdding, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        attributes_validating(&staff)?;
+        let schema_version = self.categories_usecase.item_validating(&staff).await?;
+
+        let player = player_getting(&self.players_repository, player_id).await?;
+
+        // 🔍 ชื่อห้ามซ้ำภายในกระเป๋าเดียวกัน (item ที่ไม่มีแบบ)
+        if self.items_repository.find_by_name(Some(player_id), staff.name.clone()).await.is_ok() {
+            return Err(Box::new(APIError::ItemAlreadyExists(staff.name.clone())));
+        }
+
+        let entity = ItemsEntity {
+            attributes_schema_version: schema_version, // 🧾 เวอร์ชัน schema ที่ใช้ตรวจ attributes
+            ..staff.to_entity(Arc::clone(&self.timer_helper))
+        };
+        self.admitted_storing(&player, entity, actor).await
+    }
+
+    // 🎲 เพิ่ม instance ที่สร้างจากแบบเข้ากระเป๋าผู้เล่น (ตรวจแบบและ attributes มาแล้ว)
+    pub async fn entity_storing(&self, player_id: i32, entity: ItemsEntity, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let player = player_getting(&self.players_repository, player_id).await?;
+        self.admitted_storing(&player, entity, actor).await
+    }
+
+    // 🎒 ตรวจช่องและน้ำหนักแล้วบันทึก - เจ้าของคือผู้เล่นคนนี้เสมอ
+    // 🔒 ล็อกผู้เล่น -> อ่านกระเป๋า -> เพิ่ม -> อ่านกลับ ใน transaction เดียว
+    async fn admitted_storing(&self, player: &PlayersEntity, entity: ItemsEntity, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let player_id = match player.id {
+            Some(id) => id,
+            None => return Err(Box::new(APIError::MissingEntityId(player.name.clone()))),
+        };
+        let scope = match self.unit_of_work.begin().await {
+            Ok(scope) => scope,
+            Err(e) => return Err(Box::new(APIError::AddingItemError(e))),
+        };
+        let items_repository = scope.items();
+
+        // 🔒 request อื่นที่เพิ่ม item ให้ผู้เล่นคนนี้ต้องรอจน commit - ไม่เห็นกระเป๋าที่ยังว่าง 19/20 พร้อมกัน
+        let player = player_locking(&scope.players(), player_id).await?;
+        let owned = owned_getting(&items_repository, player_id).await?;
+        self.admitting(&player, &owned, entity.weight)?;
+
+        let item = match items_repository
+            .insert(ItemsEntity {
+                owner_id: Some(player_id),           // 👤 เจ้าของคือผู้เล่นใน path เสมอ
+                ..entity
+            }, Auditing::new(ItemAction::Create, actor, Arc::clone(&self.timer_helper)))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::AddingItemError(e))),
+        };
+        if let Err(e) = scope.commit().await {
+            return Err(Box::new(APIError::AddingItemError(e)));
+        }
+        item.to_model()
+    }
+
+    // 🔁 โอน item ไปให้ผู้เล่นอีกคน - ผู้รับต้องมีที่ว่างพอ
+    // 🔒 โอนเฉพาะเมื่อ item ยังเป็น version ที่อ่านมา (และตรงกับ precondition ของ client)
+    // 🔒 ตรวจผู้รับ -> เปลี่ยนเจ้าของ ใน transaction เดียว
+    pub async fn transferring(
+        &self,
+        item_id: i32,
+        to_player_id: i32,
+        precondition: Precondition,
+        actor: &Actor,
+    ) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let scope = match self.unit_of_work.begin().await {
+            Ok(scope) => scope,
+            Err(e) => return Err(Box::new(APIError::UpdatingItemError(e))),
+        };
+        let items_repository = scope.items();
+
+        let item = match items_repository.find_by_id(item_id).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemNotFound(item_id))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        precondition.checking(item.version)?;
+
+        // ✅ อยู่กับผู้รับอยู่แล้ว ไม่ต้องทำอะไร
+        if item.owner_id == Some(to_player_id) {
+            return item.to_model();
+        }
+
+        // 🔒 ล็อกผู้รับก่อนอ่านกระเป๋า (เหมือน admitted_storing)
+        let player = player_locking(&scope.players(), to_player_id).await?;
+        // 🔍 instance ของแบบเดียวกันมีหลายชิ้นได้ - ชื่อห้ามซ้ำเฉพาะ item ที่ไม่มีแบบ
+        if item.definition_id.is_none()
+            && items_repository.find_by_name(Some(to_player_id), item.name.clone()).await.is_ok()
+        {
+            return Err(Box::new(APIError::ItemAlreadyExists(item.name.clone())));
+        }
+
+        let owned = owned_getting(&items_repository, to_player_id).await?;
+        self.admitting(&player, &owned, item.weight)?;
+
+        let transferred = match items_repository
+            .update_owner(
+                item_id,
+                to_player_id,
+                self.timer_helper.now(),
+                Some(item.version),
+                Auditing::new(ItemAction::Transfer, actor, Arc::clone(&self.timer_helper)),
+            )
+            .await
+        {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(missing_item(&self.items_repository, item_id, precondition).await),
+            Err(e) => return Err(Box::new(APIError::UpdatingItemError(e))),
+        };
+        if let Err(e) = scope.commit().await {
+            return Err(Box::new(APIError::UpdatingItemError(e)));
+        }
+        transferred.to_model()
+    }
+
+    // 📏 ตรวจว่ารับ item น้ำหนัก incoming_weight เพิ่มได้หรือไม่
+    fn admitting(&self, player: &PlayersEntity, owned: &[ItemsEntity], incoming_weight: i32) -> Result<(), Box<dyn IntoErrorResponse>> {
+        let tier = self.tier_getting(player);
+
+        if owned.len() + 1 > tier.max_slots {
+            return Err(Box::new(APIError::InventoryFull(format!(
+                "no free slot ({}/{})",
+                owned.len(),
+                tier.max_slots
+            ))));
+        }
+
+        let weight = total_weight(owned) + i64::from(incoming_weight);
+        if weight > tier.max_weight {
+            return Err(Box::new(APIError::InventoryFull(format!(
+                "weight {} exceeds limit {}",
+                weight, tier.max_weight
+            ))));
+        }
+
+        Ok(())
+    }
+
+    // 🏅 limit ของ tier ผู้เล่น (ถ้าไม่มีใน Setting.toml ใช้ default_tier)
+    fn tier_getting(&self, player: &PlayersEntity) -> InventoryTier {
+        self.setting
+            .tiers
+            .get(&player.tier)
+            .or_else(|| self.setting.tiers.get(&self.setting.default_tier))
+            .cloned()
+            .unwrap_or(InventoryTier {
+                max_slots: 0,
+                max_weight: 0,
+            })
+    }
+
+}
+
+// 🎮 ดึงผู้เล่น (ไม่พบ -> 404) - repository ปกติหรือของ transaction
+async fn player_getting(players_repository: &SharedPlayersRepository, player_id: i32) -> Result<PlayersEntity, Box<dyn IntoErrorResponse>> {
+    match players_repository.find_by_id(player_id).await {
+        Ok(r) => Ok(r),
+        Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::PlayerNotFound(player_id))),
+        Err(e) => Err(Box::new(APIError::FetchingPlayerError(e))),
+    }
+}
+
+// 🔒 ดึงผู้เล่นพร้อมล็อกแถว (ไม่พบ -> 404) - ใช้กับ repository ของ transaction
+async fn player_locking(players_repository: &SharedPlayersRepository, player_id: i32) -> Result<PlayersEntity, Box<dyn IntoErrorResponse>> {
+    match players_repository.find_for_update(player_id).await {
+        Ok(r) => Ok(r),
+        Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::PlayerNotFound(player_id))),
+        Err(e) => Err(Box::new(APIError::FetchingPlayerError(e))),
+    }
+}
+
+// 🎒 item ทั้งหมดในกระเป๋า - repository ปกติหรือของ transaction
+async fn owned_getting(items_repository: &SharedItemsRepository, player_id: i32) -> Result<Vec<ItemsEntity>, Box<dyn IntoErrorResponse>> {
+    match items_repository.find_by_owner(player_id).await {
+        Ok(r) => Ok(r),
+        Err(e) => Err(Box::new(APIError::FetchingItemError(e))),
+    }
+}
+
+// ⚖️ น้ำหนักรวมของ item (ใช้ i64 กันล้น)
+fn total_weight(items: &[ItemsEntity]) -> i64 {
+    items.iter().map(|item| i64::from(item.weight)).sum()
+}
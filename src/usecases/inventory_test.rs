@@ -0,0 +1,312 @@
+// === 🧪 Test Module: Inventory Use Case Tests ===
+// 📖 ทดสอบกติกาช่องและน้ำหนักของกระเป๋าผู้เล่นโดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::http::StatusCode;
+    use mockall::{Sequence, predicate::eq};
+    use sqlx::types::Json;
+
+    use crate::{
+        entities::{
+            categories::Categories as CategoriesEntity, item_revisions::Auditing, items::Items as ItemsEntity,
+            players::Players as PlayersEntity,
+        },
+        models::{
+            audit::{Actor, ItemAction},
+            etag::Precondition,
+            item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding},
+        },
+        repositories::{
+            categories::MockCategoriesRepository,
+            items::{MockItemsRepository, SharedItemsRepository},
+            players::{MockPlayersRepository, SharedPlayersRepository},
+            unit_of_work::{BoxedTransactionScope, MockTransactionScope, MockUnitOfWork, SharedUnitOfWork},
+        },
+        setting::{Inventory as InventorySetting, InventoryTier},
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, inventory::InventoryUsecase},
+    };
+
+    // 👤 GM ที่เป็นผู้แก้ไข (บันทึกลงประวัติ)
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: None,
+        }
+    }
+
+    // 🏅 basic: 2 ช่อง / 10 น้ำหนัก
+    fn inventory_setting() -> InventorySetting {
+        InventorySetting {
+            default_tier: "basic".to_string(),
+            tiers: HashMap::from([(
+                "basic".to_string(),
+                InventoryTier {
+                    max_slots: 2,
+                    max_weight: 10,
+                },
+            )]),
+        }
+    }
+
+    fn player_entity(id: i32) -> PlayersEntity {
+        PlayersEntity {
+            id: Some(id),
+            ..PlayersEntity::new("alice".to_string(), "basic".to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    fn item_entity(id: i32, owner_id: i32, weight: i32) -> ItemsEntity {
+        ItemsEntity {
+            id: Some(id),
+            owner_id: Some(owner_id),
+            weight,
+            ..ItemsEntity::new(format!("staff {}", id), Category::Staff.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    fn players_repository_mock() -> MockPlayersRepository {
+        let mut players_repository_mock = MockPlayersRepository::new();
+        players_repository_mock
+            .expect_find_by_id()
+            .returning(|id| Box::pin(async move { Ok(player_entity(id)) }));
+        players_repository_mock
+            .expect_find_for_update()
+            .returning(|id| Box::pin(async move { Ok(player_entity(id)) }));
+        players_repository_mock
+    }
+
+    // 📚 catalog ที่มี Staff และ Sword
+    fn categories_usecase() -> Arc<CategoriesUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async {
+                let t = TimerHelper::Mock.creation();
+                Ok(vec![
+                    CategoriesEntity::new("Staff".to_string(), Arc::clone(&t)),
+                    CategoriesEntity::new("Sword".to_string(), t),
+                ])
+            })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation())
+    }
+
+    // 🔒 Mock UnitOfWork: scope ใช้ Mock Repository ชุดเดียวกับ usecase / commit ได้ ตามที่กำหนด (0 = ต้องไม่ commit)
+    fn unit_of_work(
+        items_repository: SharedItemsRepository,
+        players_repository: SharedPlayersRepository,
+        commits: usize,
+    ) -> SharedUnitOfWork {
+        let mut unit_of_work_mock = MockUnitOfWork::new();
+        unit_of_work_mock.expect_begin().returning(move || {
+            let mut scope_mock = MockTransactionScope::new();
+            scope_mock
+                .expect_items()
+                .return_const(Arc::clone(&items_repository));
+            scope_mock
+                .expect_players()
+                .return_const(Arc::clone(&players_repository));
+            scope_mock
+                .expect_commit()
+                .times(commits)
+                .returning(|| Box::pin(async { Ok(()) }));
+            let scope: BoxedTransactionScope = Box::new(scope_mock);
+            Box::pin(async move { Ok(scope) })
+        });
+        Arc::new(unit_of_work_mock)
+    }
+
+    fn staff_adding(weight: i32) -> StaffAdding {
+        StaffAdding {
+            name: "fire staff".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight,
+            stats: ItemStats {
+                magic_power: Some(5),
+                ..ItemStats::default()
+            },
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+        }
+    }
+
+    // 🧪 มีที่ว่างพอ: เพิ่มได้และเจ้าของคือผู้เล่นใน path
+    #[tokio::test]
+    async fn storing_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let mut players_repository_mock = MockPlayersRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+        let mut sequence = Sequence::new();
+
+        players_repository_mock
+            .expect_find_by_id()
+            .returning(|id| Box::pin(async move { Ok(player_entity(id)) }));
+        items_repository_mock
+            .expect_find_by_name()
+            .with(eq(Some(7)), eq("fire staff".to_string()))
+            .returning(|_, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        // 🔒 ล็อกผู้เล่นก่อนอ่านกระเป๋าเสมอ
+        players_repository_mock
+            .expect_find_for_update()
+            .with(eq(7))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|id| Box::pin(async move { Ok(player_entity(id)) }));
+        items_repository_mock
+            .expect_find_by_owner()
+            .with(eq(7))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|owner_id| Box::pin(async move { Ok(vec![item_entity(1, owner_id, 4)]) }));
+        items_repository_mock
+            .expect_insert()
+            .with(eq(ItemsEntity {
+                owner_id: Some(7),
+                weight: 6,
+                stats: Json(ItemStats {
+                    magic_power: Some(5),
+                    ..ItemStats::default()
+                }),
+                ..ItemsEntity::new("fire staff".to_string(), Category::Staff.to_string(), Arc::clone(&timer_helper))
+            }), eq(Auditing::new(ItemAction::Create, &actor(), Arc::clone(&timer_helper))))
+            .times(1)
+            .returning(|item, _| Box::pin(async move { Ok(ItemsEntity { id: Some(2), ..item }) }));
+        items_repository_mock.expect_find_by_id().never();
+
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+        let players_repository: SharedPlayersRepository = Arc::new(players_repository_mock);
+        let inventory_usecase = InventoryUsecase::creation(
+            Arc::clone(&items_repository),
+            Arc::clone(&players_repository),
+            unit_of_work(items_repository, players_repository, 1),
+            timer_helper,
+            inventory_setting(),
+            categories_usecase(),
+        );
+
+        let item = match inventory_usecase.storing(7, staff_adding(6), &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("storing error"),
+        };
+
+        assert_eq!(item.owner_id, Some(7));
+        assert_eq!(item.weight, 6);
+    }
+
+    // 🧪 ช่องเต็ม: ต้องได้ 409 และไม่ insert
+    #[tokio::test]
+    async fn storing_slots_full_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        items_repository_mock
+            .expect_find_by_name()
+            .returning(|_, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_owner()
+            .returning(|owner_id| Box::pin(async move { Ok(vec![item_entity(1, owner_id, 1), item_entity(2, owner_id, 1)]) }));
+        items_repository_mock.expect_insert().never();
+
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+        let players_repository: SharedPlayersRepository = Arc::new(players_repository_mock());
+        let inventory_usecase = InventoryUsecase::creation(
+            Arc::clone(&items_repository),
+            Arc::clone(&players_repository),
+            unit_of_work(items_repository, players_repository, 0),
+            TimerHelper::Mock.creation(),
+            inventory_setting(),
+            categories_usecase(),
+        );
+
+        match inventory_usecase.storing(7, staff_adding(1), &actor()).await {
+            Ok(_) => panic!("inventory should be full"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::CONFLICT),
+        }
+    }
+
+    // 🧪 น้ำหนักเกิน: ยังมีช่องว่างแต่ต้องได้ 409
+    #[tokio::test]
+    async fn storing_overweight_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        items_repository_mock
+            .expect_find_by_name()
+            .returning(|_, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_owner()
+            .returning(|owner_id| Box::pin(async move { Ok(vec![item_entity(1, owner_id, 8)]) }));
+        items_repository_mock.expect_insert().never();
+
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+        let players_repository: SharedPlayersRepository = Arc::new(players_repository_mock());
+        let inventory_usecase = InventoryUsecase::creation(
+            Arc::clone(&items_repository),
+            Arc::clone(&players_repository),
+            unit_of_work(items_repository, players_repository, 0),
+            TimerHelper::Mock.creation(),
+            inventory_setting(),
+            categories_usecase(),
+        );
+
+        match inventory_usecase.storing(7, staff_adding(3), &actor()).await {
+            Ok(_) => panic!("inventory should be overweight"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::CONFLICT),
+        }
+    }
+
+    // 🧪 โอน item: ผู้รับมีที่ว่าง -> เปลี่ยนเจ้าของ
+    #[tokio::test]
+    async fn transferring_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Box::pin(async move { Ok(item_entity(id, 7, 5)) }));
+        items_repository_mock
+            .expect_find_by_name()
+            .with(eq(Some(8)), eq("staff 1".to_string()))
+            .returning(|_, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_owner()
+            .with(eq(8))
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        items_repository_mock
+            .expect_update_owner()
+            .with(
+                eq(1),
+                eq(8),
+                eq(timer_helper.now()),
+                eq(Some(1)),
+                eq(Auditing::new(ItemAction::Transfer, &actor(), Arc::clone(&timer_helper))),
+            )
+            .times(1)
+            .returning(|id, owner_id, _, _, _| Box::pin(async move { Ok(item_entity(id, owner_id, 5)) }));
+
+        let items_repository: SharedItemsRepository = Arc::new(items_repository_mock);
+        let players_repository: SharedPlayersRepository = Arc::new(players_repository_mock());
+        let inventory_usecase = InventoryUsecase::creation(
+            Arc::clone(&items_repository),
+            Arc::clone(&players_repository),
+            unit_of_work(items_repository, players_repository, 1),
+            timer_helper,
+            inventory_setting(),
+            categories_usecase(),
+        );
+
+        let item = match inventory_usecase.transferring(1, 8, Precondition::Any, &actor()).await {
+            Ok(r) => r,
+            Err(_) => panic!("transferring error"),
+        };
+
+        assert_eq!(item.owner_id, Some(8));
+    }
+}
@@ -0,0 +1,140 @@
+// === 🧠 Application Layer: Item Definitions Use Case ===
+// 📖 แบบของ item ที่ designer ดูแล: ชื่อ ประเภท ค่าสถานะตั้งต้น และไอคอน
+//    แบบต้องผ่านกติกาของประเภทเหมือน item ทั่วไป - instance ที่สร้างไปแล้วไม่เปลี่ยนตามเมื่อแก้แบบ
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ItemDefinitionsUsecase ดูแลเฉพาะแบบของ item (instance อยู่ใน InstancesUsecase)
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemDefinitionsRepository (abstraction)
+
+use std::sync::Arc;
+
+use crate::{
+    entities::item_definitions::ItemDefinitions as ItemDefinitionsEntity,
+    models::{
+        error::{APIError, IntoErrorResponse},
+        item_definition::{ItemDefinition, ItemDefinitionAdding},
+    },
+    repositories::item_definitions::SharedItemDefinitionsRepository,
+    time_helper::IntoTimerHelperShared,
+    usecases::{categories::CategoriesUsecase, staff::attributes_validating},
+};
+
+// 📦 ItemDefinitionsUsecase struct: จัดการแบบของ item
+pub struct ItemDefinitionsUsecase {
+    item_definitions_repository: SharedItemDefinitionsRepository, // 💾 Repository ของแบบ
+    timer_helper: IntoTimerHelperShared,                          // ⏰ เครื่องมือจัดการเวลา
+    categories_usecase: Arc<CategoriesUsecase>,                   // 📚 ตรวจแบบกับกติกาของประเภท
+}
+
+impl ItemDefinitionsUsecase {
+    // 🏗️ Factory method: สร้าง ItemDefinitionsUsecase พร้อม dependencies
+    pub fn creation(
+        item_definitions_repository: SharedItemDefinitionsRepository,
+        timer_helper: IntoTimerHelperShared,
+        categories_usecase: Arc<CategoriesUsecase>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            item_definitions_repository,
+            timer_helper,
+            categories_usecase,
+        })
+    }
+
+    // 📋 ดูแบบทั้งหมด
+    pub async fn listing(&self) -> Result<Vec<ItemDefinition>, Box<dyn IntoErrorResponse>> {
+        let entities = match self.item_definitions_repository.find_all().await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemDefinitionError(e))),
+        };
+
+        let mut definitions = Vec::with_capacity(entities.len());
+        for entity in entities {
+            definitions.push(entity.to_model()?);
+        }
+        Ok(definitions)
+    }
+
+    // 🔍 ดูแบบตาม ID
+    pub async fn getting(&self, id: i32) -> Result<ItemDefinition, Box<dyn IntoErrorResponse>> {
+        self.found_getting(id).await?.to_model()
+    }
+
+    // ➕ เพิ่มแบบใหม่
+    pub async fn adding(&self, definition: ItemDefinitionAdding) -> Result<ItemDefinition, Box<dyn IntoErrorResponse>> {
+        self.definition_validating(&definition).await?;
+
+        let entity = definition.to_entity(Arc::clone(&self.timer_helper));
+        let name = entity.name.clone();
+        match self.item_definitions_repository.insert(entity).await {
+            Ok(r) => r.to_model(),
+            Err(e) => Err(saving_error(e, &name)),
+        }
+    }
+
+    // ✏️ แก้แบบทั้งก้อน - มีผลกับ instance ที่สร้างหลังจากนี้เท่านั้น
+    pub async fn updating(&self, id: i32, definition: ItemDefinitionAdding) -> Result<ItemDefinition, Box<dyn IntoErrorResponse>> {
+        self.definition_validating(&definition).await?;
+        let found = self.found_getting(id).await?;
+
+        let entity = ItemDefinitionsEntity {
+            id: found.id,
+            created_at: found.created_at,
+            ..definition.to_entity(Arc::clone(&self.timer_helper))
+        };
+        let name = entity.name.clone();
+        match self.item_definitions_repository.update(entity).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::ItemDefinitionNotFound(id))),
+            Err(e) => Err(saving_error(e, &name)),
+        }
+    }
+
+    // 🔍 ดึงแบบตาม ID (ไม่พบ -> 404)
+    async fn found_getting(&self, id: i32) -> Result<ItemDefinitionsEntity, Box<dyn IntoErrorResponse>> {
+        match self.item_definitions_repository.find_by_id(id).await {
+            Ok(r) => Ok(r),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::ItemDefinitionNotFound(id))),
+            Err(e) => Err(Box::new(APIError::FetchingItemDefinitionError(e))),
+        }
+    }
+
+    // ✅ แบบต้องผ่านกติกาเดียวกับ item ใหม่ และช่วงสุ่ม/ความทนทานต้องใช้ได้
+    async fn definition_validating(&self, definition: &ItemDefinitionAdding) -> Result<(), Box<dyn IntoErrorResponse>> {
+        let staff = definition.to_staff_adding();
+        if staff.name.is_empty() {
+            return Err(Box::new(APIError::InvalidItemAttributes("name must not be empty".to_string())));
+        }
+        attributes_validating(&staff)?;
+
+        if !(0..=100).contains(&definition.stat_variance) {
+            return Err(Box::new(APIError::InvalidItemAttributes(
+                "stat_variance must be between 0 and 100".to_string(),
+            )));
+        }
+        if definition.max_durability.is_some_and(|durability| durability < 1) {
+            return Err(Box::new(APIError::InvalidItemAttributes(
+                "max_durability must be at least 1".to_string(),
+            )));
+        }
+
+        self.categories_usecase.rules_checking(&staff).await
+    }
+}
+
+// 🔄 แปลง error ตอนบันทึก: ชื่อซ้ำในประเภทเดียวกันบอก client ได้
+fn saving_error(e: sqlx::Error, name: &str) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match code.as_deref() {
+        // 🔄 unique violation: มีแบบชื่อนี้ในประเภทนี้แล้ว
+        Some("23505") => Box::new(APIError::ItemDefinitionAlreadyExists(name.to_string())),
+        _ => Box::new(APIError::SavingItemDefinitionError(e)),
+    }
+}
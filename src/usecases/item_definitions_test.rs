@@ -0,0 +1,65 @@
+// === 🧪 Test Module: Item Definitions Use Case Tests ===
+// 📖 ทดสอบการตรวจแบบของ item โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::http::StatusCode;
+
+    use crate::{
+        entities::categories::Categories as CategoriesEntity,
+        models::{
+            item::{Category, ItemStats, Rarity},
+            item_definition::ItemDefinitionAdding,
+        },
+        repositories::{categories::MockCategoriesRepository, item_definitions::MockItemDefinitionsRepository},
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, item_definitions::ItemDefinitionsUsecase},
+    };
+
+    fn definition_adding(stat_variance: i32, max_durability: Option<i32>) -> ItemDefinitionAdding {
+        ItemDefinitionAdding {
+            name: "Fire Staff".to_string(),
+            category: Category::Staff,
+            weight: 2,
+            rarity: Rarity::Common,
+            required_level: 1,
+            base_stats: ItemStats {
+                magic_power: Some(10),
+                ..ItemStats::default()
+            },
+            stat_variance,
+            max_durability,
+            icon: None,
+        }
+    }
+
+    // 🧪 stat_variance เกิน 100 หรือ max_durability ต่ำกว่า 1 ต้องได้ 422 และไม่ insert
+    #[tokio::test]
+    async fn adding_invalid_test() {
+        let mut item_definitions_repository_mock = MockItemDefinitionsRepository::new();
+        item_definitions_repository_mock.expect_insert().never();
+
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+
+        let item_definitions_usecase = ItemDefinitionsUsecase::creation(
+            Arc::new(item_definitions_repository_mock),
+            TimerHelper::Mock.creation(),
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation()),
+        );
+
+        for definition in [definition_adding(150, Some(50)), definition_adding(20, Some(0))] {
+            match item_definitions_usecase.adding(definition).await {
+                Ok(_) => panic!("definition should be invalid"),
+                Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+            }
+        }
+    }
+}
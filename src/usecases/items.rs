@@ -0,0 +1,145 @@
+// === 🧠 Application Layer: Items Use Case ===
+// 📖 ตรรกะทางธุรกิจสำหรับการอ่านข้อมูล Item (ดูรายการ / ดูทีละชิ้น) พร้อม ETag
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    ItemsUsecase ดูแลการอ่าน Item - การเพิ่ม Staff ยังอยู่ที่ StaffUsecase
+//
+// 4️⃣ Interface Segregation Principle (ISP):
+//    ใช้เฉพาะ method อ่านข้อมูลจาก ItemsRepository
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository ไม่ใช่ concrete implementation
+
+use std::{io, sync::Arc};
+
+use axum::body::Bytes;
+use futures_util::{StreamExt, stream::BoxStream};
+
+use crate::{
+    entities::items::ItemsFilter,              // 🔎 เงื่อนไขของ Repository
+    models::{
+        error::{APIError, IntoErrorResponse},  // 📋 Error handling models
+        etag::{Precondition, Tagged, item_etag, list_etag}, // 🏷️ ETag
+        export::{ExportQuery, ExportedItem},   // 📤 รูปแบบไฟล์ export
+        filter::filter_parsing,                // 🧮 ?filter= แบบ RSQL
+        item::{Item, ItemsQuery},              // 📋 API models
+    },
+    repositories::items::SharedItemsRepository, // 💾 Repository interface
+    usecases::categories::CategoriesUsecase,   // 🌳 Catalog ประเภท
+};
+
+// 📦 ItemsUsecase struct: จัดการ business logic ของการอ่าน Item
+pub struct ItemsUsecase {
+    items_repository: SharedItemsRepository,   // 💾 Dependency: Repository สำหรับเข้าถึงข้อมูล
+    categories_usecase: Arc<CategoriesUsecase>, // 🌳 Dependency: หาประเภทลูกหลานสำหรับกรอง
+}
+
+impl ItemsUsecase {
+    // 🏗️ Factory method: สร้าง ItemsUsecase พร้อม dependencies
+    pub fn creation(items_repository: SharedItemsRepository, categories_usecase: Arc<CategoriesUsecase>) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            categories_usecase,
+        })
+    }
+
+    // 📋 ดูรายการ Item ที่ตรงกับ query พร้อม ETag ของทั้งรายการ
+    pub async fn listing(&self, query: ItemsQuery) -> Result<Tagged<Vec<Item>>, Box<dyn IntoErrorResponse>> {
+        if let (Some(min_level), Some(max_level)) = (query.min_level, query.max_level)
+            && min_level > max_level
+        {
+            return Err(Box::new(APIError::InvalidQuery(format!(
+                "min_level {} is greater than max_level {}",
+                min_level, max_level
+            ))));
+        }
+
+        // 🧮 ?filter= ผิดรูปแบบได้ 400 ก่อนอ่านฐานข้อมูล
+        let expression = match query.filter.as_deref().map(filter_parsing).transpose() {
+            Ok(expression) => expression,
+            Err(e) => return Err(Box::new(e)),
+        };
+
+        // 🌳 กรองด้วยประเภทแม่ = ประเภทนั้นและลูกหลานทั้งหมด
+        let categories = match &query.category {
+            Some(category) => Some(self.categories_usecase.descendants_getting(category).await?),
+            None => None,
+        };
+
+        let entities = match self.items_repository.find_all(query.to_filter(categories, expression)).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))), // ❌ Error จากฐานข้อมูล
+        };
+
+        let etag = list_etag(entities.iter().map(|e| (e.id.unwrap_or_default(), e.updated_at)));
+        let mut items = Vec::with_capacity(entities.len());
+        for entity in entities {
+            items.push(entity.to_model()?);            // 🔄 Entity -> Model
+        }
+
+        Ok(Tagged { value: items, etag })
+    }
+
+    // 📤 ส่งออก Item ที่ตรงกับ query ทีละแถวในรูปแบบที่ขอ (ไม่รวม item ที่ถูกลบ)
+    // ✅ ตรวจ query ก่อนเริ่ม stream - หลังจากนั้น status 200 ถูกส่งไปแล้ว
+    pub async fn exporting(&self, query: ExportQuery) -> Result<BoxStream<'static, Result<Bytes, io::Error>>, Box<dyn IntoErrorResponse>> {
+        let (updated_from, updated_to) = match query.range() {
+            Ok(range) => range,
+            Err(e) => return Err(Box::new(e)),
+        };
+        let categories = match &query.category {
+            Some(category) => Some(self.categories_usecase.descendants_getting(category).await?),
+            None => None,
+        };
+
+        let entities = self.items_repository.stream_all(ItemsFilter {
+            categories,
+            updated_from,
+            updated_to,
+            ..ItemsFilter::default()
+        });
+        let items = entities.map(|entity| {
+            let entity = entity.map_err(io::Error::other)?;
+            let (created_at, updated_at) = (entity.created_at, entity.updated_at);
+            match entity.to_model() {
+                Ok(item) => Ok(ExportedItem { item, created_at, updated_at }),
+                Err(e) => Err(io::Error::other(e.error().error)),
+            }
+        });
+
+        Ok(query.format.encoding(items))
+    }
+
+    // 🔍 ดู Item ตาม ID พร้อม ETag
+    pub async fn getting(&self, id: i32) -> Result<Tagged<Item>, Box<dyn IntoErrorResponse>> {
+        let entity = match self.items_repository.find_by_id(id).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemNotFound(id))), // 🔍 ไม่พบ
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))), // ❌ Error จากฐานข้อมูล
+        };
+
+        Ok(Tagged {
+            etag: item_etag(id, entity.version),
+            value: entity.to_model()?,
+        })
+    }
+}
+
+// 🔒 การแก้ไขแบบมีเงื่อนไขไม่พบแถว: อ่านใหม่เพื่อแยกว่ามีคนแก้ไขไปก่อน (version ไม่ตรง) หรือไม่มี item จริง ๆ
+pub async fn missing_item(
+    items_repository: &SharedItemsRepository,
+    id: i32,
+    precondition: Precondition,
+) -> Box<dyn IntoErrorResponse> {
+    match items_repository.find_by_id(id).await {
+        Ok(current) => match precondition.checking(current.version) {
+            Err(e) => e,
+            // 🔢 ผ่านเงื่อนไขของ client แต่ชนกับการแก้ไขอื่นระหว่างอ่านกับเขียน
+            Ok(()) => Box::new(APIError::VersionConflict(precondition, current.version)),
+        },
+        Err(sqlx::Error::RowNotFound) => Box::new(APIError::ItemNotFound(id)),
+        Err(e) => Box::new(APIError::FetchingItemError(e)),
+    }
+}
@@ -0,0 +1,393 @@
+// === 🧪 Test Module: Items Use Case Tests ===
+// 📖 ทดสอบการอ่าน Item และ ETag โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use futures_util::{StreamExt, stream};
+    use mockall::predicate::eq;
+
+    use crate::{
+        entities::{
+            categories::Categories as CategoriesEntity,
+            items::{Items as ItemsEntity, ItemsExpression, ItemsField, ItemsFilter, ItemsOperator, ItemsValue},
+        },
+        models::{
+            etag::{Precondition, item_etag, none_matched},
+            export::{ExportFormat, ExportQuery},
+            item::{Category, ItemsQuery, Rarity},
+        },
+        repositories::{categories::MockCategoriesRepository, items::MockItemsRepository},
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, items::ItemsUsecase},
+    };
+
+    fn staff_entity(id: i32, name: &str) -> ItemsEntity {
+        let t = TimerHelper::Mock.creation();
+        ItemsEntity {
+            id: Some(id),
+            ..ItemsEntity::new(name.to_string(), Category::Staff.to_string(), t)
+        }
+    }
+
+    // 🌳 catalog: Weapon > Magic > Staff และ Weapon > Sword
+    fn categories_usecase() -> Arc<CategoriesUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async {
+                let category = |id: i32, name: &str, parent_id: Option<i32>| CategoriesEntity {
+                    id: Some(id),
+                    parent_id,
+                    ..CategoriesEntity::new(name.to_string(), TimerHelper::Mock.creation())
+                };
+                Ok(vec![
+                    category(1, "Weapon", None),
+                    category(2, "Magic", Some(1)),
+                    category(3, "Staff", Some(2)),
+                    category(4, "Sword", Some(1)),
+                ])
+            })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation())
+    }
+
+    // 🧪 ETag ของ item ได้จาก version และใช้กับ If-None-Match ได้
+    #[tokio::test]
+    async fn getting_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(1))
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(staff_entity(1, "wooden staff")) }));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let first = match items_usecase.getting(1).await {
+            Ok(r) => r,
+            Err(_) => panic!("getting error"),
+        };
+        let second = match items_usecase.getting(1).await {
+            Ok(r) => r,
+            Err(_) => panic!("getting error"),
+        };
+
+        assert_eq!(first.value.name, "wooden staff");
+        assert_eq!(first.etag, second.etag);                           // 🏷️ ข้อมูลเดิม ETag เดิม
+        assert!(none_matched(Some(&format!("W/{}", first.etag)), &first.etag));
+        assert!(!none_matched(Some("\"other\""), &first.etag));
+    }
+
+    // 🧪 If-Match: ETag ของ item นี้ให้ version / * ไม่ตรวจ / ETag อื่นหรือ weak ETag ไม่มีทางตรง
+    #[test]
+    fn precondition_test() {
+        let etag = item_etag(1, 4);
+
+        assert_eq!(Precondition::from_request(Some(&etag), 1, None), Precondition::IfMatch(4));
+        assert_eq!(Precondition::from_request(Some(&format!("\"x\", {}", etag)), 1, Some(2)), Precondition::IfMatch(4));
+        assert_eq!(Precondition::from_request(Some("*"), 1, None), Precondition::Any);
+        assert_eq!(Precondition::from_request(Some(&etag), 2, None), Precondition::IfMatch(0));
+        assert_eq!(Precondition::from_request(Some(&format!("W/{}", etag)), 1, None), Precondition::IfMatch(0));
+        assert_eq!(Precondition::from_request(None, 1, Some(2)), Precondition::Version(2));
+        assert_eq!(Precondition::from_request(None, 1, None), Precondition::Any);
+
+        assert!(Precondition::IfMatch(4).checking(4).is_ok());
+        assert!(Precondition::Any.checking(9).is_ok());
+        match Precondition::IfMatch(4).checking(5) {
+            Ok(_) => panic!("version should conflict"),
+            Err(e) => assert_eq!(e.error().current_version, Some(5)),
+        }
+    }
+
+    // 🧪 ETag ของรายการเปลี่ยนเมื่อมีแถวเพิ่ม
+    #[tokio::test]
+    async fn listing_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let mut calls = 0;
+        items_repository_mock
+            .expect_find_all()
+            .times(2)
+            .returning(move |_| {
+                calls += 1;
+                let items = (1..=calls).map(|id| staff_entity(id, "staff")).collect();
+                Box::pin(async move { Ok(items) })
+            });
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let one = match items_usecase.listing(ItemsQuery::default()).await {
+            Ok(r) => r,
+            Err(_) => panic!("listing error"),
+        };
+        let two = match items_usecase.listing(ItemsQuery::default()).await {
+            Ok(r) => r,
+            Err(_) => panic!("listing error"),
+        };
+
+        assert_eq!(one.value.len(), 1);
+        assert_eq!(two.value.len(), 2);
+        assert_ne!(one.etag, two.etag);
+    }
+
+    // 🧪 query string ถูกส่งต่อเป็น ItemsFilter ของ Repository (ประเภทแม่รวมลูกหลาน)
+    #[tokio::test]
+    async fn listing_filter_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_all()
+            .with(eq(ItemsFilter {
+                rarity: Some("Rare".to_string()),
+                min_level: Some(5),
+                max_level: None,
+                categories: Some(vec![
+                    "Weapon".to_string(),
+                    "Magic".to_string(),
+                    "Sword".to_string(),
+                    "Staff".to_string(),
+                ]),
+                definition_id: None,
+                include_deleted: false,
+                updated_from: None,
+                updated_to: None,
+                expression: None,
+            }))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ItemsQuery {
+            rarity: Some(Rarity::Rare),
+            min_level: Some(5),
+            max_level: None,
+            category: Some("Weapon".to_string()),
+            definition_id: None,
+            include_deleted: false,
+            filter: None,
+        };
+        match items_usecase.listing(query).await {
+            Ok(r) => assert!(r.value.is_empty()),
+            Err(_) => panic!("listing error"),
+        }
+    }
+
+    // 🧪 min_level มากกว่า max_level ต้องได้ 400 โดยไม่ query
+    #[tokio::test]
+    async fn listing_invalid_level_range_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_find_all().never();
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ItemsQuery {
+            min_level: Some(10),
+            max_level: Some(5),
+            ..ItemsQuery::default()
+        };
+        match items_usecase.listing(query).await {
+            Ok(_) => panic!("level range should be invalid"),
+            Err(e) => assert_eq!(e.error().status_code, axum::http::StatusCode::BAD_REQUEST),
+        }
+    }
+
+    // 🧪 ?filter= ถูก parse เป็น ItemsExpression ของ Repository (, ผูกหลวมกว่า ; / วงเล็บจัดกลุ่ม / * = LIKE)
+    #[tokio::test]
+    async fn listing_filter_expression_test() {
+        let created = NaiveDate::from_ymd_opt(2026, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0)).unwrap_or_default();
+        let expression = ItemsExpression::Or(vec![
+            ItemsExpression::And(vec![
+                ItemsExpression::Comparison(ItemsField::Category, ItemsOperator::Equal, vec![ItemsValue::Text("Staff".to_string())]),
+                ItemsExpression::Comparison(
+                    ItemsField::Rarity,
+                    ItemsOperator::In,
+                    vec![ItemsValue::Text("Epic".to_string()), ItemsValue::Text("Legendary".to_string())],
+                ),
+                ItemsExpression::Comparison(ItemsField::CreatedAt, ItemsOperator::Greater, vec![ItemsValue::Timestamp(created)]),
+            ]),
+            ItemsExpression::And(vec![
+                ItemsExpression::Comparison(ItemsField::Name, ItemsOperator::Like, vec![ItemsValue::Text("Fire *".to_string())]),
+                ItemsExpression::Or(vec![
+                    ItemsExpression::Comparison(ItemsField::RequiredLevel, ItemsOperator::GreaterOrEqual, vec![ItemsValue::Integer(10)]),
+                    ItemsExpression::Comparison(ItemsField::OwnerId, ItemsOperator::NotIn, vec![ItemsValue::Integer(1)]),
+                ]),
+            ]),
+        ]);
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_all()
+            .with(eq(ItemsFilter {
+                expression: Some(expression),
+                ..ItemsFilter::default()
+            }))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ItemsQuery {
+            filter: Some(
+                "category==Staff;rarity=in=(Epic, Legendary);created_at>2026-01-01 , name==\"Fire *\";(required_level=ge=10,owner_id=out=1)"
+                    .to_string(),
+            ),
+            ..ItemsQuery::default()
+        };
+        if items_usecase.listing(query).await.is_err() {
+            panic!("listing error");
+        }
+    }
+
+    // 🧪 filter ผิดต้องได้ 400 ที่ชี้ตำแหน่งและ token โดยไม่ query
+    #[tokio::test]
+    async fn listing_invalid_filter_test() {
+        let filters = [
+            ("category==Staff;rarityy==Epic", "Invalid filter at position 17 near 'rarityy': unknown field"),
+            ("rarity=like=Epic", "Invalid filter at position 7 near '=like=': unknown operator"),
+            ("rarity==Mythic", "Invalid filter at position 9 near 'Mythic': rarity must be one of"),
+            ("name>Fire", "Invalid filter at position 5 near '>': operator is not supported for name"),
+            ("required_level<ten", "Invalid filter at position 16 near 'ten': required_level must be an integer"),
+            ("created_at>yesterday", "Invalid filter at position 12 near 'yesterday': created_at must be a date or timestamp"),
+            ("(category==Staff", "Invalid filter at position 17 near 'end of filter': expected ')'"),
+            ("name==\"Fire", "Invalid filter at position 7 near '\"': unterminated quoted value"),
+            ("name==Fire)", "Invalid filter at position 11 near ')': expected ';', ',' or end of filter"),
+            ("id==1;drop table items", "Invalid filter at position 7 near 'drop': unknown field"),
+        ];
+        for (filter, message) in filters {
+            let mut items_repository_mock = MockItemsRepository::new();
+            items_repository_mock.expect_find_all().never();
+
+            let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+            let query = ItemsQuery {
+                filter: Some(filter.to_string()),
+                ..ItemsQuery::default()
+            };
+            match items_usecase.listing(query).await {
+                Ok(_) => panic!("filter should be invalid: {}", filter),
+                Err(e) => {
+                    let error = e.error();
+                    assert_eq!(error.status_code, axum::http::StatusCode::BAD_REQUEST);
+                    assert!(error.error.starts_with(message), "{} -> {}", filter, error.error);
+                }
+            }
+        }
+    }
+
+    // 🧪 กรองด้วยประเภทที่ไม่มีใน catalog ต้องได้ 400 โดยไม่ query
+    #[tokio::test]
+    async fn listing_unknown_category_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_find_all().never();
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ItemsQuery {
+            category: Some("Bow".to_string()),
+            ..ItemsQuery::default()
+        };
+        match items_usecase.listing(query).await {
+            Ok(_) => panic!("category should be unknown"),
+            Err(e) => assert_eq!(e.error().status_code, axum::http::StatusCode::BAD_REQUEST),
+        }
+    }
+
+    // 🧪 ไม่พบ Item ต้องได้ 404
+    #[tokio::test]
+    async fn getting_not_found_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        match items_usecase.getting(9).await {
+            Ok(_) => panic!("item should not be found"),
+            Err(e) => assert_eq!(e.error().status_code, axum::http::StatusCode::NOT_FOUND),
+        }
+    }
+
+    // 🧪 export: กรองด้วยประเภทลูกหลานและช่วงเวลา แล้วส่งออกทีละแถว (JSON array ต่อกันเป็นไฟล์เดียว)
+    #[tokio::test]
+    async fn exporting_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let from = NaiveDate::from_ymd_opt(2026, 10, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+        items_repository_mock
+            .expect_stream_all()
+            .with(eq(ItemsFilter {
+                categories: Some(vec!["Magic".to_string(), "Staff".to_string()]),
+                updated_from: from,
+                ..ItemsFilter::default()
+            }))
+            .times(1)
+            .returning(|_| Box::pin(stream::iter(vec![Ok(staff_entity(1, "wooden staff")), Ok(staff_entity(2, "fire staff"))])));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ExportQuery {
+            format: ExportFormat::Json,
+            category: Some("Magic".to_string()),
+            updated_from: Some("2026-10-01".to_string()),
+            ..ExportQuery::default()
+        };
+        let chunks = match items_usecase.exporting(query).await {
+            Ok(r) => r.collect::<Vec<_>>().await,
+            Err(_) => panic!("exporting error"),
+        };
+        let body: Vec<u8> = chunks.into_iter().flat_map(|chunk| chunk.expect("chunk").to_vec()).collect();
+
+        let exported: Vec<serde_json::Value> = serde_json::from_slice(&body).expect("JSON array");
+        assert_eq!(exported.len(), 2);
+        assert_eq!(exported[1]["name"], "fire staff");
+        assert_eq!(exported[1]["created_at"], "1970-01-01T00:00:00");
+    }
+
+    // 🧪 CSV: header แถวแรก แล้วหนึ่งแถวต่อ item
+    #[tokio::test]
+    async fn exporting_csv_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_stream_all()
+            .returning(|_| Box::pin(stream::iter(vec![Ok(staff_entity(1, "wooden, staff"))])));
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ExportQuery {
+            format: ExportFormat::Csv,
+            ..ExportQuery::default()
+        };
+        let chunks = match items_usecase.exporting(query).await {
+            Ok(r) => r.collect::<Vec<_>>().await,
+            Err(_) => panic!("exporting error"),
+        };
+        let body: Vec<u8> = chunks.into_iter().flat_map(|chunk| chunk.expect("chunk").to_vec()).collect();
+        let lines: Vec<&str> = std::str::from_utf8(&body).expect("UTF-8").lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,name,category,"));
+        assert!(lines[1].starts_with("1,\"wooden, staff\",Staff,,0,Common,1,{},{},"));
+    }
+
+    // 🧪 ช่วงเวลากลับด้านต้องได้ 400 โดยไม่ query
+    #[tokio::test]
+    async fn exporting_invalid_range_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_stream_all().never();
+
+        let items_usecase = ItemsUsecase::creation(Arc::new(items_repository_mock), categories_usecase());
+
+        let query = ExportQuery {
+            updated_from: Some("2026-10-18T00:00:00".to_string()),
+            updated_to: Some("2026-10-01".to_string()),
+            ..ExportQuery::default()
+        };
+        match items_usecase.exporting(query).await {
+            Ok(_) => panic!("range should be invalid"),
+            Err(e) => assert_eq!(e.error().status_code, axum::http::StatusCode::BAD_REQUEST),
+        }
+    }
+}
@@ -1,2 +1,24 @@
+pub mod batch;
+pub mod batch_test;
+pub mod categories;
+pub mod categories_test;
+pub mod history;
+pub mod history_test;
+pub mod import;
+pub mod import_test;
+pub mod instances;
+pub mod instances_test;
+pub mod inventory;
+pub mod inventory_test;
+pub mod item_definitions;
+pub mod item_definitions_test;
+pub mod items;
+pub mod items_test;
+pub mod players;
+pub mod players_test;
+pub mod search;
+pub mod search_test;
 pub mod staff;
-pub mod staff_test;
\ No newline at end of file
+pub mod staff_test;
+pub mod trash;
+pub mod trash_test;
\ No newline at end of file
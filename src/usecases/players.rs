@@ -0,0 +1,115 @@
+// === 🧠 Application Layer: Players Use Case ===
+// 📖 ตรรกะทางธุรกิจของผู้เล่น: สมัคร, ดูข้อมูล, เปลี่ยน tier (admin) และดู item ที่ครอบครอง
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    PlayersUsecase ดูแลเฉพาะ business logic ของผู้เล่น
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedPlayersRepository และ SharedItemsRepository (abstractions)
+
+use std::sync::Arc;
+
+use crate::{
+    models::{
+        error::{APIError, IntoErrorResponse},
+        item::Item,
+        player::{Player, PlayerRegistering, PlayerTierUpdating},
+    },
+    repositories::{items::SharedItemsRepository, players::SharedPlayersRepository},
+    setting::Inventory as InventorySetting,
+    time_helper::IntoTimerHelperShared,
+};
+
+// 📦 PlayersUsecase struct: จัดการ business logic ของผู้เล่น
+pub struct PlayersUsecase {
+    players_repository: SharedPlayersRepository, // 💾 Repository ของผู้เล่น
+    items_repository: SharedItemsRepository,     // 💾 Repository ของ item (ใช้ query ตามเจ้าของ)
+    timer_helper: IntoTimerHelperShared,         // ⏰ เครื่องมือจัดการเวลา
+    setting: InventorySetting,                   // 🏅 tier ที่มีและ tier เริ่มต้นของผู้เล่นใหม่
+}
+
+impl PlayersUsecase {
+    // 🏗️ Factory method: สร้าง PlayersUsecase พร้อม dependencies
+    pub fn creation(
+        players_repository: SharedPlayersRepository,
+        items_repository: SharedItemsRepository,
+        timer_helper: IntoTimerHelperShared,
+        setting: InventorySetting,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            players_repository,
+            items_repository,
+            timer_helper,
+            setting,
+        })
+    }
+
+    // ➕ สมัครผู้เล่นใหม่ - ชื่อห้ามซ้ำ / tier เป็น default_tier เสมอ
+    pub async fn registering(&self, player: PlayerRegistering) -> Result<Player, Box<dyn IntoErrorResponse>> {
+        // 🔄 ชื่อซ้ำให้ unique constraint ของฐานข้อมูลตรวจ (ไม่ค้นก่อน - สองคำขอพร้อมกันผ่านการค้นได้ทั้งคู่)
+        let entity = match self
+            .players_repository
+            .insert(player.to_entity(self.setting.default_tier.clone(), Arc::clone(&self.timer_helper)))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => return Err(registering_error(e, &player)),
+        };
+
+        entity.to_model()
+    }
+
+    // 🏅 เปลี่ยน tier ของผู้เล่น (admin) - ต้องเป็น tier ที่มีใน inventory.tiers
+    pub async fn tier_updating(
+        &self,
+        id: i32,
+        updating: PlayerTierUpdating,
+    ) -> Result<Player, Box<dyn IntoErrorResponse>> {
+        if !self.setting.tiers.contains_key(&updating.tier) {
+            return Err(Box::new(APIError::InvalidPlayerTier(updating.tier)));
+        }
+
+        match self
+            .players_repository
+            .update_tier(id, updating.tier, self.timer_helper.now())
+            .await
+        {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::PlayerNotFound(id))),
+            Err(e) => Err(Box::new(APIError::UpdatingPlayerError(e))),
+        }
+    }
+
+    // 🔍 ดูข้อมูลผู้เล่นตาม ID
+    pub async fn getting(&self, id: i32) -> Result<Player, Box<dyn IntoErrorResponse>> {
+        match self.players_repository.find_by_id(id).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::PlayerNotFound(id))),
+            Err(e) => Err(Box::new(APIError::FetchingPlayerError(e))),
+        }
+    }
+
+    // 🎒 ดู item ทั้งหมดของผู้เล่น - ถ้าไม่มีผู้เล่นคนนี้ตอบ 404 แทน list ว่าง
+    pub async fn items_listing(&self, id: i32) -> Result<Vec<Item>, Box<dyn IntoErrorResponse>> {
+        self.getting(id).await?;
+
+        let entities = match self.items_repository.find_by_owner(id).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+
+        entities.iter().map(|entity| entity.to_model()).collect()
+    }
+}
+
+// 🔄 แปลง error ตอนสมัคร: unique violation = มีคนใช้ชื่อนี้ไปแล้ว (รวมกรณีสมัครพร้อมกัน)
+fn registering_error(e: sqlx::Error, player: &PlayerRegistering) -> Box<dyn IntoErrorResponse> {
+    match &e {
+        sqlx::Error::Database(db_error) if db_error.code().as_deref() == Some("23505") => {
+            Box::new(APIError::PlayerAlreadyExists(player.name.clone()))
+        }
+        _ => Box::new(APIError::AddingPlayerError(e)),
+    }
+}
@@ -0,0 +1,188 @@
+// === 🧪 Test Module: Players Use Case Tests ===
+// 📖 ทดสอบผู้เล่นและ item ที่ครอบครองโดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use axum::http::StatusCode;
+    use mockall::predicate::{always, eq};
+
+    use crate::{
+        entities::{items::Items as ItemsEntity, players::Players as PlayersEntity},
+        models::{
+            item::Category,
+            player::{PlayerRegistering, PlayerTierUpdating},
+        },
+        repositories::{items::MockItemsRepository, players::MockPlayersRepository},
+        setting::{Inventory as InventorySetting, InventoryTier},
+        time_helper::TimerHelper,
+        usecases::players::PlayersUsecase,
+    };
+
+    fn inventory_setting() -> InventorySetting {
+        let tier = |max_slots| InventoryTier { max_slots, max_weight: 100 };
+        InventorySetting {
+            default_tier: "basic".to_string(),
+            tiers: HashMap::from([("basic".to_string(), tier(20)), ("premium".to_string(), tier(60))]),
+        }
+    }
+
+    fn player_entity(id: i32) -> PlayersEntity {
+        let t = TimerHelper::Mock.creation();
+        PlayersEntity {
+            id: Some(id),
+            ..PlayersEntity::new("alice".to_string(), "basic".to_string(), t)
+        }
+    }
+
+    // 🧪 สมัครผู้เล่นใหม่: tier เป็น default_tier เสมอ
+    #[tokio::test]
+    async fn registering_test() {
+        let mut players_repository_mock = MockPlayersRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        players_repository_mock.expect_find_by_name().never(); // 🔄 ชื่อซ้ำตรวจด้วย unique constraint ตอน insert
+        players_repository_mock
+            .expect_insert()
+            .with(eq(PlayersEntity::new("alice".to_string(), "basic".to_string(), Arc::clone(&timer_helper))))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(player_entity(7)) }));
+
+        let players_usecase = PlayersUsecase::creation(
+            Arc::new(players_repository_mock),
+            Arc::new(MockItemsRepository::new()),
+            timer_helper,
+            inventory_setting(),
+        );
+
+        let player = match players_usecase
+            .registering(PlayerRegistering {
+                name: "alice".to_string(),
+            })
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => panic!("registering error"),
+        };
+
+        assert_eq!(player.id, 7);
+        assert_eq!(player.name, "alice");
+    }
+
+    // 🧪 เปลี่ยน tier (admin): tier ต้องมีในการตั้งค่า
+    #[tokio::test]
+    async fn tier_updating_test() {
+        let mut players_repository_mock = MockPlayersRepository::new();
+        players_repository_mock
+            .expect_update_tier()
+            .with(eq(7), eq("premium".to_string()), always())
+            .times(1)
+            .returning(|id, tier, _| {
+                Box::pin(async move {
+                    Ok(PlayersEntity {
+                        tier,
+                        ..player_entity(id)
+                    })
+                })
+            });
+        players_repository_mock
+            .expect_update_tier()
+            .with(eq(99), always(), always())
+            .returning(|_, _, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+
+        let players_usecase = PlayersUsecase::creation(
+            Arc::new(players_repository_mock),
+            Arc::new(MockItemsRepository::new()),
+            TimerHelper::Mock.creation(),
+            inventory_setting(),
+        );
+
+        let player = match players_usecase
+            .tier_updating(7, PlayerTierUpdating { tier: "premium".to_string() })
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => panic!("tier updating error"),
+        };
+        assert_eq!(player.tier, "premium");
+
+        // ❌ tier ที่ไม่มีในการตั้งค่าไม่ถึง repository / ผู้เล่นที่ไม่มีอยู่ได้ 404
+        match players_usecase
+            .tier_updating(7, PlayerTierUpdating { tier: "gold".to_string() })
+            .await
+        {
+            Ok(_) => panic!("tier should be invalid"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::BAD_REQUEST),
+        }
+        match players_usecase
+            .tier_updating(99, PlayerTierUpdating { tier: "basic".to_string() })
+            .await
+        {
+            Ok(_) => panic!("player should not be found"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+
+    // 🧪 ดู item ของผู้เล่น: ใช้ query ตามเจ้าของ
+    #[tokio::test]
+    async fn items_listing_test() {
+        let mut players_repository_mock = MockPlayersRepository::new();
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        players_repository_mock
+            .expect_find_by_id()
+            .with(eq(7))
+            .returning(|_| Box::pin(async { Ok(player_entity(7)) }));
+        items_repository_mock
+            .expect_find_by_owner()
+            .with(eq(7))
+            .times(1)
+            .returning(|owner_id| {
+                Box::pin(async move {
+                    let t = TimerHelper::Mock.creation();
+                    Ok(vec![ItemsEntity {
+                        id: Some(1),
+                        owner_id: Some(owner_id),
+                        ..ItemsEntity::new("wooden staff".to_string(), Category::Staff.to_string(), t)
+                    }])
+                })
+            });
+
+        let players_usecase = PlayersUsecase::creation(
+            Arc::new(players_repository_mock),
+            Arc::new(items_repository_mock),
+            TimerHelper::Mock.creation(),
+            inventory_setting(),
+        );
+
+        let items = match players_usecase.items_listing(7).await {
+            Ok(r) => r,
+            Err(_) => panic!("items listing error"),
+        };
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].owner_id, Some(7));
+    }
+
+    // 🧪 ผู้เล่นที่ไม่มีอยู่ต้องได้ 404 และไม่ query item
+    #[tokio::test]
+    async fn items_listing_player_not_found_test() {
+        let mut players_repository_mock = MockPlayersRepository::new();
+        players_repository_mock
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+
+        let players_usecase = PlayersUsecase::creation(
+            Arc::new(players_repository_mock),
+            Arc::new(MockItemsRepository::new()),
+            TimerHelper::Mock.creation(),
+            inventory_setting(),
+        );
+
+        match players_usecase.items_listing(99).await {
+            Ok(_) => panic!("player should not be found"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// === 🧠 Application Layer: Search Use Case ===
+// 📖 ค้นหาชื่อ item แบบบางส่วน / สะกดผิด / ภาษาไทย เรียงตามความเกี่ยวข้องพร้อมไฮไลต์
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    SearchUsecase ตรวจคำค้นและจัดรูปผลลัพธ์ - การให้คะแนนอยู่ใน ItemsRepository::search
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository (Mock ได้ใน test)
+
+use std::sync::Arc;
+
+use crate::{
+    entities::items::ItemsSearch,
+    models::{
+        error::{APIError, IntoErrorResponse},
+        item::lowercasing,
+        search::{ItemSearchHit, ItemSearchQuery, ItemSearchResult, highlighting},
+    },
+    repositories::items::SharedItemsRepository,
+    setting::Search as SearchSetting,
+    usecases::categories::CategoriesUsecase,
+};
+
+// 📦 SearchUsecase struct: ค้นหาชื่อ item
+pub struct SearchUsecase {
+    items_repository: SharedItemsRepository,    // 💾 Repository ของ item
+    categories_usecase: Arc<CategoriesUsecase>, // 🌳 หาประเภทลูกหลานสำหรับกรอง
+    setting: SearchSetting,                     // 🔎 limit / ความยาวคำค้น / ความใกล้เคียงขั้นต่ำ
+}
+
+impl SearchUsecase {
+    // 🏗️ Factory method: สร้าง SearchUsecase พร้อม dependencies
+    pub fn creation(
+        items_repository: SharedItemsRepository,
+        categories_usecase: Arc<CategoriesUsecase>,
+        setting: SearchSetting,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            categories_usecase,
+            setting,
+        })
+    }
+
+    // 🔎 ค้นหาชื่อ item ที่ยังไม่ถูกลบ - ผลเรียงตามคะแนน ชื่อไฮไลต์ส่วนที่ตรงกับคำค้น
+    pub async fn searching(&self, query: ItemSearchQuery) -> Result<ItemSearchResult, Box<dyn IntoErrorResponse>> {
+        let q = query.q.trim().to_string();
+        if q.is_empty() {
+            return Err(Box::new(APIError::InvalidQuery("q must not be empty".to_string())));
+        }
+        if q.chars().count() > self.setting.max_query_chars {
+            return Err(Box::new(APIError::InvalidQuery(format!(
+                "q must be at most {} characters",
+                self.setting.max_query_chars
+            ))));
+        }
+        let limit = query.limit.unwrap_or(self.setting.default_limit);
+        if limit == 0 || limit > self.setting.max_limit {
+            return Err(Box::new(APIError::InvalidQuery(format!(
+                "limit must be between 1 and {}",
+                self.setting.max_limit
+            ))));
+        }
+
+        // 🌳 กรองด้วยประเภทแม่ = ประเภทนั้นและลูกหลานทั้งหมด
+        let categories = match &query.category {
+            Some(category) => Some(self.categories_usecase.descendants_getting(category).await?),
+            None => None,
+        };
+
+        let search = ItemsSearch {
+            query: lowercasing(&q), // 🔡 ฐานข้อมูลเทียบกับ name_key ที่เป็นตัวเล็กแบบเดียวกัน
+            categories,
+            min_similarity: self.setting.min_similarity,
+            limit: limit as i64,
+        };
+        let entities = match self.items_repository.search(search).await {
+            Ok(r) => r,
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+
+        let mut hits = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let item = entity.item.to_model()?;
+            hits.push(ItemSearchHit {
+                score: entity.score,
+                highlight: highlighting(&item.name, &q),
+                item,
+            });
+        }
+        Ok(ItemSearchResult { query: q, hits })
+    }
+}
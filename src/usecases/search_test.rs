@@ -0,0 +1,148 @@
+// === 🧪 Test Module: Search Use Case Tests ===
+// 📖 ทดสอบการตรวจคำค้น ลำดับผลลัพธ์ และการไฮไลต์ (รวมภาษาไทย) โดยใช้ Mock Repository
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::http::StatusCode;
+
+    use crate::{
+        entities::{
+            categories::Categories as CategoriesEntity,
+            items::{Items as ItemsEntity, ItemsSearch, ItemsSearched},
+        },
+        models::{
+            item::{Category, name_key},
+            search::{ItemSearchQuery, highlighting},
+        },
+        repositories::{categories::MockCategoriesRepository, items::MockItemsRepository},
+        setting::Search as SearchSetting,
+        time_helper::TimerHelper,
+        usecases::{categories::CategoriesUsecase, search::SearchUsecase},
+    };
+
+    fn search_setting() -> SearchSetting {
+        SearchSetting {
+            default_limit: 20,
+            max_limit: 50,
+            max_query_chars: 10,
+            min_similarity: 0.4,
+        }
+    }
+
+    fn searched(id: i32, name: &str, score: f32) -> ItemsSearched {
+        ItemsSearched {
+            item: ItemsEntity {
+                id: Some(id),
+                ..ItemsEntity::new(name.to_string(), Category::Staff.to_string(), TimerHelper::Mock.creation())
+            },
+            score,
+        }
+    }
+
+    fn search_usecase(items_repository_mock: MockItemsRepository) -> Arc<SearchUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        SearchUsecase::creation(
+            Arc::new(items_repository_mock),
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), TimerHelper::Mock.creation()),
+            search_setting(),
+        )
+    }
+
+    #[tokio::test]
+    async fn searching_test() {
+        // 📦 Repository ได้คำค้นที่ตัดช่องว่างแล้ว / limit ตามค่าเริ่มต้น / ผลเรียงตามคะแนนมาแล้ว
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_search()
+            .withf(|search: &ItemsSearch| {
+                search.query == "เพลิง" && search.limit == 20 && search.min_similarity == 0.4 && search.categories.is_none()
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        searched(2, "ไม้เท้าเพลิง", 1.3),
+                        searched(1, "ดาบเพลิงมังกร", 1.2),
+                    ])
+                })
+            });
+
+        let query = ItemSearchQuery {
+            q: "  เพลิง ".to_string(),
+            ..ItemSearchQuery::default()
+        };
+        let result = match search_usecase(items_repository_mock).searching(query).await {
+            Ok(r) => r,
+            Err(_) => panic!("searching error"),
+        };
+
+        // ✅ ภาษาไทยไม่มีช่องว่าง - ไฮไลต์เฉพาะส่วนของคำที่ตรง
+        assert_eq!(result.query, "เพลิง");
+        let hits: Vec<(i32, &str)> = result.hits.iter().map(|hit| (hit.item.id, hit.highlight.as_str())).collect();
+        assert_eq!(
+            hits,
+            vec![(2, "ไม้เท้า<mark>เพลิง</mark>"), (1, "ดาบ<mark>เพลิง</mark>มังกร")]
+        );
+    }
+
+    #[tokio::test]
+    async fn searching_non_ascii_case_test() {
+        // 🔡 คำค้นตัวพิมพ์ใหญ่ที่ไม่ใช่ ASCII: repository ได้ตัวเล็กแบบเดียวกับ name_key / ไฮไลต์ใช้กติกาเดียวกัน
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_search()
+            .withf(|search: &ItemsSearch| search.query == "épée" && search.query == name_key("ÉPÉE"))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![searched(4, "Grande Épée", 1.1)]) }));
+
+        let query = ItemSearchQuery {
+            q: "ÉPÉE".to_string(),
+            ..ItemSearchQuery::default()
+        };
+        let result = match search_usecase(items_repository_mock).searching(query).await {
+            Ok(r) => r,
+            Err(_) => panic!("searching error"),
+        };
+
+        assert_eq!(result.query, "ÉPÉE");
+        assert_eq!(result.hits[0].highlight, "Grande <mark>Épée</mark>");
+    }
+
+    #[tokio::test]
+    async fn searching_invalid_query_test() {
+        // ❌ คำค้นว่าง / ยาวเกิน / limit นอกช่วง - ไม่ค้นเลย
+        let queries = [
+            ItemSearchQuery { q: "   ".to_string(), ..ItemSearchQuery::default() },
+            ItemSearchQuery { q: "ไม้เท้าน้ำแข็ง".to_string(), ..ItemSearchQuery::default() },
+            ItemSearchQuery { q: "staff".to_string(), limit: Some(0), ..ItemSearchQuery::default() },
+            ItemSearchQuery { q: "staff".to_string(), limit: Some(51), ..ItemSearchQuery::default() },
+        ];
+        for query in queries {
+            let mut items_repository_mock = MockItemsRepository::new();
+            items_repository_mock.expect_search().never();
+            match search_usecase(items_repository_mock).searching(query).await {
+                Ok(_) => panic!("query should be invalid"),
+                Err(e) => assert_eq!(e.error().status_code, StatusCode::BAD_REQUEST),
+            }
+        }
+    }
+
+    // 🖍️ ไฮไลต์: ไม่สนตัวพิมพ์ / แยกคำเมื่อมีช่องว่าง / สระอำที่พิมพ์แยก / สะกดผิด / escape HTML
+    #[test]
+    fn highlighting_test() {
+        assert_eq!(highlighting("Fire Staff", "staff FIRE"), "<mark>Fire</mark> <mark>Staff</mark>");
+        assert_eq!(highlighting("ไม้เท้าน้ำแข็ง", "นํ้าแข็ง"), "ไม้เท้า<mark>น้ำแข็ง</mark>");
+        assert_eq!(highlighting("ดาบ\u{200B}เพลิง", "ดาบเพลิง"), "<mark>ดาบ\u{200B}เพลิง</mark>");
+        assert_eq!(highlighting("ดาบเพลิงมังกร", "ดาบเพลงมังกร"), "<mark>ดาบเพล</mark>ิงมังกร");
+        assert_eq!(highlighting("Plain <b> Rod", "<b>"), "Plain <mark>&lt;b&gt;</mark> Rod");
+        assert_eq!(highlighting("Frost Staff", "xyz"), "Frost Staff");
+    }
+}
@@ -21,19 +21,27 @@
 
 use std::sync::Arc;
 
+use sqlx::types::Json;
+
 use crate::{
+    entities::{item_revisions::Auditing, items::{Items as ItemsEntity, Upserted}}, // 🏛️ Domain Entity
     models::{
+        audit::{Actor, ItemAction},            // 📜 ผู้แก้ไขสำหรับประวัติ
         error::{APIError, IntoErrorResponse},  // 📋 Error handling models
-        item::{Item, StaffAdding},             // 📋 API models
+        etag::Precondition,                    // 🔒 version ที่ client แก้ไขจาก
+        item::{AttributesUpdating, Item, StaffAdding, StaffUpserting, name_normalizing}, // 📋 API models / 🔤 ชื่อที่บันทึก
+        patch::ItemPatch,                      // 🩹 Merge Patch / JSON Patch
     },
     repositories::items::SharedItemsRepository, // 💾 Repository interface
     time_helper::IntoTimerHelperShared,        // ⏰ Time utility
+    usecases::{categories::CategoriesUsecase, items::missing_item}, // 📚 Catalog ประเภท / 🔒 แยก version ไม่ตรงกับไม่พบ
 };
 
 // 📦 StaffUsecase struct: จัดการ business logic ของ Staff
 pub struct StaffUsecase {
     items_repository: SharedItemsRepository,   // 💾 Dependency: Repository สำหรับเข้าถึงข้อมูล
     timer_helper: IntoTimerHelperShared,       // ⏰ Dependency: เครื่องมือจัดการเวลา
+    categories_usecase: Arc<CategoriesUsecase>, // 📚 Dependency: ตรวจประเภทกับ catalog
 }
 
 impl StaffUsecase {
@@ -41,53 +49,258 @@ impl StaffUsecase {
     pub fn creation(
         items_repository: SharedItemsRepository,   // 💾 Repository dependency
         timer_helper: IntoTimerHelperShared,       // ⏰ Timer dependency
+        categories_usecase: Arc<CategoriesUsecase>, // 📚 Catalog dependency
     ) -> Arc<Self> {
         Arc::new(Self {
             items_repository,
             timer_helper,
+            categories_usecase,
         })
     }
 
     // ➕ Business Logic: เพิ่ม Staff ใหม่
     // นี่คือหัวใจของ Use Case - จัดการ business rules และ flow
-    pub async fn adding(&self, staff: StaffAdding) -> Result<Item, Box<dyn IntoErrorResponse>> {
-        // 🔍 ขั้นตอนที่ 1: ตรวจสอบว่ามี Staff ชื่อนี้อยู่แล้วหรือไม่ (Business Rule)
-        if let Ok(_) = self.items_repository.find_by_name(staff.name.clone()).await {
-            return Err(Box::new(APIError::ItemAlreadyExists(staff.name.clone())));
+    pub async fn adding(&self, staff: StaffAdding, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        // 🔤 ขั้นตอนที่ 0: normalize ชื่อ (ตรวจ / บันทึก / แจ้งชื่อซ้ำ ด้วยชื่อเดียวกัน)
+        let staff = StaffAdding {
+            name: name_normalizing(&staff.name),
+            ..staff
         };
+        // 📊 ตรวจชื่อ และ weight / level / stats ตามกติกาของประเภท
+        // 👤 item ของผู้เล่นต้องผ่านกติกากระเป๋า (POST /players/{id}/items)
+        owner_rejecting(staff.owner_id)?;
+        name_validating(&staff.name)?;
+        attributes_validating(&staff)?;
+        // 📚 ประเภทต้องอยู่ใน catalog และผ่านกติกาของ tree / attributes ต้องผ่าน JSON Schema ของประเภท
+        let schema_version = self.categories_usecase.item_validating(&staff).await?;
 
-        // ➕ ขั้นตอนที่ 2: แปลง Model เป็ Entity และบันทึกลงฐานข้อมูล
-
-        // 🔄 แบบเดิม: สร้าง Entity โดยตรง (ไม่ตาม Clean Architecture flow)
-        // let id = match self
-        //     .items_repository
-        //     .insert(ItemsEntity::new(                    // 🏗️ สร้าง Entity ใหม่
-        //         staff.name.clone(),
-        //         Category::Staff.to_string(),             // 🏷️ กำหนดประเภทเป็น Staff
-        //         Arc::clone(&self.timer_helper),          // ⏰ ใช้ timer helper สำหรับ timestamp
-        //     ))
-        //     .await
-
-        // ✅ แบบใหม่: ใช้ Model → Entity → Database flow ตาม Clean Architecture
-        let id = match self
+        // ➕ ขั้นตอนที่ 1: แปลง Model เป็น Entity และบันทึกลงฐานข้อมูล (round trip เดียว)
+        // 👤 ชื่อห้ามซ้ำเฉพาะภายในเจ้าของเดียวกัน - unique index บน name_key (ไม่สนตัวพิมพ์) ของฐานข้อมูลตรวจให้ (ซ้ำ -> 409)
+        // ✅ insert ส่งกลับแถวที่เพิ่ม (RETURNING *) ใช้ได้ทันที ไม่ต้องค้นชื่อก่อนหรือ find_by_id อ่านกลับ
+        let staff_entity = match self
             .items_repository
-            .insert(staff.to_entity(Arc::clone(&self.timer_helper))) // 🔄 ใช้ to_entity() แปลง Model → Entity
+            .insert(
+                ItemsEntity {
+                    attributes_schema_version: schema_version, // 🧾 เวอร์ชัน schema ที่ใช้ตรวจ attributes
+                    ..staff.to_entity(Arc::clone(&self.timer_helper)) // 🔄 ใช้ to_entity() แปลง Model → Entity
+                },
+                Auditing::new(ItemAction::Create, actor, Arc::clone(&self.timer_helper)), // 📜 บันทึกประวัติการสร้าง
+            )
             .await
         {
-            Ok(id) => id,                                // ✅ ได้ ID ใหม่จากฐานข้อมูล
-            Err(e) => return Err(Box::new(APIError::AddingItemError(e))), // ❌ Error ในการบันทึก
+            Ok(r) => r,                                  // ✅ ได้แถวใหม่จากฐานข้อมูล
+            Err(e) => return Err(adding_error(e, &staff)), // ❌ Error ในการบันทึก (ชื่อซ้ำ -> 409)
         };
 
-        // 🔍 ขั้นตอนที่ 3: ดึงข้อมูลที่เพิ่งบันทึกกลับมาเพื่อยืนยัน
-        let staff_entity = match self.items_repository.find_by_id(id).await {
-            Ok(r) => r,                                  // ✅ พบข้อมูล
-            Err(_) => return Err(Box::new(APIError::ItemNotFound(id))), // ❌ ไม่พบข้อมูล (ไม่น่าเกิดขึ้น)
+        // 🔄 ขั้นตอนที่ 2: แปลง Entity เป็น Model สำหรับ API response
+        staff_entity.to_model()
+    }
+
+    // 🔄 เพิ่ม หรือแทนที่ Staff กลางของระบบ (ไม่มีเจ้าของ) ที่ชื่อเดียวกัน ด้วยการเขียนครั้งเดียว
+    // 🔒 If-Match / version ใน body ใช้กับการแทนที่ของเดิมเท่านั้น - If-Match อ้าง id ของ item จึงต้องหาแถวเดิมก่อน
+    // 📤 ส่งกลับ item พร้อมบอกว่าเป็นการเพิ่มใหม่ (true) หรือแทนที่ของเดิม (false)
+    pub async fn upserting(
+        &self,
+        body: StaffUpserting,
+        if_match: Option<&str>,
+        actor: &Actor,
+    ) -> Result<(Item, bool), Box<dyn IntoErrorResponse>> {
+        let staff = StaffAdding {
+            name: name_normalizing(&body.item.name),
+            ..body.item
         };
+        owner_rejecting(staff.owner_id)?;
+        name_validating(&staff.name)?;
+        attributes_validating(&staff)?;
+        let schema_version = self.categories_usecase.item_validating(&staff).await?;
 
-        // 🔄 ขั้นตอนที่ 4: แปลง Entity เป็น Model สำหรับ API response
-        Ok(match staff_entity.to_model(){
-            Ok(r) => r,                                  // ✅ แปลงสำเร็จ
-            Err(e) => return Err(e),                     // ❌ Error ในการแปลง
-        })
+        let entity = ItemsEntity {
+            attributes_schema_version: schema_version,
+            ..staff.to_entity(Arc::clone(&self.timer_helper))
+        };
+        // 🔍 แถวที่จะถูกแทนที่ - key เดียวกับ unique index (เจ้าของ, ประเภท, name_key) - ไม่มีเงื่อนไขก็ไม่ต้องหา
+        let conditional = if_match.is_some() || body.version.is_some();
+        let existing = match conditional {
+            false => None,
+            true => match self.items_repository.find_by_key(None, entity.category.clone(), staff.name.clone()).await {
+                Ok(r) => Some(r),
+                Err(sqlx::Error::RowNotFound) => None,
+                Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+            },
+        };
+        let (id, precondition) = match &existing {
+            Some(r) => {
+                let id = r.id.unwrap_or_default();
+                let precondition = Precondition::from_request(if_match, id, body.version);
+                precondition.checking(r.version)?;
+                (id, precondition)
+            }
+            // 🔢 มีเงื่อนไขแต่ไม่มีแถวเดิม (รวม If-Match: *) - ไม่ตรง ไม่เพิ่มใหม่
+            None if conditional => return Err(Box::new(APIError::UpsertTargetNotFound(staff.name.clone()))),
+            None => (0, Precondition::Any), // ➕ เพิ่มใหม่ไม่มี version ให้ตรวจ
+        };
+
+        let auditing = Auditing::new(ItemAction::Update, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.upsert(entity, precondition.expected(), auditing).await {
+            Ok(Upserted::Created(r)) => Ok((r.to_model()?, true)),
+            Ok(Upserted::Updated(r)) => Ok((r.to_model()?, false)),
+            // 🔢 มีการแก้ไขอื่นระหว่างอ่านแถวเดิมกับเขียน (แถวถูกลบไปแล้วก็ไม่มีให้แทนที่)
+            Err(sqlx::Error::RowNotFound) => match self.items_repository.find_by_id(id).await {
+                Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::UpsertTargetNotFound(staff.name.clone()))),
+                _ => Err(missing_item(&self.items_repository, id, precondition).await),
+            },
+            Err(e) => Err(adding_error(e, &staff)),
+        }
+    }
+
+    // 🧩 แทนที่ attributes ของ item - ตรวจกับ schema เวอร์ชันล่าสุดของประเภทแล้วบันทึกเวอร์ชันนั้นแทนเวอร์ชันเดิม
+    // 🔒 บันทึกเฉพาะเมื่อ item ยังเป็น version ที่อ่านมา (และตรงกับ precondition ของ client)
+    pub async fn attributes_updating(
+        &self,
+        id: i32,
+        body: AttributesUpdating,
+        precondition: Precondition,
+        actor: &Actor,
+    ) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let entity = match self.items_repository.find_by_id(id).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemNotFound(id))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        precondition.checking(entity.version)?;
+
+        let schema_version = self
+            .categories_usecase
+            .attributes_validating(&entity.get_category(), &body.attributes)
+            .await?;
+
+        let entity = ItemsEntity {
+            attributes: Json(body.attributes),
+            attributes_schema_version: schema_version,
+            updated_at: self.timer_helper.now(),
+            ..entity
+        };
+        let auditing = Auditing::new(ItemAction::Update, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.update_attributes(entity, auditing).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(missing_item(&self.items_repository, id, precondition).await),
+            Err(e) => Err(Box::new(APIError::UpdatingItemError(e))),
+        }
+    }
+
+    // 🩹 แก้ไขบางส่วน: ใช้ patch กับรูป JSON ของ item แล้วตรวจผลลัพธ์เหมือนตอนเพิ่ม (ชื่อ / ค่า / ประเภท / attributes)
+    // 🔒 บันทึกเฉพาะเมื่อ item ยังเป็น version ที่อ่านมา (และตรงกับ precondition ของ client)
+    pub async fn patching(
+        &self,
+        id: i32,
+        patch: ItemPatch,
+        precondition: Precondition,
+        actor: &Actor,
+    ) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let entity = match self.items_repository.find_by_id(id).await {
+            Ok(r) => r,
+            Err(sqlx::Error::RowNotFound) => return Err(Box::new(APIError::ItemNotFound(id))),
+            Err(e) => return Err(Box::new(APIError::FetchingItemError(e))),
+        };
+        precondition.checking(entity.version)?;
+
+        let patched = match patch.applying(&entity.to_model()?) {
+            Ok(item) => item.to_staff_adding(),
+            Err(e) => return Err(Box::new(e)),
+        };
+        name_validating(&patched.name)?;
+        attributes_validating(&patched)?;
+        let schema_version = self.categories_usecase.item_validating(&patched).await?;
+
+        // 🔄 field ที่ patch แก้ไม่ได้ (แบบ / ความทนทาน / เวลาสร้าง) คงค่าเดิม
+        let entity = ItemsEntity {
+            id: entity.id,
+            attributes_schema_version: schema_version,
+            definition_id: entity.definition_id,
+            durability: entity.durability,
+            version: entity.version,
+            created_at: entity.created_at,
+            updated_at: self.timer_helper.now(),
+            ..patched.to_entity(Arc::clone(&self.timer_helper))
+        };
+        let auditing = Auditing::new(ItemAction::Update, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.update(entity, auditing).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(missing_item(&self.items_repository, id, precondition).await),
+            Err(e) => Err(updating_error(e, &patched)),
+        }
+    }
+}
+
+// 👤 owner_id ตั้งได้เฉพาะทาง InventoryUsecase (ตรวจช่อง/น้ำหนัก + บันทึกการโอน)
+pub fn owner_rejecting(owner_id: Option<i32>) -> Result<(), Box<dyn IntoErrorResponse>> {
+    match owner_id {
+        Some(_) => Err(Box::new(APIError::ItemOwnerNotWritable(
+            "POST /players/{id}/items or POST /items/{id}/transfer".to_string(),
+        ))),
+        None => Ok(()),
+    }
+}
+
+// 📝 ตรวจชื่อของ Item ใหม่: ต้องมีตัวอักษรและไม่มีอักขระควบคุม (เช่น ขึ้นบรรทัดใหม่จาก CSV)
+pub fn name_validating(name: &str) -> Result<(), Box<dyn IntoErrorResponse>> {
+    if name.trim().is_empty() {
+        return Err(Box::new(APIError::InvalidItemName("name must not be empty".to_string())));
+    }
+    if name.chars().any(char::is_control) {
+        return Err(Box::new(APIError::InvalidItemName(
+            "name must not contain control characters".to_string(),
+        )));
+    }
+    Ok(())
+}
+
+// 📊 ตรวจค่าของ Item ใหม่ที่ไม่ขึ้นกับประเภท: ค่าติดลบใช้ไม่ได้
+// 🌳 stats ที่ประเภทต้องมี/มีได้ มาจาก rules ใน catalog (CategoriesUsecase::item_validating)
+pub fn attributes_validating(staff: &StaffAdding) -> Result<(), Box<dyn IntoErrorResponse>> {
+    let invalid = |reason: &str| -> Result<(), Box<dyn IntoErrorResponse>> {
+        Err(Box::new(APIError::InvalidItemAttributes(reason.to_string())))
+    };
+
+    if staff.weight < 0 {
+        return invalid("weight must not be negative");
+    }
+    if staff.required_level < 1 {
+        return invalid("required_level must be at least 1");
+    }
+    if staff.stats.present().iter().any(|(_, value)| *value < 0) {
+        return invalid("stats must not be negative");
+    }
+
+    Ok(())
+}
+
+// 🔄 แปลง error ตอน insert: constraint ของฐานข้อมูลบอกเหตุผลที่ client แก้ไขได้
+pub fn adding_error(e: sqlx::Error, staff: &StaffAdding) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match (code.as_deref(), staff.owner_id) {
+        // 👤 foreign key violation: ไม่มีผู้เล่นคนนี้
+        (Some("23503"), Some(owner_id)) => Box::new(APIError::PlayerNotFound(owner_id)),
+        // 🔄 unique violation: มีคนเพิ่มชื่อเดียวกันไปก่อนหน้าเล็กน้อย
+        (Some("23505"), _) => Box::new(APIError::ItemAlreadyExists(staff.name.clone())),
+        _ => Box::new(APIError::AddingItemError(e)),
+    }
+}
+
+// 🔄 แปลง error ตอน update: ชื่อชนกับ item อื่น / ไม่มีผู้เล่นเจ้าของ
+pub fn updating_error(e: sqlx::Error, staff: &StaffAdding) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match (code.as_deref(), staff.owner_id) {
+        (Some("23503"), Some(owner_id)) => Box::new(APIError::PlayerNotFound(owner_id)),
+        (Some("23505"), _) => Box::new(APIError::ItemAlreadyExists(staff.name.clone())),
+        _ => Box::new(APIError::UpdatingItemError(e)),
     }
 }
@@ -5,14 +5,29 @@
 mod tests {
     use std::sync::Arc;
 
+    use axum::http::StatusCode;
     use mockall::predicate::eq;    // 🎭 Mock library สำหรับจำลองพฤติกรรม
+    use serde_json::json;
+    use sqlx::types::Json;
 
     use crate::{
-        entities::items::Items as ItemsEntity,      // 🏛️ Domain Entity
-        models::item::{Category, StaffAdding},      // 📋 API Models
-        repositories::items::MockItemsRepository,   // 🎭 Mock Repository (จำลอง)
+        entities::{
+            categories::Categories as CategoriesEntity,
+            item_revisions::Auditing,
+            items::{Items as ItemsEntity, Upserted},
+        }, // 🏛️ Domain Entity
+        models::{
+            audit::{Actor, ItemAction},
+            etag::Precondition,
+            item::{Category, ItemAttributes, ItemStats, Rarity, StaffAdding, StaffUpserting, name_key, name_normalizing},
+            patch::{ItemPatch, PatchOperation},
+        }, // 📋 API Models
+        repositories::{categories::MockCategoriesRepository, items::MockItemsRepository}, // 🎭 Mock Repository (จำลอง)
         time_helper::TimerHelper,                   // ⏰ Timer utility
-        usecases::staff::StaffUsecase,              // 🧠 Use Case ที่จะทดสอบ
+        usecases::{
+            categories::CategoriesUsecase,          // 📚 Catalog ประเภท
+            staff::{StaffUsecase, attributes_validating}, // 🧠 Use Case ที่จะทดสอบ
+        },
     };
 
     // 🧪 Test Function: ทดสอบการเพิ่ม Staff ใหม่
@@ -24,6 +39,9 @@ mod tests {
         // ⏰ สร้าง Mock Timer Helper - ใช้เวลาที่กำหนดไว้ล่วงหน้าแทนที่เวลาปัจจุบัน
         let timer_helper = TimerHelper::Mock.creation();
 
+        // 👤 ผู้เรียก (บันทึกลงประวัติการแก้ไข)
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+
         // 📥 เตรียมข้อมูล input สำหรับการทดสอบ
 
         // 🔄 แบบเดิม: ไม่มี category field
@@ -35,54 +53,65 @@ mod tests {
         let req = StaffAdding {
             name: "wooden staff".to_string(),
             category: Category::Staff,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Rare,
+            required_level: 5,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats {
+                magic_power: Some(12),
+                ..ItemStats::default()
+            },
         };
 
-        // 🎭 Mock Setup 1: กำหนดพฤติกรรมของ find_by_name()
-        // จำลองสถานการณ์ที่ไม่พบ Staff ชื่อนี้ในระบบ (เพื่อให้เพิ่มได้)
-        items_repository_mock
-            .expect_find_by_name()                      // 🔍 คาดหวังให้มีการเรียก find_by_name
-            .with(eq(req.name.clone()))                 // 📋 ด้วยพารามิเตอร์ที่ตรงกับชื่อที่ต้องการเพิ่ม
-            .times(1)                                   // 🔢 เรียกครั้งเดียวเท่านั้น
-            .returning(|_| Box::pin(async {Err(sqlx::Error::RowNotFound)})); // ❌ ส่งกลับ "ไม่พบข้อมูล"
+        // 🎭 Mock Setup 1: ไม่ค้นชื่อก่อน - ชื่อซ้ำถูกตรวจโดย unique index ตอน insert
+        items_repository_mock.expect_find_by_name().never();
 
         // 🎭 Mock Setup 2: กำหนดพฤติกรรมของ insert()
-        // จำลองการบันทึกข้อมูลใหม่สำเร็จและได้ ID = 1 กลับมา
+        // จำลองการบันทึกข้อมูลใหม่สำเร็จ ได้แถวที่เพิ่ม (ID = 1) กลับมาทันที
         items_repository_mock
             .expect_insert()                            // ➕ คาดหวังให้มีการเรียก insert
-            .with(eq(ItemsEntity::new(                  // 📋 ด้วย Entity ที่มีข้อมูลตรงตามที่คาดหวัง
-                req.name.clone(),
-                Category::Staff.to_string(),
-                Arc::clone(&timer_helper),
-            )))
-            .returning(|_| Box::pin(async {Ok(1)}));    // ✅ ส่งกลับ ID = 1 (สำเร็จ)
-
-        // 🎭 Mock Setup 3: กำหนดพฤติกรรมของ find_by_id()
-        // จำลองการดึงข้อมูลที่เพิ่งบันทึกกลับมาเพื่อยืนยัน
-        items_repository_mock
-            .expect_find_by_id()                        // 🔍 คาดหวังให้มีการเรียก find_by_id
-            .with(eq(1))                                // 📋 ด้วย ID = 1
-            .times(1)                                   // 🔢 เรียกครั้งเดียวเท่านั้น
-            .returning(|_| {                            // 🔄 ส่งกลับข้อมูล Entity ที่สมบูรณ์
-                Box::pin(async{
-                    let t = TimerHelper::Mock.creation();
-                    Ok(ItemsEntity {
-                        id: Some(1),                    // 🆔 มี ID แล้ว
-                        name: "wooden staff".to_string(),
-                        category: Category::Staff.to_string(),
-                        created_at: t.now(),
-                        updated_at: t.now(),
-                    })
+            .with(eq(ItemsEntity {                      // 📋 ด้วย Entity ที่มีข้อมูลตรงตามที่คาดหวัง
+                rarity: Rarity::Rare.to_string(),
+                required_level: 5,
+                stats: Json(req.stats.clone()),
+                ..ItemsEntity::new(req.name.clone(), Category::Staff.to_string(), Arc::clone(&timer_helper))
+            }), eq(Auditing::new(ItemAction::Create, &actor, Arc::clone(&timer_helper)))) // 📜 บันทึกประวัติในชื่อของ actor
+            .times(1)                                   // 🔢 round trip เดียว
+            .returning(|item, _| Box::pin(async move {  // ✅ ส่งกลับแถวที่เพิ่ม (RETURNING *)
+                Ok(ItemsEntity {
+                    id: Some(1),                        // 🆔 มี ID แล้ว
+                    ..item
                 })
-            });
+            }));
+
+        // 🎭 Mock Setup 3: ไม่ต้องอ่านกลับด้วย find_by_id
+        items_repository_mock.expect_find_by_id().never();
 
         // 🏗️ สร้าง Use Case พร้อม Mock dependencies
+        // 📚 Mock catalog: มีประเภท Staff อยู่แล้ว
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock
+            .expect_find_all()
+            .returning(|| {
+                Box::pin(async {
+                    Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())])
+                })
+            });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) })); // 🧾 ยังไม่มี schema ของ attributes
+        let categories_usecase =
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), Arc::clone(&timer_helper));
+
         let staff_usecase = StaffUsecase::creation(
             Arc::new(items_repository_mock),            // 🎭 ใช้ Mock Repository แทนของจริง
-            timer_helper                                // ⏰ ใช้ Mock Timer
+            timer_helper,                               // ⏰ ใช้ Mock Timer
+            categories_usecase,                         // 📚 ตรวจประเภทกับ Mock catalog
         );
 
         // 🧪 ทดสอบการเรียกใช้งาน Use Case
-        let result = match staff_usecase.adding(req).await {
+        let result = match staff_usecase.adding(req, &actor).await {
             Ok(r) => r,                                 // ✅ คาดหวังให้สำเร็จ
             Err(_) => panic!("adding error"),           // ❌ ถ้าผิดพลาดให้ panic
         };
@@ -96,6 +125,404 @@ mod tests {
         // ✅ Assertions: ตรวจสอบผลลัพธ์
         assert_eq!(result.id, id);                      // 🆔 ID ต้องตรงกัน
         assert_eq!(result.name, "wooden staff");       // 📝 ชื่อต้องตรงกับที่ส่งเข้ามา
+        assert_eq!(result.rarity, Rarity::Rare);       // 💎 ความหายาก
+        assert_eq!(result.stats.magic_power, Some(12)); // 🪄 พลังเวท
 
     } // 🎉 การทดสอบเสร็จสิ้น - ทำให้มั่นใจว่า Use Case ทำงานถูกต้อง
+
+    // 🧪 upsert ชื่อที่มีอยู่แล้ว: ได้ item ที่ถูกแทนที่ และบอกว่าไม่ใช่การเพิ่มใหม่
+    #[tokio::test]
+    async fn upserting_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+
+        items_repository_mock.expect_find_by_name().never();
+        items_repository_mock.expect_insert().never();
+        items_repository_mock
+            .expect_upsert()
+            .times(1)
+            .returning(|item, _, _| {
+                Box::pin(async move {
+                    Ok(Upserted::Updated(ItemsEntity {
+                        id: Some(3),
+                        version: 2,
+                        ..item
+                    }))
+                })
+            });
+
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+
+        let staff_usecase = StaffUsecase::creation(
+            Arc::new(items_repository_mock),
+            Arc::clone(&timer_helper),
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), timer_helper),
+        );
+
+        let req = StaffAdding {
+            name: "wooden staff".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 3,
+            rarity: Rarity::Epic,
+            required_level: 5,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats {
+                magic_power: Some(20),
+                ..ItemStats::default()
+            },
+        };
+        let (item, created) = match staff_usecase.upserting(StaffUpserting { item: req, version: None }, None, &actor).await {
+            Ok(r) => r,
+            Err(_) => panic!("upserting error"),
+        };
+
+        assert!(!created);
+        assert_eq!(item.id, 3);
+        assert_eq!(item.version, 2);
+        assert_eq!(item.rarity, Rarity::Epic);
+        assert_eq!(item.stats.magic_power, Some(20));
+    }
+
+    // 🧪 upsert แทนที่ของเดิมพร้อมเงื่อนไข: version ใน body ไม่ตรง -> 409 / If-Match ตรง -> ส่ง version ให้ repository ตรวจซ้ำ
+    #[tokio::test]
+    async fn upserting_precondition_test() {
+        let timer_helper = TimerHelper::Mock.creation();
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+        let req = StaffAdding {
+            name: "wooden staff".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 3,
+            rarity: Rarity::Epic,
+            required_level: 5,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats::default(),
+        };
+
+        let mut items_repository_mock = MockItemsRepository::new();
+        let existing = {
+            let timer_helper = Arc::clone(&timer_helper);
+            move || ItemsEntity {
+                id: Some(3),
+                version: 4,
+                ..ItemsEntity::new("wooden staff".to_string(), "Staff".to_string(), Arc::clone(&timer_helper))
+            }
+        };
+        items_repository_mock
+            .expect_find_by_key()
+            .with(eq(None), eq("Staff".to_string()), eq("wooden staff".to_string()))
+            .times(2)
+            .returning(move |_, _, _| {
+                let existing = existing();
+                Box::pin(async move { Ok(existing) })
+            });
+        items_repository_mock
+            .expect_upsert()
+            .withf(|_, version, _| *version == Some(4))
+            .times(1)
+            .returning(|item, _, _| Box::pin(async move { Ok(Upserted::Updated(ItemsEntity { id: Some(3), version: 5, ..item })) }));
+        let staff_usecase = staff_usecase(items_repository_mock);
+
+        match staff_usecase.upserting(StaffUpserting { item: req.clone(), version: Some(3) }, None, &actor).await {
+            Ok(_) => panic!("stale version should conflict"),
+            Err(e) => {
+                let error = e.error();
+                assert_eq!(error.status_code, StatusCode::CONFLICT);
+                assert_eq!(error.current_version, Some(4));
+            }
+        }
+
+        let (item, created) = match staff_usecase.upserting(StaffUpserting { item: req, version: None }, Some("\"3-v4\""), &actor).await {
+            Ok(r) => r,
+            Err(_) => panic!("upserting error"),
+        };
+        assert!(!created);
+        assert_eq!(item.version, 5);
+    }
+
+    // 🧪 ชื่อเดียวกันอยู่ในประเภทอื่น: ไม่ใช่แถวที่จะถูกแทนที่ - เงื่อนไขไม่มีแถวให้ตรง -> 412 และไม่เพิ่มใหม่
+    #[tokio::test]
+    async fn upserting_other_category_test() {
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+        let req = StaffAdding {
+            name: "wooden staff".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 3,
+            rarity: Rarity::Epic,
+            required_level: 5,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats::default(),
+        };
+
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_find_by_name().never();
+        items_repository_mock
+            .expect_find_by_key()
+            .times(2)
+            .returning(|_, category, name| {
+                Box::pin(async move {
+                    match category.as_str() {
+                        // 🗡️ "wooden staff" ของ Sword (id 7) มีอยู่ แต่ของ Staff ไม่มี
+                        "Sword" => Ok(ItemsEntity { id: Some(7), ..ItemsEntity::new(name, category, TimerHelper::Mock.creation()) }),
+                        _ => Err(sqlx::Error::RowNotFound),
+                    }
+                })
+            });
+        items_repository_mock.expect_upsert().never();
+        let staff_usecase = staff_usecase(items_repository_mock);
+
+        for (if_match, version) in [(Some("\"7-v1\""), None), (None, Some(1))] {
+            match staff_usecase.upserting(StaffUpserting { item: req.clone(), version }, if_match, &actor).await {
+                Ok(_) => panic!("precondition without a matching row should fail"),
+                Err(e) => assert_eq!(e.error().status_code, StatusCode::PRECONDITION_FAILED),
+            }
+        }
+    }
+
+    // 📚 StaffUsecase ที่ catalog มี Staff (ไม่มี schema)
+    fn staff_usecase(items_repository_mock: MockItemsRepository) -> Arc<StaffUsecase> {
+        let mut categories_repository_mock = MockCategoriesRepository::new();
+        categories_repository_mock.expect_find_all().returning(|| {
+            Box::pin(async { Ok(vec![CategoriesEntity::new("Staff".to_string(), TimerHelper::Mock.creation())]) })
+        });
+        categories_repository_mock
+            .expect_find_latest_schemas()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+
+        let timer_helper = TimerHelper::Mock.creation();
+        StaffUsecase::creation(
+            Arc::new(items_repository_mock),
+            Arc::clone(&timer_helper),
+            CategoriesUsecase::creation(Arc::new(categories_repository_mock), timer_helper),
+        )
+    }
+
+    // 🪄 Staff id 7 version 2 ที่มี magic_power 5 / attack 1
+    fn staff_entity() -> ItemsEntity {
+        ItemsEntity {
+            id: Some(7),
+            version: 2,
+            stats: Json(ItemStats {
+                magic_power: Some(5),
+                attack: Some(1),
+                ..ItemStats::default()
+            }),
+            ..ItemsEntity::new("wooden staff".to_string(), Category::Staff.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    // 🧪 Merge Patch: แก้เฉพาะ field ที่ส่งมา / null ลบ key ของ stats / field อื่นคงค่าเดิม
+    #[tokio::test]
+    async fn patching_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(7))
+            .returning(|_| Box::pin(async { Ok(staff_entity()) }));
+        items_repository_mock
+            .expect_update()
+            .times(1)
+            .withf(|item, auditing| {
+                item.id == Some(7)
+                    && item.version == 2
+                    && item.name == "oak staff"
+                    && item.rarity == "Epic"
+                    && item.stats.0.magic_power == Some(5)
+                    && item.stats.0.attack.is_none()
+                    && auditing.action == ItemAction::Update
+            })
+            .returning(|item, _| Box::pin(async move { Ok(ItemsEntity { version: 3, ..item }) }));
+
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+        let patch = ItemPatch::Merge(json!({
+            "name": "oak staff",
+            "rarity": "Epic",
+            "stats": {"attack": null}
+        }));
+        let item = match staff_usecase(items_repository_mock).patching(7, patch, Precondition::IfMatch(2), &actor).await {
+            Ok(r) => r,
+            Err(_) => panic!("patching error"),
+        };
+
+        assert_eq!(item.name, "oak staff");
+        assert_eq!(item.version, 3);
+    }
+
+    // 🧪 Merge Patch ส่ง null ให้ field อ่านอย่างเดียวที่เป็น null อยู่แล้ว (owner_id / durability) = ไม่ได้แก้ จึงผ่าน
+    #[tokio::test]
+    async fn patching_null_read_only_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(staff_entity()) }));
+        items_repository_mock
+            .expect_update()
+            .times(1)
+            .withf(|item, _| item.name == "oak staff" && item.owner_id.is_none() && item.durability.is_none())
+            .returning(|item, _| Box::pin(async move { Ok(ItemsEntity { version: 3, ..item }) }));
+
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+        let patch = ItemPatch::Merge(json!({"name": "oak staff", "owner_id": null, "durability": null}));
+        let item = match staff_usecase(items_repository_mock).patching(7, patch, Precondition::Any, &actor).await {
+            Ok(r) => r,
+            Err(_) => panic!("no-op null patch should be accepted"),
+        };
+
+        assert_eq!(item.name, "oak staff");
+        assert_eq!(item.owner_id, None);
+    }
+
+    // 🧪 JSON Patch: test ไม่ผ่าน -> 409 / path ไม่มีอยู่ หรือแก้ field อ่านอย่างเดียว -> 422 และไม่บันทึกเลย
+    #[tokio::test]
+    async fn patching_rejected_test() {
+        let cases = [
+            (
+                vec![
+                    PatchOperation::Replace { path: "/name".to_string(), value: json!("oak staff") },
+                    PatchOperation::Test { path: "/stats/magic_power".to_string(), value: json!(9) },
+                ],
+                StatusCode::CONFLICT,
+            ),
+            (
+                vec![PatchOperation::Remove { path: "/attributes/color".to_string() }],
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                vec![PatchOperation::Replace { path: "/version".to_string(), value: json!(9) }],
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                vec![PatchOperation::Replace { path: "/owner_id".to_string(), value: json!(8) }],
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                vec![PatchOperation::Add { path: "/created_at".to_string(), value: json!("2020-01-01T00:00:00") }],
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+        ];
+
+        for (operations, status_code) in cases {
+            let mut items_repository_mock = MockItemsRepository::new();
+            items_repository_mock
+                .expect_find_by_id()
+                .returning(|_| Box::pin(async { Ok(staff_entity()) }));
+            items_repository_mock.expect_update().never();
+
+            let actor = Actor { name: "gm".to_string(), request_id: None };
+            let patch = ItemPatch::Json(operations);
+            match staff_usecase(items_repository_mock).patching(7, patch, Precondition::Any, &actor).await {
+                Ok(_) => panic!("patch should be rejected"),
+                Err(e) => assert_eq!(e.error().status_code, status_code),
+            }
+        }
+    }
+
+    // 🧪 ตรวจพื้นฐานที่ไม่ขึ้นกับประเภท: น้ำหนัก เลเวล และ stats ต้องไม่ติดลบ
+    //    (stats ที่ต้องมี/มีได้ของแต่ละประเภทอยู่ในกติกาของ catalog - ดู categories_test)
+    #[test]
+    fn attributes_validating_test() {
+        let staff = |stats: ItemStats| StaffAdding {
+            name: "test".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats,
+        };
+
+        assert!(attributes_validating(&staff(ItemStats::default())).is_ok());
+        assert!(attributes_validating(&StaffAdding {
+            required_level: 0,
+            ..staff(ItemStats::default())
+        })
+        .is_err());
+
+        let negative = ItemStats {
+            magic_power: Some(-1),
+            ..ItemStats::default()
+        };
+        match attributes_validating(&staff(negative)) {
+            Ok(_) => panic!("negative stats should be invalid"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+
+    // 🧪 ชื่อถูก normalize ก่อนบันทึก: ช่องว่างหัวท้าย / ช่องว่างหลายตัว (รวม full-width) / วรรณยุกต์ที่พิมพ์ก่อนสระ (NFC)
+    #[tokio::test]
+    async fn adding_normalized_name_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_find_by_name().never();
+        items_repository_mock
+            .expect_insert()
+            .times(1)
+            .withf(|item, _| item.name == "Wooden Staff \u{0E1B}\u{0E39}\u{0E48}")
+            .returning(|item, _| Box::pin(async move { Ok(ItemsEntity { id: Some(1), ..item }) }));
+
+        let req = StaffAdding {
+            name: "  Wooden \u{3000} Staff\t\u{0E1B}\u{0E48}\u{0E39} ".to_string(),
+            category: Category::Staff,
+            owner_id: None,
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats::default(),
+        };
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+        match staff_usecase(items_repository_mock).adding(req, &actor).await {
+            Ok(item) => assert_eq!(item.name, "Wooden Staff \u{0E1B}\u{0E39}\u{0E48}"),
+            Err(_) => panic!("adding error"),
+        }
+    }
+
+    // 🧪 owner_id ต้องผ่านกติกากระเป๋า - POST / PUT /items/staff ไม่เขียนเลย
+    #[tokio::test]
+    async fn adding_owned_rejected_test() {
+        let req = StaffAdding {
+            name: "wooden staff".to_string(),
+            category: Category::Staff,
+            owner_id: Some(7),
+            weight: 0,
+            rarity: Rarity::Common,
+            required_level: 1,
+            attributes: ItemAttributes::new(),
+            stats: ItemStats::default(),
+        };
+        let actor = Actor { name: "gm".to_string(), request_id: None };
+
+        let mut items_repository_mock = MockItemsRepository::new();
+        items_repository_mock.expect_insert().never();
+        items_repository_mock.expect_upsert().never();
+        let staff_usecase = staff_usecase(items_repository_mock);
+
+        match staff_usecase.adding(req.clone(), &actor).await {
+            Ok(_) => panic!("owner_id should be rejected"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+        }
+        match staff_usecase.upserting(StaffUpserting { item: req, version: None }, None, &actor).await {
+            Ok(_) => panic!("owner_id should be rejected"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::UNPROCESSABLE_ENTITY),
+        }
+    }
+
+    // 🔑 ชื่อที่ต่างกันแค่ตัวพิมพ์ / ช่องว่าง / ลำดับของวรรณยุกต์กับสระ ได้ name_key เดียวกัน
+    #[test]
+    fn name_key_test() {
+        assert_eq!(name_normalizing("  wooden   staff\n"), "wooden staff");
+        assert_eq!(name_key("Wooden Staff"), name_key("  wooden  STAFF "));
+        assert_eq!(name_key("\u{0E1B}\u{0E48}\u{0E39}"), name_key("\u{0E1B}\u{0E39}\u{0E48}"));
+        assert_eq!(name_key("Cafe\u{0301}"), name_key("CAF\u{00C9}"));
+        assert_ne!(name_key("wooden staff"), name_key("woodenstaff"));
+    }
 }
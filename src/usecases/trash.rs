@@ -0,0 +1,119 @@
+// === 🧠 Application Layer: Trash Use Case ===
+// 📖 ลบ item แบบ soft delete ให้ GM กู้คืนได้ และ purge job ที่ลบจริงเมื่อเกินระยะเก็บรักษา
+//
+// 🎯 SOLID Principles ที่ใช้ในไฟล์นี้:
+//
+// 1️⃣ Single Responsibility Principle (SRP):
+//    TrashUsecase ดูแลเฉพาะวงจรการลบ (ลบ / กู้คืน / purge) - การอ่านยังอยู่ที่ ItemsUsecase
+//
+// 5️⃣ Dependency Inversion Principle (DIP):
+//    ขึ้นอยู่กับ SharedItemsRepository และ IntoTimerHelperShared (เวลา Mock ได้ใน test)
+
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{
+    entities::item_revisions::Auditing,
+    models::{
+        audit::{Actor, ItemAction},
+        error::{APIError, IntoErrorResponse},
+        etag::Precondition,
+        item::Item,
+    },
+    repositories::items::SharedItemsRepository,
+    setting::Trash as TrashSetting,
+    time_helper::IntoTimerHelperShared,
+    usecases::items::missing_item,
+};
+
+// 📦 TrashUsecase struct: จัดการ item ที่ถูกลบ
+pub struct TrashUsecase {
+    items_repository: SharedItemsRepository, // 💾 Repository ของ item
+    timer_helper: IntoTimerHelperShared,     // ⏰ เวลาที่ลบ / เวลาตัดรอบ purge
+    setting: TrashSetting,                   // 🗑️ ระยะเก็บรักษาและความถี่ของ purge job
+}
+
+impl TrashUsecase {
+    // 🏗️ Factory method: สร้าง TrashUsecase พร้อม dependencies
+    pub fn creation(
+        items_repository: SharedItemsRepository,
+        timer_helper: IntoTimerHelperShared,
+        setting: TrashSetting,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            items_repository,
+            timer_helper,
+            setting,
+        })
+    }
+
+    // 🗑️ ลบ item (soft delete) - หายจากทุกการอ่านแต่กู้คืนได้จนกว่าจะ purge
+    // 🔒 มี precondition: ลบเฉพาะเมื่อ item ยังเป็น version นั้น
+    pub async fn removing(&self, id: i32, precondition: Precondition, actor: &Actor) -> Result<(), Box<dyn IntoErrorResponse>> {
+        let auditing = Auditing::new(ItemAction::Delete, actor, Arc::clone(&self.timer_helper));
+        let deleted_at = self.timer_helper.now();
+        match self.items_repository.soft_delete(id, deleted_at, precondition.expected(), auditing).await {
+            Ok(_) => Ok(()),
+            Err(sqlx::Error::RowNotFound) => Err(missing_item(&self.items_repository, id, precondition).await),
+            Err(e) => Err(Box::new(APIError::RemovingItemError(e))),
+        }
+    }
+
+    // ♻️ กู้คืน item ที่ถูกลบ - item ที่ยังไม่ถูกลบส่งกลับตามเดิม (เรียกซ้ำได้)
+    // 🎒 GM กู้คืนได้แม้กระเป๋าของเจ้าของจะเต็มแล้ว
+    pub async fn restoring(&self, id: i32, actor: &Actor) -> Result<Item, Box<dyn IntoErrorResponse>> {
+        let auditing = Auditing::new(ItemAction::Restore, actor, Arc::clone(&self.timer_helper));
+        match self.items_repository.restore(id, self.timer_helper.now(), auditing).await {
+            Ok(r) => return r.to_model(),
+            Err(sqlx::Error::RowNotFound) => {}
+            Err(e) => return Err(restoring_error(e, id)),
+        }
+
+        match self.items_repository.find_by_id(id).await {
+            Ok(r) => r.to_model(),
+            Err(sqlx::Error::RowNotFound) => Err(Box::new(APIError::ItemNotFound(id))),
+            Err(e) => Err(Box::new(APIError::FetchingItemError(e))),
+        }
+    }
+
+    // 🧹 ลบจริงทุก item ที่ถูกลบนานกว่า retention_days ส่งกลับจำนวนที่ลบ
+    pub async fn purging(&self) -> Result<u64, Box<dyn IntoErrorResponse>> {
+        let deleted_before = self.timer_helper.now() - chrono::Duration::days(self.setting.retention_days);
+        match self.items_repository.purge(deleted_before).await {
+            Ok(purged) => Ok(purged),
+            Err(e) => Err(Box::new(APIError::RemovingItemError(e))),
+        }
+    }
+
+    // 🕒 รัน purge job เป็นระยะตาม purge_interval_secs - error แค่ log แล้วรอรอบถัดไป
+    pub fn purge_scheduling(self: &Arc<Self>) {
+        let trash_usecase = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(trash_usecase.setting.purge_interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                match trash_usecase.purging().await {
+                    Ok(0) => {}
+                    Ok(purged) => info!("{} deleted items have been purged", purged),
+                    Err(e) => error!("Failed to purge deleted items: {}", e.error().error),
+                }
+            }
+        });
+    }
+}
+
+// 🔄 แปลง error ตอนกู้คืน: ชื่อถูกใช้ไปแล้วหลังจากลบบอก client ได้
+fn restoring_error(e: sqlx::Error, id: i32) -> Box<dyn IntoErrorResponse> {
+    let code = match &e {
+        sqlx::Error::Database(db_error) => db_error.code().map(|code| code.to_string()),
+        _ => None,
+    };
+
+    match code.as_deref() {
+        // 🔄 unique violation: มี item ชื่อเดียวกันในกระเป๋าเดียวกันแล้ว
+        Some("23505") => Box::new(APIError::ItemRestoreConflict(id)),
+        _ => Box::new(APIError::UpdatingItemError(e)),
+    }
+}
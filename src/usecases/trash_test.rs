@@ -0,0 +1,180 @@
+// === 🧪 Test Module: Trash Use Case Tests ===
+// 📖 ทดสอบ soft delete / กู้คืน / purge โดยใช้ Mock Repository และเวลา Mock
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use mockall::predicate::{always, eq};
+    use std::sync::Arc;
+
+    use crate::{
+        entities::{item_revisions::Auditing, items::Items as ItemsEntity},
+        models::{
+            audit::{Actor, ItemAction},
+            etag::Precondition,
+            item::Category,
+        },
+        repositories::items::MockItemsRepository,
+        setting::Trash as TrashSetting,
+        time_helper::TimerHelper,
+        usecases::trash::TrashUsecase,
+    };
+
+    fn trash_setting() -> TrashSetting {
+        TrashSetting {
+            retention_days: 30,
+            purge_interval_secs: 3600,
+        }
+    }
+
+    fn actor() -> Actor {
+        Actor {
+            name: "gm".to_string(),
+            request_id: Some("req-1".to_string()),
+        }
+    }
+
+    fn item_entity(id: i32) -> ItemsEntity {
+        ItemsEntity {
+            id: Some(id),
+            ..ItemsEntity::new("fire staff".to_string(), Category::Staff.to_string(), TimerHelper::Mock.creation())
+        }
+    }
+
+    // 🧪 ลบ: ตั้ง deleted_at เป็นเวลาปัจจุบัน / item ที่ไม่มี (หรือลบไปแล้ว) ได้ 404
+    #[tokio::test]
+    async fn removing_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        items_repository_mock
+            .expect_soft_delete()
+            .with(
+                eq(1),
+                eq(timer_helper.now()),
+                eq(Some(1)),
+                eq(Auditing::new(ItemAction::Delete, &actor(), Arc::clone(&timer_helper))),
+            )
+            .times(1)
+            .returning(|id, deleted_at, _, _| {
+                Box::pin(async move {
+                    Ok(ItemsEntity {
+                        deleted_at: Some(deleted_at),
+                        ..item_entity(id)
+                    })
+                })
+            });
+        items_repository_mock
+            .expect_soft_delete()
+            .with(eq(2), eq(timer_helper.now()), eq(None), always())
+            .returning(|_, _, _, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(2))
+            .returning(|_| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+
+        let trash_usecase = TrashUsecase::creation(Arc::new(items_repository_mock), timer_helper, trash_setting());
+
+        assert!(trash_usecase.removing(1, Precondition::IfMatch(1), &actor()).await.is_ok());
+        match trash_usecase.removing(2, Precondition::Any, &actor()).await {
+            Ok(_) => panic!("item should not exist"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+
+    // 🧪 ลบจาก version เก่า: If-Match ได้ 412 / field version ได้ 409 พร้อม version ปัจจุบัน
+    #[tokio::test]
+    async fn removing_version_conflict_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        items_repository_mock
+            .expect_soft_delete()
+            .with(eq(1), always(), eq(Some(2)), always())
+            .returning(|_, _, _, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| {
+                Box::pin(async move {
+                    Ok(ItemsEntity {
+                        version: 3,
+                        ..item_entity(id)
+                    })
+                })
+            });
+
+        let trash_usecase =
+            TrashUsecase::creation(Arc::new(items_repository_mock), TimerHelper::Mock.creation(), trash_setting());
+
+        match trash_usecase.removing(1, Precondition::IfMatch(2), &actor()).await {
+            Ok(_) => panic!("version should conflict"),
+            Err(e) => {
+                assert_eq!(e.error().status_code, StatusCode::PRECONDITION_FAILED);
+                assert_eq!(e.error().current_version, Some(3));
+            }
+        }
+        match trash_usecase.removing(1, Precondition::Version(2), &actor()).await {
+            Ok(_) => panic!("version should conflict"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::CONFLICT),
+        }
+    }
+
+    // 🧪 กู้คืน: item ที่ถูกลบกลับมา / item ที่ไม่ได้ถูกลบส่งกลับตามเดิม / ไม่มีเลยได้ 404
+    #[tokio::test]
+    async fn restoring_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+
+        items_repository_mock
+            .expect_restore()
+            .with(
+                eq(1),
+                eq(TimerHelper::Mock.creation().now()),
+                eq(Auditing::new(ItemAction::Restore, &actor(), TimerHelper::Mock.creation())),
+            )
+            .returning(|id, _, _| Box::pin(async move { Ok(item_entity(id)) }));
+        items_repository_mock
+            .expect_restore()
+            .returning(|_, _, _| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(2))
+            .returning(|id| Box::pin(async move { Ok(item_entity(id)) }));
+        items_repository_mock
+            .expect_find_by_id()
+            .with(eq(3))
+            .returning(|_| Box::pin(async { Err(sqlx::Error::RowNotFound) }));
+
+        let trash_usecase =
+            TrashUsecase::creation(Arc::new(items_repository_mock), TimerHelper::Mock.creation(), trash_setting());
+
+        match trash_usecase.restoring(1, &actor()).await {
+            Ok(item) => assert_eq!(item.deleted_at, None),
+            Err(_) => panic!("restoring error"),
+        }
+        assert!(trash_usecase.restoring(2, &actor()).await.is_ok());
+        match trash_usecase.restoring(3, &actor()).await {
+            Ok(_) => panic!("item should not exist"),
+            Err(e) => assert_eq!(e.error().status_code, StatusCode::NOT_FOUND),
+        }
+    }
+
+    // 🧪 purge: ลบจริงเฉพาะที่ถูกลบก่อน (เวลาปัจจุบัน - retention_days)
+    #[tokio::test]
+    async fn purging_test() {
+        let mut items_repository_mock = MockItemsRepository::new();
+        let timer_helper = TimerHelper::Mock.creation();
+
+        items_repository_mock
+            .expect_purge()
+            .with(eq(timer_helper.now() - chrono::Duration::days(30)))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(3) }));
+
+        let trash_usecase = TrashUsecase::creation(Arc::new(items_repository_mock), timer_helper, trash_setting());
+
+        match trash_usecase.purging().await {
+            Ok(purged) => assert_eq!(purged, 3),
+            Err(_) => panic!("purging error"),
+        }
+    }
+}